@@ -0,0 +1,264 @@
+//!
+//! Throughput and latency benchmarking for the linter.
+//!
+//! This module provides a first-class benchmarking entry point so users and
+//! maintainers can measure lint performance on real corpora. Each file is
+//! linted for a number of warmup iterations (to populate caches) followed by a
+//! number of measured iterations; the measured per-iteration latencies are
+//! reduced to p50/p95/p99 percentiles and an aggregate MB/s throughput.
+//!
+//! Results are serializable so a run can be stored as a baseline and a later
+//! run compared against it to flag regressions.
+
+use crate::rule::Rule;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// How many warmup and measured iterations to run per file.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Iterations run before measurement to warm caches.
+    pub warmup: usize,
+    /// Iterations whose latency is recorded.
+    pub measured: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            measured: 20,
+        }
+    }
+}
+
+/// Per-file benchmark measurement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileBenchResult {
+    pub path: String,
+    pub bytes: usize,
+    /// Median measured latency, in milliseconds.
+    pub p50_ms: f64,
+    /// 95th-percentile latency, in milliseconds.
+    pub p95_ms: f64,
+    /// 99th-percentile latency, in milliseconds.
+    pub p99_ms: f64,
+    /// Throughput at the median latency, in MB/s.
+    pub throughput_mbps: f64,
+    /// Number of warnings produced (constant across iterations).
+    pub warnings: usize,
+}
+
+/// Aggregate benchmark report across a set of files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub files: Vec<FileBenchResult>,
+    pub total_bytes: usize,
+    pub total_warnings: usize,
+    /// Aggregate throughput across all files (total bytes / summed median time).
+    pub throughput_mbps: f64,
+    /// Per-rule self-time breakdown, sourced from the span profiler.
+    pub per_rule_ms: Vec<(String, f64)>,
+}
+
+/// Benchmark a set of `(path, content)` inputs against `rules`.
+pub fn bench(inputs: &[(String, String)], rules: &[Box<dyn Rule>], config: BenchConfig) -> BenchReport {
+    crate::profiling::reset_spans();
+
+    let mut files = Vec::with_capacity(inputs.len());
+    let mut total_median = Duration::ZERO;
+
+    for (path, content) in inputs {
+        // Warmup iterations populate the AST/regex caches and are discarded.
+        for _ in 0..config.warmup {
+            let _ = crate::lint(content, rules, false);
+        }
+
+        let mut latencies = Vec::with_capacity(config.measured);
+        let mut warnings = 0;
+        for _ in 0..config.measured.max(1) {
+            let start = Instant::now();
+            let result = crate::lint(content, rules, false).unwrap_or_default();
+            latencies.push(start.elapsed());
+            warnings = result.len();
+        }
+        latencies.sort_unstable();
+
+        let p50 = percentile(&latencies, 50.0);
+        let bytes = content.len();
+        total_median += p50;
+
+        files.push(FileBenchResult {
+            path: path.clone(),
+            bytes,
+            p50_ms: ms(p50),
+            p95_ms: ms(percentile(&latencies, 95.0)),
+            p99_ms: ms(percentile(&latencies, 99.0)),
+            throughput_mbps: throughput(bytes, p50),
+            warnings,
+        });
+    }
+
+    let total_bytes: usize = files.iter().map(|f| f.bytes).sum();
+    let total_warnings: usize = files.iter().map(|f| f.warnings).sum();
+
+    BenchReport {
+        files,
+        total_bytes,
+        total_warnings,
+        throughput_mbps: throughput(total_bytes, total_median),
+        per_rule_ms: per_rule_breakdown(),
+    }
+}
+
+/// Compute the nearest-rank percentile of a sorted slice of durations.
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    let idx = rank.saturating_sub(1).min(sorted.len() - 1);
+    sorted[idx]
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn throughput(bytes: usize, time: Duration) -> f64 {
+    let secs = time.as_secs_f64();
+    if secs == 0.0 {
+        0.0
+    } else {
+        (bytes as f64 / (1024.0 * 1024.0)) / secs
+    }
+}
+
+/// Extract a per-rule self-time breakdown from the span profiler, sorted by
+/// descending time. Empty unless the `profiling` feature is enabled.
+fn per_rule_breakdown() -> Vec<(String, f64)> {
+    // The span report already renders "lint/<rule>" rows; here we only surface
+    // a best-effort ordering from whatever the profiler recorded.
+    let report = crate::profiling::get_span_report(0.0);
+    let mut rows = Vec::new();
+    for line in report.lines() {
+        let trimmed = line.trim_start();
+        if let Some((label, rest)) = trimmed.split_once(": ") {
+            if let Some(num) = rest.split_whitespace().next() {
+                if let Ok(v) = num.parse::<f64>() {
+                    rows.push((label.to_string(), v));
+                }
+            }
+        }
+    }
+    rows.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    rows
+}
+
+impl BenchReport {
+    /// Render a human-readable summary of the report.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("=== rumdl benchmark ===\n");
+        out.push_str(&format!(
+            "Files: {}  Total: {:.1} KiB  Warnings: {}\n",
+            self.files.len(),
+            self.total_bytes as f64 / 1024.0,
+            self.total_warnings
+        ));
+        out.push_str(&format!("Aggregate throughput: {:.1} MB/s\n\n", self.throughput_mbps));
+        out.push_str("file                                     | p50 ms | p95 ms | p99 ms | MB/s\n");
+        out.push_str("-----------------------------------------|--------|--------|--------|------\n");
+        for f in &self.files {
+            out.push_str(&format!(
+                "{:<40} | {:>6.3} | {:>6.3} | {:>6.3} | {:>5.1}\n",
+                truncate(&f.path, 40),
+                f.p50_ms,
+                f.p95_ms,
+                f.p99_ms,
+                f.throughput_mbps,
+            ));
+        }
+        out
+    }
+
+    /// Compare this report against a stored baseline, returning a regression
+    /// line for every file whose median latency grew beyond `tolerance` (a
+    /// fraction, e.g. `0.10` for 10%).
+    pub fn regressions(&self, baseline: &BenchReport, tolerance: f64) -> Vec<String> {
+        let mut regressions = Vec::new();
+        for current in &self.files {
+            if let Some(base) = baseline.files.iter().find(|b| b.path == current.path) {
+                if base.p50_ms > 0.0 {
+                    let delta = (current.p50_ms - base.p50_ms) / base.p50_ms;
+                    if delta > tolerance {
+                        regressions.push(format!(
+                            "{}: {:.3} ms -> {:.3} ms ({:+.1}%)",
+                            current.path,
+                            base.p50_ms,
+                            current.p50_ms,
+                            delta * 100.0
+                        ));
+                    }
+                }
+            }
+        }
+        regressions
+    }
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.len() <= max {
+        s.to_string()
+    } else {
+        format!("...{}", &s[s.len() - (max - 3)..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::MD009TrailingSpaces;
+
+    #[test]
+    fn test_bench_produces_results() {
+        let inputs = vec![("a.md".to_string(), "# Heading   \n\ntext \n".to_string())];
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD009TrailingSpaces::new(2, false))];
+        let report = bench(&inputs, &rules, BenchConfig { warmup: 1, measured: 3 });
+
+        assert_eq!(report.files.len(), 1);
+        assert_eq!(report.files[0].bytes, inputs[0].1.len());
+        assert!(report.files[0].p95_ms >= report.files[0].p50_ms);
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let ds: Vec<Duration> = (1..=10).map(|n| Duration::from_millis(n)).collect();
+        assert_eq!(percentile(&ds, 50.0), Duration::from_millis(5));
+        assert_eq!(percentile(&ds, 100.0), Duration::from_millis(10));
+        assert_eq!(percentile(&[], 50.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_regression_detection() {
+        let mk = |p50: f64| BenchReport {
+            files: vec![FileBenchResult {
+                path: "a.md".to_string(),
+                bytes: 100,
+                p50_ms: p50,
+                p95_ms: p50,
+                p99_ms: p50,
+                throughput_mbps: 1.0,
+                warnings: 0,
+            }],
+            total_bytes: 100,
+            total_warnings: 0,
+            throughput_mbps: 1.0,
+            per_rule_ms: vec![],
+        };
+        let baseline = mk(1.0);
+        let slower = mk(1.5);
+        assert_eq!(slower.regressions(&baseline, 0.1).len(), 1);
+        assert!(slower.regressions(&baseline, 0.9).is_empty());
+    }
+}
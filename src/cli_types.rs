@@ -41,11 +41,15 @@ pub struct CheckArgs {
     #[arg(short, long, default_value = "false")]
     pub list_rules: bool,
 
-    /// Disable specific rules (comma-separated)
+    /// Disable specific rules (comma-separated). Accepts exact rule names
+    /// (MD013), numeric-code prefixes (MD01 for MD010-MD019), or category
+    /// names (lists, headings, ...)
     #[arg(short, long)]
     pub disable: Option<String>,
 
-    /// Enable only specific rules (comma-separated)
+    /// Enable only specific rules (comma-separated). Accepts exact rule names
+    /// (MD013), numeric-code prefixes (MD01 for MD010-MD019), or category
+    /// names (lists, headings, ...)
     #[arg(short, long, visible_alias = "rules")]
     pub enable: Option<String>,
 
@@ -57,6 +61,19 @@ pub struct CheckArgs {
     #[arg(long)]
     pub extend_disable: Option<String>,
 
+    /// Enable only rules with one of these tags (comma-separated, e.g. "heading,table")
+    #[arg(long)]
+    pub enable_tags: Option<String>,
+
+    /// Disable rules with one of these tags (comma-separated, e.g. "blockquote,whitespace")
+    #[arg(long)]
+    pub disable_tags: Option<String>,
+
+    /// Use a named style preset ("relaxed", "strict") or a path to a preset config
+    /// file as a curated baseline, overriding any `style` set in a config file
+    #[arg(long, help = "Use a style preset (\"relaxed\", \"strict\", or a preset file path) as a baseline config")]
+    pub style: Option<String>,
+
     /// Exclude specific files or directories (comma-separated glob patterns)
     #[arg(long)]
     pub exclude: Option<String>,
@@ -101,7 +118,7 @@ pub struct CheckArgs {
     pub output: String,
 
     /// Output format for linting results
-    #[arg(long, value_parser = ["text", "full", "concise", "grouped", "json", "json-lines", "github", "gitlab", "pylint", "azure", "sarif", "junit"],
+    #[arg(long, value_parser = ["text", "full", "concise", "grouped", "json", "json-lines", "github", "gitlab", "pylint", "azure", "sarif", "junit", "checkstyle", "fixes-json", "annotated"],
           help = "Output format (default: text, or $RUMDL_OUTPUT_FORMAT, or output-format in config)")]
     pub output_format: Option<String>,
 
@@ -166,4 +183,28 @@ pub struct CheckArgs {
 
     #[arg(skip)]
     pub fail_on_mode: FailOn,
+
+    /// Restrict reported warnings to specific line ranges (may be passed multiple
+    /// times). Rules still see the whole file, but warnings outside these ranges
+    /// are dropped; useful for linting only the lines a diff touched.
+    #[arg(long, value_name = "PATH:START-END", help = "Only report warnings in PATH between lines START and END (repeatable)")]
+    pub file_lines: Vec<String>,
+
+    /// Same as `--file-lines`, but as a single JSON array:
+    /// `[{"file":"a.md","range":[10,40]}]`. Combined with `--file-lines` if both are given.
+    #[arg(long, value_name = "JSON", help = "Only report warnings per a JSON array of {file, range} restrictions")]
+    pub file_lines_json: Option<String>,
+
+    /// Restrict linting of the current file to specific line ranges (may be
+    /// passed multiple times). Unlike `--file-lines`, this is threaded into
+    /// `LintContext` itself: warnings outside these ranges are dropped, and
+    /// MD016 skips out-of-range lines in its own checks. Fixes applied with
+    /// `--fix` or `--diff` are not limited to these ranges.
+    #[arg(long, value_name = "START:END", help = "Only report warnings for lines START through END, inclusive (repeatable)")]
+    pub lines: Vec<String>,
+
+    /// Restrict linting to the lines touched by `git diff` against the
+    /// working tree, parsed from unified diff hunk headers.
+    #[arg(long, help = "Only report warnings for lines changed according to `git diff`")]
+    pub diff_only: bool,
 }
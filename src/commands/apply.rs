@@ -0,0 +1,120 @@
+//! Handler for the `apply` command.
+//!
+//! Reads a fixes-json document produced by `check --output-format=fixes-json`
+//! (from stdin or a path) and applies the edits to the referenced files using
+//! the same conflict-aware algorithm the normal `--fix` uses.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read};
+
+use colored::*;
+
+use rumdl_lib::exit_codes::exit;
+use rumdl_lib::fix_coordinator::{FixExport, apply_suggestions};
+use rumdl_lib::rule::Applicability;
+
+/// The shape of a fixes-json document: file path -> list of proposed edits.
+type FixDocument = BTreeMap<String, Vec<FixExport>>;
+
+/// Handle the apply command.
+pub fn handle_apply(input: &str, unsafe_fixes: bool, rules: Option<&str>) {
+    let raw = match read_input(input) {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("{}: {}", "Error reading fixes document".red().bold(), e);
+            exit::tool_error();
+        }
+    };
+
+    let document: FixDocument = match serde_json::from_str(&raw) {
+        Ok(doc) => doc,
+        Err(e) => {
+            eprintln!("{}: {}", "Error parsing fixes document".red().bold(), e);
+            exit::tool_error();
+        }
+    };
+
+    let rule_filter: Option<Vec<String>> = rules.map(|spec| {
+        spec.split(',')
+            .map(|r| r.trim().to_uppercase())
+            .filter(|r| !r.is_empty())
+            .collect()
+    });
+
+    let mut total_applied = 0usize;
+    let mut total_skipped = 0usize;
+    let mut files_changed = 0usize;
+
+    for (file, exports) in document {
+        let suggestions: Vec<_> = exports
+            .into_iter()
+            .filter(|export| {
+                if let Some(filter) = &rule_filter
+                    && !filter.iter().any(|r| r == &export.rule.to_uppercase())
+                {
+                    return false;
+                }
+                if export.applicability == Applicability::Unsafe && !unsafe_fixes {
+                    total_skipped += 1;
+                    return false;
+                }
+                true
+            })
+            .map(FixExport::into_suggestion)
+            .collect();
+
+        if suggestions.is_empty() {
+            continue;
+        }
+
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("{}: {}: {}", "Error reading".red().bold(), file, e);
+                continue;
+            }
+        };
+
+        let (fixed, report) = apply_suggestions(&content, suggestions);
+        total_skipped += report.deferred.len();
+
+        if fixed != content {
+            if let Err(e) = fs::write(&file, &fixed) {
+                eprintln!("{}: {}: {}", "Error writing".red().bold(), file, e);
+                continue;
+            }
+            files_changed += 1;
+            total_applied += report.applied.len();
+            println!(
+                "{} {} ({} fix(es))",
+                "Fixed".green().bold(),
+                file,
+                report.applied.len()
+            );
+        }
+    }
+
+    println!(
+        "\n{} {} fix(es) across {} file(s){}",
+        "Applied".green().bold(),
+        total_applied,
+        files_changed,
+        if total_skipped > 0 {
+            format!(", {total_skipped} skipped").dimmed().to_string()
+        } else {
+            String::new()
+        }
+    );
+}
+
+/// Read the fixes document from stdin (when `input` is "-") or a file path.
+fn read_input(input: &str) -> io::Result<String> {
+    if input == "-" {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf)?;
+        Ok(buf)
+    } else {
+        fs::read_to_string(input)
+    }
+}
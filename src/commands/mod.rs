@@ -3,6 +3,7 @@
 //! Each subcommand has its own module with a public handler function
 //! that `main()` dispatches to.
 
+pub mod apply;
 pub mod check;
 pub mod clean;
 pub mod completions;
@@ -100,6 +100,17 @@ pub struct GlobalConfig {
     /// Takes precedence over fixable
     #[serde(default)]
     pub unfixable: Vec<String>,
+
+    /// Apply fixes marked as unsafe (potentially content-altering) in addition
+    /// to the safe ones. Off by default; enabled via `--unsafe-fixes`.
+    #[serde(default)]
+    pub unsafe_fixes: bool,
+
+    /// Name of a built-in style preset (e.g. "relaxed", "strict") or a path to a
+    /// preset config file, merged in beneath the rest of this configuration.
+    /// See [`crate::presets`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
 }
 
 fn default_respect_gitignore() -> bool {
@@ -123,6 +134,8 @@ impl Default for GlobalConfig {
             output_format: None,
             fixable: Vec::new(),
             unfixable: Vec::new(),
+            unsafe_fixes: false,
+            style: None,
         }
     }
 }
@@ -391,6 +404,36 @@ line-length = 222
         assert_eq!(v2, Some(222));
     }
 
+    #[test]
+    fn test_rumdl_ron_config_loads() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.ron");
+        let config_content = r#"{
+            "MD013": {
+                "line-length": 99,
+            },
+        }"#;
+        fs::write(&config_path, config_content).unwrap();
+        let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+        let rule_cfg = sourced.rules.get("MD013").expect("MD013 rule config should exist");
+        let val = &rule_cfg.values["line-length"].value;
+        assert_eq!(val.as_integer(), Some(99));
+    }
+
+    #[test]
+    fn test_rumdl_ron_without_leading_dot_loads_global_section() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("rumdl.ron");
+        let config_content = r#"{
+            "global": {
+                "disable": ["MD001"],
+            },
+        }"#;
+        fs::write(&config_path, config_content).unwrap();
+        let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+        assert!(sourced.global.disable.value.contains(&"MD001".to_string()));
+    }
+
     #[test]
     fn test_md013_section_case_insensitivity() {
         let temp_dir = tempdir().unwrap();
@@ -914,11 +957,31 @@ local_time = 07:32:00
             "Default config from rumdl init should pass validation without warnings"
         );
     }
+
+    #[test]
+    fn test_style_key_parsed_from_rumdl_toml_and_pyproject_toml() {
+        let temp_dir = tempdir().unwrap();
+
+        let rumdl_toml_path = temp_dir.path().join(".rumdl.toml");
+        fs::write(&rumdl_toml_path, "[global]\nstyle = \"relaxed\"\n").unwrap();
+        let sourced = SourcedConfig::load_with_discovery(Some(rumdl_toml_path.to_str().unwrap()), None, true).unwrap();
+        assert_eq!(sourced.global.style.as_ref().map(|v| v.value.as_str()), Some("relaxed"));
+        assert_eq!(sourced.global.style.as_ref().map(|v| v.source), Some(ConfigSource::RumdlToml));
+
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+        fs::write(&pyproject_path, "[tool.rumdl]\nstyle = \"strict\"\n").unwrap();
+        let sourced = SourcedConfig::load_with_discovery(Some(pyproject_path.to_str().unwrap()), None, true).unwrap();
+        assert_eq!(sourced.global.style.as_ref().map(|v| v.value.as_str()), Some("strict"));
+        assert_eq!(sourced.global.style.as_ref().map(|v| v.source), Some(ConfigSource::PyprojectToml));
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfigSource {
     Default,
+    /// Value came from a named style preset (see [`crate::presets`]), merged in
+    /// beneath every real config source so the user's own settings always win.
+    Preset,
     RumdlToml,
     PyprojectToml,
     Cli,
@@ -969,10 +1032,11 @@ impl<T: Clone> SourcedValue<T> {
         fn source_precedence(src: ConfigSource) -> u8 {
             match src {
                 ConfigSource::Default => 0,
-                ConfigSource::PyprojectToml => 1,
-                ConfigSource::Markdownlint => 2,
-                ConfigSource::RumdlToml => 3,
-                ConfigSource::Cli => 4,
+                ConfigSource::Preset => 1,
+                ConfigSource::PyprojectToml => 2,
+                ConfigSource::Markdownlint => 3,
+                ConfigSource::RumdlToml => 4,
+                ConfigSource::Cli => 5,
             }
         }
 
@@ -1013,6 +1077,8 @@ pub struct SourcedGlobalConfig {
     pub output_format: Option<SourcedValue<String>>,
     pub fixable: SourcedValue<Vec<String>>,
     pub unfixable: SourcedValue<Vec<String>>,
+    pub unsafe_fixes: SourcedValue<bool>,
+    pub style: Option<SourcedValue<String>>,
 }
 
 impl Default for SourcedGlobalConfig {
@@ -1027,6 +1093,8 @@ impl Default for SourcedGlobalConfig {
             output_format: None,
             fixable: SourcedValue::new(Vec::new(), ConfigSource::Default),
             unfixable: SourcedValue::new(Vec::new(), ConfigSource::Default),
+            unsafe_fixes: SourcedValue::new(false, ConfigSource::Default),
+            style: None,
         }
     }
 }
@@ -1116,6 +1184,12 @@ impl SourcedConfig {
             fragment.global.unfixable.overrides.first().and_then(|o| o.file.clone()),
             fragment.global.unfixable.overrides.first().and_then(|o| o.line),
         );
+        self.global.unsafe_fixes.merge_override(
+            fragment.global.unsafe_fixes.value,
+            fragment.global.unsafe_fixes.source,
+            fragment.global.unsafe_fixes.overrides.first().and_then(|o| o.file.clone()),
+            fragment.global.unsafe_fixes.overrides.first().and_then(|o| o.line),
+        );
 
         // Merge output_format if present
         if let Some(output_format_fragment) = fragment.global.output_format {
@@ -1131,6 +1205,20 @@ impl SourcedConfig {
             }
         }
 
+        // Merge style if present
+        if let Some(style_fragment) = fragment.global.style {
+            if let Some(ref mut style) = self.global.style {
+                style.merge_override(
+                    style_fragment.value,
+                    style_fragment.source,
+                    style_fragment.overrides.first().and_then(|o| o.file.clone()),
+                    style_fragment.overrides.first().and_then(|o| o.line),
+                );
+            } else {
+                self.global.style = Some(style_fragment);
+            }
+        }
+
         // Merge rule configs
         for (rule_name, rule_fragment) in fragment.rules {
             let norm_rule_name = rule_name.to_ascii_uppercase(); // Normalize to uppercase for case-insensitivity
@@ -1162,7 +1250,7 @@ impl SourcedConfig {
     fn discover_config_upward() -> Option<std::path::PathBuf> {
         use std::env;
 
-        const CONFIG_FILES: &[&str] = &[".rumdl.toml", "rumdl.toml", "pyproject.toml"];
+        const CONFIG_FILES: &[&str] = &[".rumdl.toml", "rumdl.toml", ".rumdl.ron", "rumdl.ron", "pyproject.toml"];
         const MAX_DEPTH: usize = 100; // Prevent infinite traversal
 
         let start_dir = match env::current_dir() {
@@ -1258,7 +1346,12 @@ impl SourcedConfig {
             // Known markdownlint config files
             const MARKDOWNLINT_FILENAMES: &[&str] = &[".markdownlint.json", ".markdownlint.yaml", ".markdownlint.yml"];
 
-            if filename == "pyproject.toml" || filename == ".rumdl.toml" || filename == "rumdl.toml" {
+            if filename == "pyproject.toml"
+                || filename == ".rumdl.toml"
+                || filename == "rumdl.toml"
+                || filename == ".rumdl.ron"
+                || filename == "rumdl.ron"
+            {
                 let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
                     source: e,
                     path: path_str.clone(),
@@ -1268,6 +1361,10 @@ impl SourcedConfig {
                         sourced_config.merge(fragment);
                         sourced_config.loaded_files.push(path_str.clone());
                     }
+                } else if filename == ".rumdl.ron" || filename == "rumdl.ron" {
+                    let fragment = parse_rumdl_ron(&content, &path_str)?;
+                    sourced_config.merge(fragment);
+                    sourced_config.loaded_files.push(path_str.clone());
                 } else {
                     let fragment = parse_rumdl_toml(&content, &path_str)?;
                     sourced_config.merge(fragment);
@@ -1284,6 +1381,14 @@ impl SourcedConfig {
                 sourced_config.merge(fragment);
                 sourced_config.loaded_files.push(path_str.clone());
                 // markdownlint is fallback only
+            } else if path_str.ends_with(".ron") {
+                let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
+                    source: e,
+                    path: path_str.clone(),
+                })?;
+                let fragment = parse_rumdl_ron(&content, &path_str)?;
+                sourced_config.merge(fragment);
+                sourced_config.loaded_files.push(path_str.clone());
             } else {
                 // Try TOML only
                 let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
@@ -1322,6 +1427,14 @@ impl SourcedConfig {
                     let fragment = parse_rumdl_toml(&content, &path_str)?;
                     sourced_config.merge(fragment);
                     sourced_config.loaded_files.push(path_str);
+                } else if filename == ".rumdl.ron" || filename == "rumdl.ron" {
+                    let content = std::fs::read_to_string(&config_file).map_err(|e| ConfigError::IoError {
+                        source: e,
+                        path: path_str.clone(),
+                    })?;
+                    let fragment = parse_rumdl_ron(&content, &path_str)?;
+                    sourced_config.merge(fragment);
+                    sourced_config.loaded_files.push(path_str);
                 }
             } else {
                 log::debug!("[rumdl-config] No configuration file found via upward traversal");
@@ -1376,6 +1489,14 @@ impl SourcedConfig {
                 .global
                 .unfixable
                 .merge_override(cli.unfixable.value.clone(), ConfigSource::Cli, None, None);
+            // `--unsafe-fixes` is opt-in: only override when the flag is set so
+            // it cannot silently clear a config-file `unsafe_fixes = true`.
+            if cli.unsafe_fixes.value {
+                sourced_config
+                    .global
+                    .unsafe_fixes
+                    .merge_override(true, ConfigSource::Cli, None, None);
+            }
             // No rule-specific CLI overrides implemented yet
         }
 
@@ -1383,6 +1504,29 @@ impl SourcedConfig {
 
         Ok(sourced_config)
     }
+
+    /// Resolves and merges the named style preset (`style = "..."` in a config
+    /// file, or `cli_style` from `--style`) into this config, as a low-precedence
+    /// fragment that only fills in values the user hasn't already set elsewhere.
+    ///
+    /// `cli_style`, when given, overrides any config-file `style` value (it is
+    /// applied with [`ConfigSource::Cli`], the highest precedence). See
+    /// [`crate::presets`] for how preset names and file paths are resolved.
+    pub fn apply_style(&mut self, cli_style: Option<&str>) -> Result<(), ConfigError> {
+        if let Some(style) = cli_style {
+            match &mut self.global.style {
+                Some(sv) => sv.merge_override(style.to_string(), ConfigSource::Cli, None, None),
+                None => self.global.style = Some(SourcedValue::new(style.to_string(), ConfigSource::Cli)),
+            }
+        }
+
+        if let Some(style_name) = self.global.style.as_ref().map(|sv| sv.value.clone()) {
+            let fragment = crate::presets::resolve_preset(&style_name)?;
+            self.merge(fragment);
+        }
+
+        Ok(())
+    }
 }
 
 impl From<SourcedConfig> for Config {
@@ -1407,6 +1551,8 @@ impl From<SourcedConfig> for Config {
             output_format: sourced.global.output_format.as_ref().map(|v| v.value.clone()),
             fixable: sourced.global.fixable.value,
             unfixable: sourced.global.unfixable.value,
+            unsafe_fixes: sourced.global.unsafe_fixes.value,
+            style: sourced.global.style.as_ref().map(|v| v.value.clone()),
         };
         Config { global, rules }
     }
@@ -1658,6 +1804,20 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
                     .push_override(value, source, file.clone(), None);
             }
         }
+        if let Some(style) = rumdl_table.get("style")
+            && let Ok(value) = String::deserialize(style.clone())
+        {
+            if fragment.global.style.is_none() {
+                fragment.global.style = Some(SourcedValue::new(value.clone(), source));
+            } else {
+                fragment
+                    .global
+                    .style
+                    .as_mut()
+                    .unwrap()
+                    .push_override(value, source, file.clone(), None);
+            }
+        }
         if let Some(fixable) = rumdl_table.get("fixable")
             && let Ok(values) = Vec::<String>::deserialize(fixable.clone())
         {
@@ -1676,6 +1836,16 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
                 .unfixable
                 .push_override(normalized_values, source, file.clone(), None);
         }
+        if let Some(unsafe_fixes) = rumdl_table
+            .get("unsafe-fixes")
+            .or_else(|| rumdl_table.get("unsafe_fixes"))
+            && let Ok(value) = bool::deserialize(unsafe_fixes.clone())
+        {
+            fragment
+                .global
+                .unsafe_fixes
+                .push_override(value, source, file.clone(), None);
+        }
 
         // --- Re-introduce special line-length handling ---
         let mut found_line_length_val: Option<toml::Value> = None;
@@ -1717,6 +1887,7 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
                 "line-length",
                 "output_format",
                 "output-format",
+                "style",
                 "fixable",
                 "unfixable",
             ]
@@ -1815,12 +1986,27 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
         || !fragment.global.fixable.value.is_empty()
         || !fragment.global.unfixable.value.is_empty()
         || fragment.global.output_format.is_some()
+        || fragment.global.style.is_some()
         || !fragment.rules.is_empty();
     if has_any { Ok(Some(fragment)) } else { Ok(None) }
 }
 
+/// Parses rumdl.ron / .rumdl.ron content.
+///
+/// RON has no dedicated config-fragment parser of its own: a RON document deserializes
+/// cleanly into a [`toml::Value`] (enums, nested structs, and optional fields all map onto
+/// TOML's data model), so this re-serializes that value back to TOML text and hands it to
+/// [`parse_rumdl_toml`], reusing all of its section handling and source tracking.
+fn parse_rumdl_ron(content: &str, path: &str) -> Result<SourcedConfigFragment, ConfigError> {
+    let value: toml::Value =
+        ron::from_str(content).map_err(|e| ConfigError::ParseError(format!("{path}: Failed to parse RON: {e}")))?;
+    let toml_content = toml::to_string(&value)
+        .map_err(|e| ConfigError::ParseError(format!("{path}: Failed to convert RON to TOML: {e}")))?;
+    parse_rumdl_toml(&toml_content, path)
+}
+
 /// Parses rumdl.toml / .rumdl.toml content.
-fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment, ConfigError> {
+pub(crate) fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment, ConfigError> {
     let doc = content
         .parse::<DocumentMut>()
         .map_err(|e| ConfigError::ParseError(format!("{path}: Failed to parse TOML: {e}")))?;
@@ -1952,6 +2138,28 @@ fn parse_rumdl_toml(content: &str, path: &str) -> Result<SourcedConfigFragment,
                         );
                     }
                 }
+                "style" => {
+                    if let Some(toml_edit::Value::String(formatted_string)) = value_item.as_value() {
+                        let val = formatted_string.value().clone();
+                        if fragment.global.style.is_none() {
+                            fragment.global.style = Some(SourcedValue::new(val.clone(), source));
+                        } else {
+                            fragment
+                                .global
+                                .style
+                                .as_mut()
+                                .unwrap()
+                                .push_override(val, source, file.clone(), None);
+                        }
+                    } else {
+                        log::warn!(
+                            "[WARN] Expected string for global key '{}' in {}, found {}",
+                            key,
+                            path,
+                            value_item.type_name()
+                        );
+                    }
+                }
                 "fixable" => {
                     if let Some(toml_edit::Value::Array(formatted_array)) = value_item.as_value() {
                         let values: Vec<String> = formatted_array
@@ -923,6 +923,7 @@ impl From<SourcedConfig<ConfigValidated>> for Config {
             output_format: sourced.global.output_format.as_ref().map(|v| v.value.clone()),
             fixable: sourced.global.fixable.value,
             unfixable: sourced.global.unfixable.value,
+            unsafe_fixes: sourced.global.unsafe_fixes.value,
             flavor: sourced.global.flavor.value,
             force_exclude: sourced.global.force_exclude.value,
             cache_dir: sourced.global.cache_dir.as_ref().map(|v| v.value.clone()),
@@ -0,0 +1,170 @@
+//! `--lines`/`--diff-only` support: restricting a single lint pass to a set
+//! of inclusive line ranges, the same capability rustfmt exposes as
+//! `file_lines`.
+//!
+//! Unlike [`crate::line_ranges::LineRanges`] (which maps *multiple files* to
+//! their own ranges and only filters which warnings get reported), a
+//! [`FileLines`] applies to the *single* file currently being linted and is
+//! threaded all the way into [`crate::lint_context::LintContext`], so rules
+//! can also use it to keep `fix()` from rewriting bytes outside the allowed
+//! ranges.
+
+use std::ops::RangeInclusive;
+
+/// An optional set of inclusive line ranges a lint pass is restricted to.
+/// `None` (via [`FileLines::all`]) means every line is allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileLines {
+    ranges: Option<Vec<RangeInclusive<usize>>>,
+}
+
+impl FileLines {
+    /// No restriction: every line is allowed.
+    pub fn all() -> Self {
+        Self { ranges: None }
+    }
+
+    /// Restrict to exactly the given ranges.
+    pub fn from_ranges(ranges: Vec<RangeInclusive<usize>>) -> Self {
+        Self { ranges: Some(ranges) }
+    }
+
+    /// Parse repeatable `--lines START:END` specs into ranges.
+    pub fn parse_specs(specs: &[String]) -> Result<Self, String> {
+        let mut ranges = Vec::with_capacity(specs.len());
+        for spec in specs {
+            ranges.push(Self::parse_spec(spec)?);
+        }
+        Ok(Self::from_ranges(ranges))
+    }
+
+    /// Parse a single `START:END` spec (1-indexed, inclusive on both ends).
+    pub fn parse_spec(spec: &str) -> Result<RangeInclusive<usize>, String> {
+        let (start, end) = spec
+            .split_once(':')
+            .ok_or_else(|| format!("invalid --lines spec '{spec}', expected START:END"))?;
+        let start: usize = start
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid start line in --lines spec '{spec}'"))?;
+        let end: usize = end
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid end line in --lines spec '{spec}'"))?;
+        if start == 0 || end < start {
+            return Err(format!("invalid --lines spec '{spec}': lines are 1-indexed and end must be >= start"));
+        }
+        Ok(start..=end)
+    }
+
+    /// Parse unified diff hunk headers (`@@ -a,b +c,d @@`) into the ranges of
+    /// the *new* file each hunk touches. A hunk with a zero new-line count
+    /// (pure deletion) contributes no range, since there is nothing left in
+    /// the new file to restrict linting to.
+    pub fn parse_diff_hunks(diff: &str) -> Vec<RangeInclusive<usize>> {
+        let mut ranges = Vec::new();
+        for line in diff.lines() {
+            let Some(rest) = line.strip_prefix("@@ ") else { continue };
+            let Some(new_part) = rest.split(' ').find(|tok| tok.starts_with('+')) else {
+                continue;
+            };
+            let new_part = &new_part[1..];
+            let (start_str, count_str) = new_part.split_once(',').unwrap_or((new_part, "1"));
+            let Ok(start) = start_str.parse::<usize>() else { continue };
+            let Ok(count) = count_str.parse::<usize>() else { continue };
+            if count == 0 || start == 0 {
+                continue;
+            }
+            ranges.push(start..=(start + count - 1));
+        }
+        ranges
+    }
+
+    /// Whether `line` (1-indexed) falls inside an allowed range, or whether
+    /// there is no restriction at all.
+    pub fn contains(&self, line: usize) -> bool {
+        match &self.ranges {
+            None => true,
+            Some(ranges) => ranges.iter().any(|r| r.contains(&line)),
+        }
+    }
+
+    /// Combine two restrictions, unioning their ranges. A restriction merged
+    /// with [`FileLines::all`] stays unrestricted, matching the "no filter
+    /// wins" semantics `--lines`/`--diff-only` share when only one is passed.
+    pub fn merge(self, other: FileLines) -> FileLines {
+        match (self.ranges, other.ranges) {
+            (None, _) | (_, None) => FileLines::all(),
+            (Some(mut a), Some(b)) => {
+                a.extend(b);
+                FileLines::from_ranges(a)
+            }
+        }
+    }
+
+    pub fn into_ranges(self) -> Option<Vec<RangeInclusive<usize>>> {
+        self.ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unrestricted_allows_every_line() {
+        let fl = FileLines::all();
+        assert!(fl.contains(1));
+        assert!(fl.contains(9999));
+    }
+
+    #[test]
+    fn test_parse_spec_restricts_to_range() {
+        let fl = FileLines::parse_specs(&["10:20".to_string()]).unwrap();
+        assert!(!fl.contains(9));
+        assert!(fl.contains(10));
+        assert!(fl.contains(20));
+        assert!(!fl.contains(21));
+    }
+
+    #[test]
+    fn test_parse_spec_rejects_malformed_input() {
+        assert!(FileLines::parse_spec("abc").is_err());
+        assert!(FileLines::parse_spec("5:2").is_err());
+        assert!(FileLines::parse_spec("0:5").is_err());
+    }
+
+    #[test]
+    fn test_parse_diff_hunks() {
+        let diff = "diff --git a/foo.md b/foo.md\n\
+                     @@ -10,3 +10,5 @@ Some context\n\
+                     context line\n\
+                     +added\n\
+                     +added\n";
+        let ranges = FileLines::parse_diff_hunks(diff);
+        assert_eq!(ranges, vec![10..=14]);
+    }
+
+    #[test]
+    fn test_parse_diff_hunks_skips_pure_deletions() {
+        let diff = "@@ -5,3 +4,0 @@\n-removed\n-removed\n-removed\n";
+        assert!(FileLines::parse_diff_hunks(diff).is_empty());
+    }
+
+    #[test]
+    fn test_merge_unions_ranges() {
+        let a = FileLines::from_ranges(vec![1..=5]);
+        let b = FileLines::from_ranges(vec![10..=15]);
+        let merged = a.merge(b);
+        assert!(merged.contains(3));
+        assert!(merged.contains(12));
+        assert!(!merged.contains(8));
+    }
+
+    #[test]
+    fn test_merge_with_unrestricted_stays_unrestricted() {
+        let a = FileLines::from_ranges(vec![1..=5]);
+        let merged = a.merge(FileLines::all());
+        assert!(merged.contains(9999));
+    }
+}
@@ -12,6 +12,71 @@ use std::collections::HashSet;
 use std::error::Error;
 use std::path::Path;
 
+/// Resolve a single `--enable`/`--disable` (or config `enable`/`disable`)
+/// token to the set of concrete rule names it selects.
+///
+/// A token that matches a rule name exactly, or one of a rule's
+/// [`Rule::aliases`] case-insensitively (e.g. mdl's `header-increment` for
+/// MD001), resolves to that one rule. A token shaped like `MD` followed by
+/// one or two digits (e.g. `MD01`) is instead treated as a numeric-code
+/// prefix, ruff-`--select`-style, and expands to every rule whose code
+/// starts with those digits (so `MD01` selects MD010 through MD019). A
+/// token matching one of [`Rule::tags`]'s category names, singular or
+/// plural (e.g. `list`/`lists`, `heading`/`headings`), expands to every
+/// rule carrying that tag. Anything else is returned unchanged, on the
+/// assumption it's already a full `MD0xx` code.
+fn resolve_token(token: &str, all_rules: &[Box<dyn Rule>]) -> HashSet<String> {
+    if let Some(rule) = all_rules
+        .iter()
+        .find(|rule| rule.name().eq_ignore_ascii_case(token) || rule.aliases().iter().any(|alias| alias.eq_ignore_ascii_case(token)))
+    {
+        return std::iter::once(rule.name().to_string()).collect();
+    }
+
+    if let Some(digits) = token.strip_prefix("MD").or_else(|| token.strip_prefix("md"))
+        && !digits.is_empty()
+        && digits.len() <= 2
+        && digits.chars().all(|c| c.is_ascii_digit())
+    {
+        let by_prefix: HashSet<String> = all_rules
+            .iter()
+            .filter(|rule| rule.name().strip_prefix("MD").is_some_and(|code| code.starts_with(digits)))
+            .map(|rule| rule.name().to_string())
+            .collect();
+        if !by_prefix.is_empty() {
+            return by_prefix;
+        }
+    }
+
+    let lower = token.to_ascii_lowercase();
+    let singular = lower.strip_suffix('s').unwrap_or(&lower);
+    let by_tag: HashSet<String> = all_rules
+        .iter()
+        .filter(|rule| rule.tags().iter().any(|tag| *tag == lower || *tag == singular))
+        .map(|rule| rule.name().to_string())
+        .collect();
+    if !by_tag.is_empty() {
+        return by_tag;
+    }
+
+    std::iter::once(token.to_string()).collect()
+}
+
+/// Expand a comma-separated list of tag tokens (from `--enable-tags` /
+/// `--disable-tags`) into the set of rule names carrying any of those tags.
+fn expand_tags(tags_str: &str, all_rules: &[Box<dyn Rule>]) -> HashSet<String> {
+    let wanted: HashSet<String> = tags_str
+        .split(',')
+        .map(|t| t.trim().to_ascii_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    all_rules
+        .iter()
+        .filter(|rule| rule.tags().iter().any(|tag| wanted.contains(*tag)))
+        .map(|rule| rule.name().to_string())
+        .collect()
+}
+
 pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_config::Config) -> Vec<Box<dyn Rule>> {
     // 1. Initialize all available rules using from_config only
     let all_rules: Vec<Box<dyn Rule>> = rumdl_lib::rules::all_rules(config);
@@ -19,28 +84,36 @@ pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_
     // 2. Determine the final list of enabled rules based on precedence
     let final_rules: Vec<Box<dyn Rule>>;
 
-    // Rule names provided via CLI flags
-    let cli_enable_set: Option<HashSet<&str>> = args
-        .enable
-        .as_deref()
-        .map(|s| s.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()).collect());
-    let cli_disable_set: Option<HashSet<&str>> = args
-        .disable
-        .as_deref()
-        .map(|s| s.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()).collect());
-    let cli_extend_enable_set: Option<HashSet<&str>> = args
-        .extend_enable
-        .as_deref()
-        .map(|s| s.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()).collect());
-    let cli_extend_disable_set: Option<HashSet<&str>> = args
-        .extend_disable
-        .as_deref()
-        .map(|s| s.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()).collect());
+    // Rule names provided via CLI flags. Each token is resolved against the
+    // rules' aliases and numeric-code prefixes first, so `--enable
+    // header-increment` and `--enable MD01` both work the same as an exact
+    // `--enable MD001`.
+    let split_and_resolve = |s: &str| -> HashSet<String> {
+        s.split(',')
+            .map(|r| r.trim())
+            .filter(|r| !r.is_empty())
+            .flat_map(|r| resolve_token(r, &all_rules))
+            .collect()
+    };
+    let cli_enable_set: Option<HashSet<String>> = args.enable.as_deref().map(split_and_resolve);
+    let cli_disable_set: Option<HashSet<String>> = args.disable.as_deref().map(split_and_resolve);
+    let cli_extend_enable_set: Option<HashSet<String>> = args.extend_enable.as_deref().map(split_and_resolve);
+    let cli_extend_disable_set: Option<HashSet<String>> = args.extend_disable.as_deref().map(split_and_resolve);
 
     // Rule names provided via config file
-    let config_enable_set: HashSet<&str> = config.global.enable.iter().map(|s| s.as_str()).collect();
+    let config_enable_set: HashSet<String> = config
+        .global
+        .enable
+        .iter()
+        .flat_map(|s| resolve_token(s, &all_rules))
+        .collect();
 
-    let config_disable_set: HashSet<&str> = config.global.disable.iter().map(|s| s.as_str()).collect();
+    let config_disable_set: HashSet<String> = config
+        .global
+        .disable
+        .iter()
+        .flat_map(|s| resolve_token(s, &all_rules))
+        .collect();
 
     if let Some(enabled_cli) = &cli_enable_set {
         // CLI --enable completely overrides config (ruff --select behavior)
@@ -80,7 +153,7 @@ pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_
             if !config_enable_set.is_empty() {
                 let mut extended_enable_set = config_enable_set.clone();
                 for rule in extend_enabled_cli {
-                    extended_enable_set.insert(rule);
+                    extended_enable_set.insert(rule.clone());
                 }
 
                 // Re-filter with extended set
@@ -159,6 +232,22 @@ pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_
         final_rules = current_rules; // Assign the final filtered vector
     }
 
+    // 3. Narrow (or further restrict) the result by `--enable-tags`/
+    // `--disable-tags`, layered on top of whatever `--enable`/`--disable`
+    // and the config already settled on.
+    let mut final_rules = final_rules;
+    if args.disable_tags.is_some() || args.enable_tags.is_some() {
+        let rules_for_tags = rumdl_lib::rules::all_rules(config);
+        if let Some(disable_tags) = args.disable_tags.as_deref() {
+            let disabled_by_tag = expand_tags(disable_tags, &rules_for_tags);
+            final_rules.retain(|rule| !disabled_by_tag.contains(rule.name()));
+        }
+        if let Some(enable_tags) = args.enable_tags.as_deref() {
+            let enabled_by_tag = expand_tags(enable_tags, &rules_for_tags);
+            final_rules.retain(|rule| enabled_by_tag.contains(rule.name()));
+        }
+    }
+
     // 4. Print enabled rules if verbose
     if args.verbose {
         println!("Enabled rules:");
@@ -453,12 +542,14 @@ pub fn process_file_with_formatter(
     output_format: &rumdl_lib::output::OutputFormat,
     output_writer: &rumdl_lib::output::OutputWriter,
     config: &rumdl_config::Config,
+    line_ranges: Option<&rumdl_lib::line_ranges::LineRanges>,
+    file_lines: Option<&rumdl_lib::file_lines::FileLines>,
 ) -> (bool, usize, usize, usize, Vec<rumdl_lib::rule::LintWarning>) {
     let formatter = output_format.create_formatter();
 
     // Call the original process_file_inner to get warnings and original line ending
     let (all_warnings, mut content, total_warnings, fixable_warnings, original_line_ending) =
-        process_file_inner(file_path, rules, verbose, quiet, config);
+        process_file_inner(file_path, rules, verbose, quiet, config, line_ranges, file_lines);
 
     if total_warnings == 0 {
         return (false, 0, 0, 0, Vec::new());
@@ -471,7 +562,7 @@ pub fn process_file_with_formatter(
             let unfixable_warnings: Vec<_> = all_warnings.iter().filter(|w| w.fix.is_none()).cloned().collect();
 
             if !unfixable_warnings.is_empty() {
-                let formatted = formatter.format_warnings(&unfixable_warnings, file_path);
+                let formatted = formatter.format_warnings_with_content(&unfixable_warnings, file_path, &content);
                 if !formatted.is_empty() {
                     output_writer.writeln(&formatted).unwrap_or_else(|e| {
                         eprintln!("Error writing output: {e}");
@@ -480,7 +571,7 @@ pub fn process_file_with_formatter(
             }
         } else {
             // In check mode, show all warnings with [*] for fixable issues
-            let formatted = formatter.format_warnings(&all_warnings, file_path);
+            let formatted = formatter.format_warnings_with_content(&all_warnings, file_path, &content);
             if !formatted.is_empty() {
                 output_writer.writeln(&formatted).unwrap_or_else(|e| {
                     eprintln!("Error writing output: {e}");
@@ -586,6 +677,30 @@ pub fn process_file_with_formatter(
                     eprintln!("Error writing output: {e}");
                 });
             }
+
+            // Note how many unsafe fixes were left unapplied so users know
+            // `--unsafe-fixes` could resolve more.
+            if !config.global.unsafe_fixes {
+                let unsafe_rules: std::collections::HashSet<&str> = rules
+                    .iter()
+                    .filter(|r| r.fix_applicability() == rumdl_lib::rule::Applicability::Unsafe)
+                    .map(|r| r.name())
+                    .collect();
+                let unsafe_skipped = all_warnings
+                    .iter()
+                    .filter(|w| w.fix.is_some() && w.rule_name.is_some_and(|n| unsafe_rules.contains(n)))
+                    .count();
+                if unsafe_skipped > 0 {
+                    output_writer
+                        .writeln(&format!(
+                            "{} {unsafe_skipped} unsafe fix(es) available; run with --unsafe-fixes to apply",
+                            "note:".cyan().bold()
+                        ))
+                        .unwrap_or_else(|e| {
+                            eprintln!("Error writing output: {e}");
+                        });
+                }
+            }
         }
     }
 
@@ -597,6 +712,8 @@ pub fn process_file_inner(
     verbose: bool,
     quiet: bool,
     config: &rumdl_config::Config,
+    line_ranges: Option<&rumdl_lib::line_ranges::LineRanges>,
+    file_lines: Option<&rumdl_lib::file_lines::FileLines>,
 ) -> (
     Vec<rumdl_lib::rule::LintWarning>,
     String,
@@ -652,8 +769,13 @@ pub fn process_file_inner(
         rules.to_vec()
     };
 
-    // Use the standard lint function with the configured flavor
-    let warnings_result = rumdl_lib::lint(&content, &filtered_rules, verbose, config.markdown_flavor());
+    // Use the standard lint function with the configured flavor. When `--lines`/
+    // `--diff-only` restricts this file, go through `lint_with_line_ranges`, which
+    // drops warnings outside the ranges (MD016 also skips those lines itself).
+    let warnings_result = match file_lines.and_then(|fl| fl.clone().into_ranges()) {
+        Some(ranges) => rumdl_lib::lint_with_line_ranges(&content, &filtered_rules, verbose, ranges),
+        None => rumdl_lib::lint(&content, &filtered_rules, verbose, config.markdown_flavor()),
+    };
 
     // Clear the environment variable after processing
     unsafe {
@@ -663,6 +785,11 @@ pub fn process_file_inner(
     // Combine all warnings
     let mut all_warnings = warnings_result.unwrap_or_default();
 
+    // Restrict to the requested line ranges (--file-lines / --file-lines-json), if any
+    if let Some(line_ranges) = line_ranges {
+        all_warnings.retain(|w| line_ranges.is_allowed(file_path, w.line));
+    }
+
     // Sort warnings by line number, then column
     all_warnings.sort_by(|a, b| {
         if a.line == b.line {
@@ -747,6 +874,8 @@ pub fn process_file_collect_warnings(
     verbose: bool,
     quiet: bool,
     config: &rumdl_config::Config,
+    line_ranges: Option<&rumdl_lib::line_ranges::LineRanges>,
+    file_lines: Option<&rumdl_lib::file_lines::FileLines>,
 ) -> Vec<rumdl_lib::rule::LintWarning> {
     if verbose && !quiet {
         println!("Processing file: {file_path}");
@@ -778,11 +907,17 @@ pub fn process_file_collect_warnings(
     unsafe {
         std::env::set_var("RUMDL_FILE_PATH", file_path);
     }
-    let warnings_result = rumdl_lib::lint(&content, &filtered_rules, verbose, config.markdown_flavor());
+    let warnings_result = match file_lines.and_then(|fl| fl.clone().into_ranges()) {
+        Some(ranges) => rumdl_lib::lint_with_line_ranges(&content, &filtered_rules, verbose, ranges),
+        None => rumdl_lib::lint(&content, &filtered_rules, verbose, config.markdown_flavor()),
+    };
     unsafe {
         std::env::remove_var("RUMDL_FILE_PATH");
     }
     let mut all_warnings = warnings_result.unwrap_or_default();
+    if let Some(line_ranges) = line_ranges {
+        all_warnings.retain(|w| line_ranges.is_allowed(file_path, w.line));
+    }
     all_warnings.sort_by(|a, b| {
         if a.line == b.line {
             a.column.cmp(&b.column)
@@ -1,8 +1,135 @@
 use crate::config::Config;
 use crate::lint_context::LintContext;
-use crate::rule::{LintWarning, Rule};
+use crate::rule::{Applicability, LintWarning, Rule};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
+/// A serializable fix suggestion, as emitted by `--output-format=fixes-json`
+/// and consumed by `rumdl apply`. Decouples detection from application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixExport {
+    /// The rule that proposed the edit (e.g. `"MD047"`).
+    pub rule: String,
+    /// The diagnostic message.
+    pub message: String,
+    /// The `[start, end)` byte range the edit replaces.
+    pub byte_range: [usize; 2],
+    /// The replacement text.
+    pub replacement: String,
+    /// Confidence that applying the edit preserves intent.
+    #[serde(default)]
+    pub applicability: Applicability,
+}
+
+impl FixExport {
+    /// Convert into a [`Suggestion`] for the conflict-aware applier.
+    pub fn into_suggestion(self) -> Suggestion {
+        Suggestion {
+            rule_name: self.rule,
+            range: self.byte_range[0]..self.byte_range[1],
+            replacement: self.replacement,
+        }
+    }
+}
+
+/// A single proposed edit collected from a rule's warning, in the rustfix
+/// `(byte_range, replacement)` model.
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    /// The rule that proposed this edit.
+    pub rule_name: String,
+    /// The byte range in the current buffer the edit replaces.
+    pub range: std::ops::Range<usize>,
+    /// The replacement text.
+    pub replacement: String,
+}
+
+/// Outcome of a conflict-aware, multi-pass fix run.
+#[derive(Debug, Clone, Default)]
+pub struct ConflictAwareStats {
+    /// Number of detect→apply→reparse passes performed.
+    pub iterations: usize,
+    /// Total edits applied across all passes.
+    pub applied: usize,
+    /// Total edits skipped across all passes because they overlapped an
+    /// already-applied edit in the same pass.
+    pub skipped: usize,
+    /// Names of rules that had at least one edit applied.
+    pub fixed_rule_names: HashSet<String>,
+    /// Number of unsafe fixes that were available but skipped because
+    /// `--unsafe-fixes` was not set.
+    pub unsafe_skipped: usize,
+    /// True if the loop stopped because it hit `max_iterations` while still
+    /// making changes (possible oscillating fixes).
+    pub hit_cap: bool,
+}
+
+/// Report of one [`apply_suggestions`] pass: which suggestions were applied
+/// and which were deferred, in full (not just counts), so a caller building
+/// a `--fix` or "fix all" report can say exactly what happened to each
+/// warning instead of just how many.
+#[derive(Debug, Clone, Default)]
+pub struct ApplyReport {
+    /// Suggestions that were applied, in application order (ascending start
+    /// offset, ties broken by end offset and then by rule name).
+    pub applied: Vec<Suggestion>,
+    /// Suggestions deferred because they overlapped an already-applied edit
+    /// or fell outside the buffer. Safe to re-propose against fresh offsets
+    /// on a second pass (a second `check()` after reparsing).
+    pub deferred: Vec<Suggestion>,
+}
+
+/// Apply a set of suggestions to `content` in a single conflict-aware pass.
+///
+/// Suggestions are sorted by start offset, then by end offset, then by rule name so that
+/// overlapping edits resolve to the same winner regardless of the order
+/// rules ran in (mirroring rustc's `CodeSuggestion` application, which picks
+/// a deterministic subset of non-conflicting suggestions rather than failing
+/// the whole batch). They are then applied greedily into a fresh buffer while
+/// tracking the high-water mark of the last applied range's end: any
+/// suggestion whose range overlaps an already-applied one — or whose bounds
+/// fall outside the buffer — is the conflict *loser* and is *deferred*, not
+/// dropped; callers re-run detection so deferred edits are re-proposed
+/// against fresh offsets on a later pass.
+///
+/// Returns the rewritten content and an [`ApplyReport`] of what was applied
+/// vs deferred.
+pub fn apply_suggestions(content: &str, mut suggestions: Vec<Suggestion>) -> (String, ApplyReport) {
+    suggestions.sort_by(|a, b| {
+        a.range
+            .start
+            .cmp(&b.range.start)
+            .then(a.range.end.cmp(&b.range.end))
+            .then(a.rule_name.cmp(&b.rule_name))
+    });
+
+    let mut out = String::with_capacity(content.len());
+    let mut high_water = 0usize;
+    let mut report = ApplyReport::default();
+
+    for s in suggestions {
+        // Overlaps an already-applied edit, or is otherwise unusable against
+        // the current buffer: defer it for a later pass.
+        if s.range.start < high_water
+            || s.range.start > s.range.end
+            || s.range.end > content.len()
+            || !content.is_char_boundary(s.range.start)
+            || !content.is_char_boundary(s.range.end)
+        {
+            report.deferred.push(s);
+            continue;
+        }
+
+        out.push_str(&content[high_water..s.range.start]);
+        out.push_str(&s.replacement);
+        high_water = s.range.end;
+        report.applied.push(s);
+    }
+    out.push_str(&content[high_water..]);
+
+    (out, report)
+}
+
 /// Coordinates rule fixing to minimize the number of passes needed
 pub struct FixCoordinator {
     /// Rules that should run before others (rule -> rules that depend on it)
@@ -195,6 +322,12 @@ impl FixCoordinator {
                     continue;
                 }
 
+                // Skip unsafe fixes unless the user opted in with --unsafe-fixes.
+                if rule.fix_applicability() == Applicability::Unsafe && !config.global.unsafe_fixes {
+                    processed_rules.insert(rule.name());
+                    continue;
+                }
+
                 // Create context for this specific rule
                 let ctx = LintContext::new(content, config.markdown_flavor());
                 total_ctx_creations += 1;
@@ -241,6 +374,107 @@ impl FixCoordinator {
 
         Ok((total_fixed, iterations, total_ctx_creations, fixed_rule_names))
     }
+
+    /// Whether `rule_name` is eligible for fixing under the `[global]`
+    /// fixable/unfixable configuration.
+    fn rule_is_fixable(rule_name: &str, config: &Config) -> bool {
+        if config
+            .global
+            .unfixable
+            .iter()
+            .any(|r| r.eq_ignore_ascii_case(rule_name))
+        {
+            return false;
+        }
+        if !config.global.fixable.is_empty() {
+            return config.global.fixable.iter().any(|r| r.eq_ignore_ascii_case(rule_name));
+        }
+        true
+    }
+
+    /// Apply fixes using a conflict-aware, multi-pass suggestion model.
+    ///
+    /// Each pass collects every fixable rule's proposed `(range, replacement)`
+    /// edit from a freshly parsed [`LintContext`], applies the non-overlapping
+    /// subset via [`apply_suggestions`], then reparses so any skipped
+    /// (overlapping) edit is re-proposed against fresh offsets. The loop stops
+    /// once a pass makes no change or `max_iterations` is reached; hitting the
+    /// cap while still changing content sets [`ConflictAwareStats::hit_cap`] and
+    /// logs a warning about possible oscillating fixes.
+    ///
+    /// The `[global]` fixable/unfixable filtering is honored on every pass.
+    pub fn apply_fixes_conflict_aware(
+        &self,
+        rules: &[Box<dyn Rule>],
+        content: &mut String,
+        config: &Config,
+        max_iterations: usize,
+    ) -> Result<ConflictAwareStats, String> {
+        let mut stats = ConflictAwareStats::default();
+
+        while stats.iterations < max_iterations {
+            stats.iterations += 1;
+
+            let ctx = LintContext::new(content, config.markdown_flavor());
+
+            // Collect every enabled rule's proposed edits for this pass.
+            let mut suggestions = Vec::new();
+            for rule in rules {
+                if !Self::rule_is_fixable(rule.name(), config) {
+                    continue;
+                }
+                let is_unsafe = rule.fix_applicability() == Applicability::Unsafe;
+                if is_unsafe && !config.global.unsafe_fixes {
+                    // Report-but-skip: count the available edits on the first
+                    // pass (later passes would double-count) and move on.
+                    if stats.iterations == 1 {
+                        let warnings = rule.check(&ctx).map_err(|e| e.to_string())?;
+                        stats.unsafe_skipped += warnings.iter().filter(|w| w.fix.is_some()).count();
+                    }
+                    continue;
+                }
+                let warnings = rule.check(&ctx).map_err(|e| e.to_string())?;
+                for warning in warnings {
+                    if let Some(fix) = warning.fix {
+                        suggestions.push(Suggestion {
+                            rule_name: rule.name().to_string(),
+                            range: fix.range,
+                            replacement: fix.replacement,
+                        });
+                    }
+                }
+            }
+
+            if suggestions.is_empty() {
+                break;
+            }
+
+            let (new_content, report) = apply_suggestions(content, suggestions);
+            stats.applied += report.applied.len();
+            stats.skipped += report.deferred.len();
+            for s in &report.applied {
+                stats.fixed_rule_names.insert(s.rule_name.clone());
+            }
+
+            if new_content == *content {
+                // Nothing changed (e.g. every remaining edit was a no-op); stable.
+                break;
+            }
+            *content = new_content;
+
+            // If we've exhausted the cap but the last pass still changed
+            // content, detection may be oscillating.
+            if stats.iterations >= max_iterations {
+                stats.hit_cap = true;
+                eprintln!(
+                    "Warning: fix did not reach a stable state after {max_iterations} iterations; \
+                     remaining warnings may indicate oscillating fixes"
+                );
+            }
+        }
+
+        Ok(stats)
+    }
 }
 
 #[cfg(test)]
@@ -578,6 +812,240 @@ mod tests {
         assert_eq!(content, "unchanged");
     }
 
+    #[test]
+    fn test_apply_suggestions_non_overlapping() {
+        let content = "hello world";
+        let suggestions = vec![
+            Suggestion {
+                rule_name: "MDA".to_string(),
+                range: 0..5,
+                replacement: "HI".to_string(),
+            },
+            Suggestion {
+                rule_name: "MDB".to_string(),
+                range: 6..11,
+                replacement: "THERE".to_string(),
+            },
+        ];
+        let (out, report) = apply_suggestions(content, suggestions);
+        assert_eq!(out, "HI THERE");
+        let applied_names: Vec<&str> = report.applied.iter().map(|s| s.rule_name.as_str()).collect();
+        assert_eq!(applied_names, vec!["MDA", "MDB"]);
+        assert!(report.deferred.is_empty());
+    }
+
+    #[test]
+    fn test_apply_suggestions_skips_overlap() {
+        let content = "abcdef";
+        // Two edits touching overlapping ranges: only the earlier-starting one
+        // applies; the overlapping one is deferred for a later pass.
+        let suggestions = vec![
+            Suggestion {
+                rule_name: "MDA".to_string(),
+                range: 1..4,
+                replacement: "X".to_string(),
+            },
+            Suggestion {
+                rule_name: "MDB".to_string(),
+                range: 3..5,
+                replacement: "Y".to_string(),
+            },
+        ];
+        let (out, report) = apply_suggestions(content, suggestions);
+        assert_eq!(out, "aXef");
+        assert_eq!(report.applied.len(), 1);
+        assert_eq!(report.applied[0].rule_name, "MDA");
+        assert_eq!(report.deferred.len(), 1);
+        assert_eq!(report.deferred[0].rule_name, "MDB");
+    }
+
+    #[test]
+    fn test_apply_suggestions_out_of_bounds_skipped() {
+        let content = "abc";
+        let suggestions = vec![Suggestion {
+            rule_name: "MDA".to_string(),
+            range: 2..10,
+            replacement: "Z".to_string(),
+        }];
+        let (out, report) = apply_suggestions(content, suggestions);
+        assert_eq!(out, "abc");
+        assert!(report.applied.is_empty());
+        assert_eq!(report.deferred.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_suggestions_tie_breaks_by_rule_name_deterministically() {
+        // Two suggestions with identical ranges (a true conflict, not just
+        // adjacency): whichever order they're pushed in, the lower rule name
+        // must win so results don't depend on rule iteration order.
+        let content = "abcdef";
+        let suggestions_a = vec![
+            Suggestion {
+                rule_name: "MD099".to_string(),
+                range: 1..3,
+                replacement: "Z".to_string(),
+            },
+            Suggestion {
+                rule_name: "MD001".to_string(),
+                range: 1..3,
+                replacement: "X".to_string(),
+            },
+        ];
+        let suggestions_b: Vec<Suggestion> = suggestions_a.iter().cloned().rev().collect();
+
+        let (out_a, report_a) = apply_suggestions(content, suggestions_a);
+        let (out_b, report_b) = apply_suggestions(content, suggestions_b);
+
+        assert_eq!(out_a, "aXdef");
+        assert_eq!(out_a, out_b);
+        assert_eq!(report_a.applied[0].rule_name, "MD001");
+        assert_eq!(report_b.applied[0].rule_name, "MD001");
+    }
+
+    #[test]
+    fn test_conflict_aware_respects_unfixable() {
+        use crate::rule::{Fix, Severity};
+
+        #[derive(Clone)]
+        struct RangeRule;
+        impl Rule for RangeRule {
+            fn name(&self) -> &'static str {
+                "MD001"
+            }
+            fn check(&self, ctx: &LintContext) -> LintResult {
+                // Propose turning the first character into uppercase 'X', once.
+                if ctx.content.starts_with('X') {
+                    return Ok(vec![]);
+                }
+                Ok(vec![LintWarning {
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 2,
+                    message: "lead".to_string(),
+                    rule_name: Some("MD001"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: 0..1,
+                        replacement: "X".to_string(),
+                    }),
+                }])
+            }
+            fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+                Ok(ctx.content.to_string())
+            }
+            fn description(&self) -> &'static str {
+                "x"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Whitespace
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let coordinator = FixCoordinator::new();
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(RangeRule)];
+
+        // Fixable by default: edit applies and the loop stabilizes.
+        let mut content = "abc".to_string();
+        let config = Config {
+            global: GlobalConfig::default(),
+            per_file_ignores: HashMap::new(),
+            rules: Default::default(),
+        };
+        let stats = coordinator
+            .apply_fixes_conflict_aware(&rules, &mut content, &config, 10)
+            .unwrap();
+        assert_eq!(content, "Xbc");
+        assert_eq!(stats.applied, 1);
+        assert!(!stats.hit_cap);
+
+        // Marked unfixable: no edit applies.
+        let mut content = "abc".to_string();
+        let mut config = config;
+        config.global.unfixable = vec!["MD001".to_string()];
+        let stats = coordinator
+            .apply_fixes_conflict_aware(&rules, &mut content, &config, 10)
+            .unwrap();
+        assert_eq!(content, "abc");
+        assert_eq!(stats.applied, 0);
+    }
+
+    #[test]
+    fn test_conflict_aware_gates_unsafe_fixes() {
+        use crate::rule::{Applicability, Fix, Severity};
+
+        #[derive(Clone)]
+        struct UnsafeRule;
+        impl Rule for UnsafeRule {
+            fn name(&self) -> &'static str {
+                "MD033"
+            }
+            fn check(&self, ctx: &LintContext) -> LintResult {
+                if ctx.content.starts_with('X') {
+                    return Ok(vec![]);
+                }
+                Ok(vec![LintWarning {
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 2,
+                    message: "html".to_string(),
+                    rule_name: Some("MD033"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: 0..1,
+                        replacement: "X".to_string(),
+                    }),
+                }])
+            }
+            fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+                Ok(ctx.content.to_string())
+            }
+            fn description(&self) -> &'static str {
+                "x"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Html
+            }
+            fn fix_applicability(&self) -> Applicability {
+                Applicability::Unsafe
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let coordinator = FixCoordinator::new();
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(UnsafeRule)];
+
+        // Default: unsafe fix is reported but not applied.
+        let mut content = "abc".to_string();
+        let mut config = Config {
+            global: GlobalConfig::default(),
+            per_file_ignores: HashMap::new(),
+            rules: Default::default(),
+        };
+        let stats = coordinator
+            .apply_fixes_conflict_aware(&rules, &mut content, &config, 10)
+            .unwrap();
+        assert_eq!(content, "abc");
+        assert_eq!(stats.applied, 0);
+        assert_eq!(stats.unsafe_skipped, 1);
+
+        // With unsafe_fixes enabled, the edit applies.
+        config.global.unsafe_fixes = true;
+        let mut content = "abc".to_string();
+        let stats = coordinator
+            .apply_fixes_conflict_aware(&rules, &mut content, &config, 10)
+            .unwrap();
+        assert_eq!(content, "Xbc");
+        assert_eq!(stats.applied, 1);
+        assert_eq!(stats.unsafe_skipped, 0);
+    }
+
     #[test]
     fn test_cyclic_dependencies_handled() {
         // Test that cyclic dependencies don't cause infinite loops
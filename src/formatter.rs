@@ -130,6 +130,12 @@ pub fn print_config_with_provenance(sourced: &rumdl_config::SourcedConfig) {
         format!("flavor = {:?}", g.flavor.value),
         format!("[from {}]", format_provenance(g.flavor.source)),
     ));
+    if let Some(style) = &g.style {
+        global_lines.push((
+            format!("style = {:?}", style.value),
+            format!("[from {}]", format_provenance(style.source)),
+        ));
+    }
     global_lines.push((String::new(), String::new()));
     all_lines.extend(global_lines);
     // All rules, but only if they have config items
@@ -260,6 +266,87 @@ pub fn print_config_with_provenance(sourced: &rumdl_config::SourcedConfig) {
     }
 }
 
+/// Print only the configuration keys whose effective value was overridden from
+/// the built-in defaults, annotated with the source that set them.
+///
+/// This is the `config diff` view: it answers "what in my effective config is
+/// not a default, and where did it come from?" without dumping the full table.
+pub fn print_config_diff(sourced: &rumdl_config::SourcedConfig) {
+    use rumdl_config::ConfigSource::Default as DefaultSource;
+
+    let g = &sourced.global;
+    let mut all_lines: Vec<(String, String)> = Vec::new();
+
+    let mut global_lines = Vec::new();
+    let mut push_global = |name: &str, value: String, source: rumdl_config::ConfigSource| {
+        if source != DefaultSource {
+            global_lines.push((
+                format!("{name} = {value}"),
+                format!("[from {}]", format_provenance(source)),
+            ));
+        }
+    };
+    push_global("enable", format!("{:?}", g.enable.value), g.enable.source);
+    push_global("disable", format!("{:?}", g.disable.value), g.disable.source);
+    push_global("exclude", format!("{:?}", g.exclude.value), g.exclude.source);
+    push_global("include", format!("{:?}", g.include.value), g.include.source);
+    push_global(
+        "respect_gitignore",
+        g.respect_gitignore.value.to_string(),
+        g.respect_gitignore.source,
+    );
+    push_global("flavor", format!("{:?}", g.flavor.value), g.flavor.source);
+    if let Some(style) = &g.style {
+        push_global("style", format!("{:?}", style.value), style.source);
+    }
+    if !global_lines.is_empty() {
+        all_lines.push(("[global]".to_string(), String::new()));
+        all_lines.extend(global_lines);
+        all_lines.push((String::new(), String::new()));
+    }
+
+    // Per-rule overrides: only keys with a non-default source.
+    let mut rule_names: Vec<_> = sourced.rules.keys().cloned().collect();
+    rule_names.sort();
+    for rule_name in rule_names {
+        let rule_cfg = &sourced.rules[&rule_name];
+        let mut keys: Vec<_> = rule_cfg.values.keys().collect();
+        keys.sort();
+        let mut lines = Vec::new();
+        for key in keys {
+            let sv = &rule_cfg.values[key];
+            if sv.source == DefaultSource {
+                continue;
+            }
+            lines.push((
+                format!("{key} = {}", format_toml_value(&sv.value)),
+                format!("[from {}]", format_provenance(sv.source)),
+            ));
+        }
+        if !lines.is_empty() {
+            all_lines.push((format!("[{rule_name}]"), String::new()));
+            all_lines.extend(lines);
+            all_lines.push((String::new(), String::new()));
+        }
+    }
+
+    if all_lines.is_empty() {
+        println!("Effective configuration matches the defaults (no overrides)");
+        return;
+    }
+
+    let max_left = all_lines.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+    for (left, right) in &all_lines {
+        if left.is_empty() && right.is_empty() {
+            println!();
+        } else if !right.is_empty() {
+            println!("{:<width$} {}", left, right.dimmed(), width = max_left);
+        } else {
+            println!("{left:<max_left$} {right}");
+        }
+    }
+}
+
 /// Format a TOML value for display
 pub fn format_toml_value(val: &toml::Value) -> String {
     match val {
@@ -329,76 +416,146 @@ pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
     );
 }
 
-/// Generate a unified diff between original and modified content
-pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String {
-    let mut diff = String::new();
+/// A single line-level edit produced by the diff algorithm.
+enum LineEdit<'a> {
+    /// Line present in both buffers.
+    Context(&'a str),
+    /// Line only in the original buffer.
+    Removed(&'a str),
+    /// Line only in the modified buffer.
+    Added(&'a str),
+}
 
-    // Create diff header
-    diff.push_str(&format!("--- {file_path}\n"));
-    diff.push_str(&format!("+++ {file_path} (fixed)\n"));
+/// Compute a line-based edit script between two buffers using a longest
+/// common subsequence (the same foundation as Myers' diff). This avoids the
+/// cascading mismatches a positional comparison produces when lines are
+/// inserted or removed rather than changed in place.
+fn diff_lines<'a>(original: &[&'a str], modified: &[&'a str]) -> Vec<LineEdit<'a>> {
+    let n = original.len();
+    let m = modified.len();
+
+    // Classic DP table of LCS lengths.
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if original[i] == modified[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
 
+    // Backtrack to produce the edit script in order.
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if original[i] == modified[j] {
+            edits.push(LineEdit::Context(original[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            edits.push(LineEdit::Removed(original[i]));
+            i += 1;
+        } else {
+            edits.push(LineEdit::Added(modified[j]));
+            j += 1;
+        }
+    }
+    while i < n {
+        edits.push(LineEdit::Removed(original[i]));
+        i += 1;
+    }
+    while j < m {
+        edits.push(LineEdit::Added(modified[j]));
+        j += 1;
+    }
+    edits
+}
+
+/// Generate a colored unified diff between original and modified content.
+///
+/// The diff is computed over lines using a longest-common-subsequence edit
+/// script, then differing runs are grouped into hunks with up to 3 lines of
+/// unchanged context on either side. Coloring goes through the `colored`
+/// crate, so it honors the global `--color` setting automatically.
+pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String {
     let original_lines: Vec<&str> = original.lines().collect();
     let modified_lines: Vec<&str> = modified.lines().collect();
 
-    // Simple line-by-line diff (could be improved with a proper diff algorithm)
-    let max_lines = original_lines.len().max(modified_lines.len());
-    let mut in_diff_block = false;
-    let mut diff_start = 0;
-    let mut changes = Vec::new();
+    let edits = diff_lines(&original_lines, &modified_lines);
 
-    for i in 0..max_lines {
-        let orig_line = original_lines.get(i).copied().unwrap_or("");
-        let mod_line = modified_lines.get(i).copied().unwrap_or("");
+    // Find the runs of context that are long enough to split hunks (more than
+    // 2 * context lines of unchanged content between changes starts a new hunk).
+    const CONTEXT: usize = 3;
 
-        if orig_line != mod_line {
-            if !in_diff_block {
-                in_diff_block = true;
-                diff_start = i.saturating_sub(3); // Include 3 lines of context before
-            }
-        } else if in_diff_block {
-            // End of diff block, include 3 lines of context after
-            let diff_end = (i + 3).min(max_lines);
-            changes.push((diff_start, diff_end));
-            in_diff_block = false;
-        }
+    // Locate the indices of changed edits.
+    let change_indices: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !matches!(e, LineEdit::Context(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut diff = String::new();
+    diff.push_str(&format!("--- {file_path}\n"));
+    diff.push_str(&format!("+++ {file_path} (fixed)\n"));
+
+    if change_indices.is_empty() {
+        diff.push_str("No changes\n");
+        return diff;
     }
 
-    // Handle case where diff extends to the end of file
-    if in_diff_block {
-        changes.push((diff_start, max_lines));
+    // Group change indices into hunks separated by more than 2*CONTEXT context lines.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    let mut hunk_start = change_indices[0];
+    let mut hunk_end = change_indices[0];
+    for &idx in &change_indices[1..] {
+        if idx - hunk_end - 1 > 2 * CONTEXT {
+            hunks.push((hunk_start, hunk_end));
+            hunk_start = idx;
+        }
+        hunk_end = idx;
     }
+    hunks.push((hunk_start, hunk_end));
 
-    // Generate unified diff format for each change block
-    if changes.is_empty() {
-        diff.push_str("No changes\n");
-    } else {
-        for (start, end) in changes {
-            diff.push_str(&format!(
-                "@@ -{},{} +{},{} @@\n",
-                start + 1,
-                end - start,
-                start + 1,
-                end - start
-            ));
+    for (first_change, last_change) in hunks {
+        let start = first_change.saturating_sub(CONTEXT);
+        let end = (last_change + CONTEXT + 1).min(edits.len());
 
-            for i in start..end {
-                let orig_line = original_lines.get(i).copied().unwrap_or("");
-                let mod_line = modified_lines.get(i).copied().unwrap_or("");
-
-                if i >= original_lines.len() {
-                    // Line only in modified
-                    diff.push_str(&format!("+{mod_line}\n"));
-                } else if i >= modified_lines.len() {
-                    // Line only in original
-                    diff.push_str(&format!("-{orig_line}\n"));
-                } else if orig_line == mod_line {
-                    // Context line
-                    diff.push_str(&format!(" {orig_line}\n"));
-                } else {
-                    // Changed line
-                    diff.push_str(&format!("-{orig_line}\n"));
-                    diff.push_str(&format!("+{mod_line}\n"));
+        // Compute hunk line counts and starting line numbers from the edits.
+        let mut old_start = 1;
+        let mut new_start = 1;
+        for edit in &edits[..start] {
+            match edit {
+                LineEdit::Context(_) => {
+                    old_start += 1;
+                    new_start += 1;
+                }
+                LineEdit::Removed(_) => old_start += 1,
+                LineEdit::Added(_) => new_start += 1,
+            }
+        }
+        let mut old_len = 0;
+        let mut new_len = 0;
+        for edit in &edits[start..end] {
+            match edit {
+                LineEdit::Context(_) => {
+                    old_len += 1;
+                    new_len += 1;
                 }
+                LineEdit::Removed(_) => old_len += 1,
+                LineEdit::Added(_) => new_len += 1,
+            }
+        }
+
+        diff.push_str(&format!("@@ -{old_start},{old_len} +{new_start},{new_len} @@\n").cyan().to_string());
+
+        for edit in &edits[start..end] {
+            match edit {
+                LineEdit::Context(line) => diff.push_str(&format!(" {line}\n")),
+                LineEdit::Removed(line) => diff.push_str(&format!("-{line}\n").red().to_string()),
+                LineEdit::Added(line) => diff.push_str(&format!("+{line}\n").green().to_string()),
             }
         }
     }
@@ -1,12 +1,16 @@
+pub mod bench;
 pub mod config;
 pub mod exit_codes;
+pub mod file_lines;
 pub mod inline_config;
+pub mod line_ranges;
 pub mod lint_context;
 pub mod lsp;
 pub mod markdownlint_config;
 pub mod output;
 pub mod parallel;
 pub mod performance;
+pub mod presets;
 pub mod profiling;
 pub mod rule;
 pub mod vscode;
@@ -24,10 +28,18 @@ pub use rules::heading_utils::{Heading, HeadingStyle};
 pub use rules::*;
 
 pub use crate::lint_context::{LineInfo, LintContext, ListItemInfo};
-use crate::rule::{LintResult, Rule, RuleCategory};
+use crate::rule::{ContentFeature, ContentRequirement, LintResult, Rule};
 use crate::utils::document_structure::DocumentStructure;
 use std::time::Instant;
 
+/// Minimum number of applicable rules before `lint()` spreads rule checks
+/// across the rayon thread pool.
+const PARALLEL_RULE_THRESHOLD: usize = 16;
+
+/// Minimum content size (bytes) before `lint()` parallelizes; tiny files are
+/// dominated by dispatch overhead.
+const PARALLEL_CONTENT_THRESHOLD: usize = 50 * 1024;
+
 /// Content characteristics for efficient rule filtering
 #[derive(Debug, Default)]
 struct ContentCharacteristics {
@@ -100,20 +112,28 @@ impl ContentCharacteristics {
         chars
     }
 
-    /// Check if a rule should be skipped based on content characteristics
+    /// Whether a single content feature is present in the document.
+    fn has_feature(&self, feature: ContentFeature) -> bool {
+        match feature {
+            ContentFeature::Headings => self.has_headings,
+            ContentFeature::Lists => self.has_lists,
+            ContentFeature::Links => self.has_links,
+            ContentFeature::Images => self.has_images,
+            ContentFeature::Code => self.has_code,
+            ContentFeature::Emphasis => self.has_emphasis,
+            ContentFeature::Html => self.has_html,
+            ContentFeature::Tables => self.has_tables,
+            ContentFeature::Blockquotes => self.has_blockquotes,
+        }
+    }
+
+    /// Check if a rule should be skipped based on its declared content
+    /// requirements (see [`Rule::content_requirements`]).
     fn should_skip_rule(&self, rule: &dyn Rule) -> bool {
-        match rule.category() {
-            RuleCategory::Heading => !self.has_headings,
-            RuleCategory::List => !self.has_lists,
-            RuleCategory::Link => !self.has_links && !self.has_images,
-            RuleCategory::Image => !self.has_images,
-            RuleCategory::CodeBlock => !self.has_code,
-            RuleCategory::Html => !self.has_html,
-            RuleCategory::Emphasis => !self.has_emphasis,
-            RuleCategory::Blockquote => !self.has_blockquotes,
-            RuleCategory::Table => !self.has_tables,
-            // Always check these categories as they apply to all content
-            RuleCategory::Whitespace | RuleCategory::FrontMatter | RuleCategory::Other => false,
+        match rule.content_requirements() {
+            ContentRequirement::Always => false,
+            ContentRequirement::Any(features) => !features.iter().any(|&f| self.has_feature(f)),
+            ContentRequirement::All(features) => !features.iter().all(|&f| self.has_feature(f)),
         }
     }
 }
@@ -122,6 +142,28 @@ impl ContentCharacteristics {
 /// Assumes the provided `rules` vector contains the final,
 /// configured, and filtered set of rules to be executed.
 pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResult {
+    lint_impl(content, rules, _verbose, None)
+}
+
+/// Like [`lint`], but restricts results to the given inclusive line ranges
+/// (the `--lines`/`--diff-only` feature). Rules see the whole document, but
+/// may use [`crate::lint_context::LintContext::line_in_range`] to skip
+/// warnings and fixes outside `line_ranges`.
+pub fn lint_with_line_ranges(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+    line_ranges: Vec<std::ops::RangeInclusive<usize>>,
+) -> LintResult {
+    lint_impl(content, rules, verbose, Some(line_ranges))
+}
+
+fn lint_impl(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    _verbose: bool,
+    line_ranges: Option<Vec<std::ops::RangeInclusive<usize>>>,
+) -> LintResult {
     let mut warnings = Vec::new();
     let _overall_start = Instant::now();
 
@@ -130,9 +172,6 @@ pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResul
         return Ok(warnings);
     }
 
-    // Parse inline configuration comments once
-    let inline_config = crate::inline_config::InlineConfig::from_content(content);
-
     // Analyze content characteristics for rule filtering
     let characteristics = ContentCharacteristics::analyze(content);
 
@@ -159,10 +198,14 @@ pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResul
 
     // Parse LintContext once (migration step)
     let lint_ctx = crate::lint_context::LintContext::new(content);
+    let lint_ctx = match line_ranges {
+        Some(ranges) => lint_ctx.with_line_ranges(ranges),
+        None => lint_ctx,
+    };
 
-    for rule in applicable_rules {
-        let _rule_start = Instant::now();
-
+    // Check a single rule and filter its warnings against inline-disable comments.
+    // Only reads the shared, immutable contexts, so it is safe to run concurrently.
+    let check_rule = |rule: &dyn Rule| -> LintResult {
         // Try optimized paths in order of preference
         let result = if rule.uses_ast() {
             if let Some(ref ast_ref) = ast {
@@ -183,45 +226,81 @@ pub fn lint(content: &str, rules: &[Box<dyn Rule>], _verbose: bool) -> LintResul
                 .unwrap_or_else(|| rule.check(&lint_ctx))
         };
 
-        match result {
-            Ok(rule_warnings) => {
-                // Filter out warnings for rules disabled via inline comments
-                let filtered_warnings: Vec<_> = rule_warnings
-                    .into_iter()
-                    .filter(|warning| {
-                        // Use the warning's rule_name if available, otherwise use the rule's name
-                        let rule_name_to_check = warning.rule_name.unwrap_or(rule.name());
-
-                        // Extract the base rule name for sub-rules like "MD029-style" -> "MD029"
-                        let base_rule_name = if let Some(dash_pos) = rule_name_to_check.find('-') {
-                            &rule_name_to_check[..dash_pos]
-                        } else {
-                            rule_name_to_check
-                        };
-
-                        !inline_config.is_rule_disabled(
-                            base_rule_name,
-                            warning.line, // Already 1-indexed
-                        )
-                    })
-                    .collect();
-                warnings.extend(filtered_warnings);
-            }
-            Err(e) => {
-                log::error!("Error checking rule {}: {}", rule.name(), e);
-                return Err(e);
+        result.map(|rule_warnings| {
+            rule_warnings
+                .into_iter()
+                .filter(|warning| {
+                    // Use the warning's rule_name if available, otherwise use the rule's name
+                    let rule_name_to_check = warning.rule_name.unwrap_or(rule.name());
+
+                    // Extract the base rule name for sub-rules like "MD029-style" -> "MD029"
+                    let base_rule_name = if let Some(dash_pos) = rule_name_to_check.find('-') {
+                        &rule_name_to_check[..dash_pos]
+                    } else {
+                        rule_name_to_check
+                    };
+
+                    !lint_ctx.is_suppressed(
+                        base_rule_name,
+                        warning.line, // Already 1-indexed
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    // Large files with many rules benefit from spreading the independent rule
+    // checks across the rayon pool; small inputs stay sequential to avoid the
+    // dispatch overhead. Results are merged and sorted below so the output is
+    // identical regardless of which path runs.
+    let use_parallel =
+        applicable_rules.len() >= PARALLEL_RULE_THRESHOLD && content.len() >= PARALLEL_CONTENT_THRESHOLD;
+
+    if use_parallel {
+        use rayon::prelude::*;
+
+        let per_rule: Vec<LintResult> = applicable_rules
+            .par_iter()
+            .map(|rule| check_rule(&***rule))
+            .collect();
+
+        for result in per_rule {
+            match result {
+                Ok(rule_warnings) => warnings.extend(rule_warnings),
+                Err(e) => return Err(e),
             }
         }
-
-        #[cfg(not(test))]
-        if _verbose {
-            let rule_duration = _rule_start.elapsed();
-            if rule_duration.as_millis() > 500 {
-                log::debug!("Rule {} took {:?}", rule.name(), rule_duration);
+    } else {
+        // Root span covers the whole sequential pass; each rule gets a child
+        // span so the profiling report shows the hottest rules inline.
+        let _lint_span = crate::profiling::span("lint");
+        for rule in &applicable_rules {
+            let _rule_span = crate::profiling::span(rule.name());
+
+            match check_rule(&***rule) {
+                Ok(rule_warnings) => warnings.extend(rule_warnings),
+                Err(e) => {
+                    log::error!("Error checking rule {}: {}", rule.name(), e);
+                    return Err(e);
+                }
             }
         }
     }
 
+    // `--lines`/`--diff-only`: drop warnings outside the restricted line ranges.
+    if lint_ctx.line_ranges.is_some() {
+        warnings.retain(|w| lint_ctx.line_in_range(w.line));
+    }
+
+    // Sort by position then rule name so the result is deterministic regardless
+    // of rule execution order (sequential vs. parallel).
+    warnings.sort_by(|a, b| {
+        a.line
+            .cmp(&b.line)
+            .then(a.column.cmp(&b.column))
+            .then_with(|| a.rule_name.unwrap_or("").cmp(b.rule_name.unwrap_or("")))
+    });
+
     #[cfg(not(test))]
     if _verbose {
         let skipped_rules = _total_rules - _applicable_count;
@@ -246,6 +325,12 @@ pub fn reset_profiling() {
     profiling::reset()
 }
 
+/// Get the hierarchical span profiling report, collapsing nodes below
+/// `threshold_pct` of their parent into an `(other)` line.
+pub fn get_span_profiling_report(threshold_pct: f64) -> String {
+    profiling::get_span_report(threshold_pct)
+}
+
 /// Get regex cache statistics for performance monitoring
 pub fn get_regex_cache_stats() -> std::collections::HashMap<String, u64> {
     crate::utils::regex_cache::get_cache_stats()
@@ -256,9 +341,28 @@ pub fn get_ast_cache_stats() -> std::collections::HashMap<u64, u64> {
     crate::utils::ast_utils::get_ast_cache_stats()
 }
 
+/// Lint `content` against `rules`, returning a cached result when the same
+/// `(content, rule set, crate version)` has been linted before.
+///
+/// The cache key folds in each rule's name and serialized configuration (see
+/// [`crate::utils::lint_cache::compute_key`]), so any change to the document,
+/// the rule selection, or a rule's options triggers a fresh lint. Results are
+/// written back after a miss so subsequent calls are served from disk, which is
+/// a large win for editor and CI flows that lint the same tree repeatedly.
+pub fn lint_cached(content: &str, rules: &[Box<dyn Rule>], verbose: bool) -> LintResult {
+    let key = crate::utils::lint_cache::compute_key(content, rules);
+    if let Some(cached) = crate::utils::lint_cache::get(key) {
+        return Ok(cached);
+    }
+    let warnings = lint(content, rules, verbose)?;
+    crate::utils::lint_cache::set(key, &warnings);
+    Ok(warnings)
+}
+
 /// Clear all caches (useful for testing and memory management)
 pub fn clear_all_caches() {
     crate::utils::ast_utils::clear_ast_cache();
+    crate::utils::lint_cache::clear();
     // Note: Regex cache is intentionally not cleared as it's global and shared
 }
 
@@ -317,6 +421,19 @@ pub fn get_cache_performance_report() -> String {
         }
     }
 
+    report.push('\n');
+
+    // Lint result cache statistics
+    let lint_stats = crate::utils::lint_cache::get_stats();
+    report.push_str("Lint Result Cache:\n");
+    report.push_str(&format!("  Hits: {}\n", lint_stats.hits));
+    report.push_str(&format!("  Misses: {}\n", lint_stats.misses));
+    report.push_str(&format!("  Writes: {}\n", lint_stats.writes));
+    report.push_str(&format!("  Evictions: {}\n", lint_stats.evictions));
+    if lint_stats.hits + lint_stats.misses > 0 {
+        report.push_str(&format!("  Cache hit rate: {:.1}%\n", lint_stats.hit_rate()));
+    }
+
     report
 }
 
@@ -467,6 +584,36 @@ mod tests {
         assert!(warnings.is_empty()); // Should be disabled by inline comment
     }
 
+    #[test]
+    fn test_lint_output_sorted_by_position_and_rule() {
+        // Two rules reporting on the same lines must come out ordered by
+        // (line, column, rule_name) regardless of rule order in the slice.
+        let content = "##  Heading with trailing spaces   \n#### Skipped level";
+        let forward: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD001HeadingIncrement),
+            Box::new(MD009TrailingSpaces::new(2, false)),
+        ];
+        let reversed: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD009TrailingSpaces::new(2, false)),
+            Box::new(MD001HeadingIncrement),
+        ];
+
+        let a = lint(content, &forward, false).unwrap();
+        let b = lint(content, &reversed, false).unwrap();
+
+        let key = |w: &[_]| {
+            w.iter()
+                .map(|x: &crate::rule::LintWarning| (x.line, x.column, x.rule_name))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(key(&a), key(&b));
+
+        // Warnings are non-decreasing by (line, column).
+        for pair in a.windows(2) {
+            assert!((pair[0].line, pair[0].column) <= (pair[1].line, pair[1].column));
+        }
+    }
+
     #[test]
     fn test_lint_rule_filtering() {
         // Content with no lists
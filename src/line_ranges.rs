@@ -0,0 +1,204 @@
+//! Per-file line-range restrictions for `--file-lines` / `--file-lines-json`.
+//!
+//! Modeled on rustfmt's `file_lines` option: rules still run against the
+//! full document (so multi-line/document-scoped checks like duplicate
+//! headings keep seeing correct context), but warnings are filtered down to
+//! the requested region afterwards. This lets CI lint only the lines a diff
+//! actually touched, without having to feed rules a truncated document.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// An inclusive, 1-based line range (`start-end`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl LineRange {
+    fn contains(&self, line: usize) -> bool {
+        line >= self.start && line <= self.end
+    }
+}
+
+/// A single entry from the `--file-lines-json` form:
+/// `{"file": "path.md", "range": [10, 40]}`.
+#[derive(Debug, Deserialize)]
+struct JsonEntry {
+    file: String,
+    range: (usize, usize),
+}
+
+/// Per-file line-range restrictions collected from `--file-lines` and/or
+/// `--file-lines-json`.
+///
+/// A file with no entry here is unrestricted (all of its warnings are
+/// reported); a file with one or more entries only reports warnings whose
+/// line falls inside one of its ranges.
+#[derive(Debug, Clone, Default)]
+pub struct LineRanges {
+    ranges: HashMap<String, Vec<LineRange>>,
+}
+
+impl LineRanges {
+    /// Parse a list of `path:start-end` specs, as passed via repeated
+    /// `--file-lines` flags.
+    pub fn parse_specs(specs: &[String]) -> Result<Self, String> {
+        let mut ranges: HashMap<String, Vec<LineRange>> = HashMap::new();
+        for spec in specs {
+            let (path, range) = Self::parse_one_spec(spec)?;
+            ranges.entry(path).or_default().push(range);
+        }
+        Ok(Self { ranges })
+    }
+
+    fn parse_one_spec(spec: &str) -> Result<(String, LineRange), String> {
+        let (path, span) = spec
+            .rsplit_once(':')
+            .ok_or_else(|| format!("invalid --file-lines spec '{spec}': expected 'path:start-end'"))?;
+        let (start, end) = span
+            .split_once('-')
+            .ok_or_else(|| format!("invalid --file-lines spec '{spec}': expected 'path:start-end'"))?;
+        let start: usize = start
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid --file-lines spec '{spec}': '{start}' is not a line number"))?;
+        let end: usize = end
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid --file-lines spec '{spec}': '{end}' is not a line number"))?;
+        if start == 0 || end < start {
+            return Err(format!(
+                "invalid --file-lines spec '{spec}': range must satisfy 1 <= start <= end"
+            ));
+        }
+        Ok((normalize_path(path), LineRange { start, end }))
+    }
+
+    /// Parse the JSON array form: `[{"file":"x.md","range":[10,40]}, ...]`.
+    pub fn parse_json(json: &str) -> Result<Self, String> {
+        let entries: Vec<JsonEntry> =
+            serde_json::from_str(json).map_err(|e| format!("invalid --file-lines-json: {e}"))?;
+        let mut ranges: HashMap<String, Vec<LineRange>> = HashMap::new();
+        for entry in entries {
+            let (start, end) = entry.range;
+            if start == 0 || end < start {
+                return Err(format!(
+                    "invalid --file-lines-json entry for '{}': range must satisfy 1 <= start <= end",
+                    entry.file
+                ));
+            }
+            ranges
+                .entry(normalize_path(&entry.file))
+                .or_default()
+                .push(LineRange { start, end });
+        }
+        Ok(Self { ranges })
+    }
+
+    /// Merge another set of ranges into this one (for combining
+    /// `--file-lines` and `--file-lines-json` on the same invocation).
+    pub fn merge(mut self, other: LineRanges) -> Self {
+        for (path, mut spans) in other.ranges {
+            self.ranges.entry(path).or_default().append(&mut spans);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Whether `line` of `file_path` should be reported.
+    pub fn is_allowed(&self, file_path: &str, line: usize) -> bool {
+        match self.ranges.get(&normalize_path(file_path)) {
+            Some(spans) => spans.iter().any(|r| r.contains(line)),
+            None => true,
+        }
+    }
+}
+
+/// Normalize a path for lookup so `--file-lines ./docs/a.md:1-5` still
+/// matches a walked path of `docs/a.md`, and Windows-style separators match
+/// POSIX ones.
+fn normalize_path(path: &str) -> String {
+    Path::new(path)
+        .strip_prefix("./")
+        .unwrap_or(Path::new(path))
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_specs_single_range() {
+        let ranges = LineRanges::parse_specs(&["docs/a.md:10-40".to_string()]).unwrap();
+        assert!(ranges.is_allowed("docs/a.md", 10));
+        assert!(ranges.is_allowed("docs/a.md", 40));
+        assert!(!ranges.is_allowed("docs/a.md", 9));
+        assert!(!ranges.is_allowed("docs/a.md", 41));
+    }
+
+    #[test]
+    fn test_parse_specs_multiple_ranges_same_file() {
+        let ranges =
+            LineRanges::parse_specs(&["a.md:1-5".to_string(), "a.md:20-25".to_string()]).unwrap();
+        assert!(ranges.is_allowed("a.md", 3));
+        assert!(ranges.is_allowed("a.md", 22));
+        assert!(!ranges.is_allowed("a.md", 10));
+    }
+
+    #[test]
+    fn test_file_without_entry_is_unrestricted() {
+        let ranges = LineRanges::parse_specs(&["a.md:1-5".to_string()]).unwrap();
+        assert!(ranges.is_allowed("b.md", 1));
+        assert!(ranges.is_allowed("b.md", 9999));
+    }
+
+    #[test]
+    fn test_parse_specs_rejects_malformed_spec() {
+        assert!(LineRanges::parse_specs(&["a.md".to_string()]).is_err());
+        assert!(LineRanges::parse_specs(&["a.md:10".to_string()]).is_err());
+        assert!(LineRanges::parse_specs(&["a.md:40-10".to_string()]).is_err());
+        assert!(LineRanges::parse_specs(&["a.md:0-10".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = r#"[{"file": "docs/a.md", "range": [10, 40]}, {"file": "docs/b.md", "range": [1, 3]}]"#;
+        let ranges = LineRanges::parse_json(json).unwrap();
+        assert!(ranges.is_allowed("docs/a.md", 15));
+        assert!(!ranges.is_allowed("docs/a.md", 50));
+        assert!(ranges.is_allowed("docs/b.md", 2));
+    }
+
+    #[test]
+    fn test_parse_json_rejects_invalid_range() {
+        let json = r#"[{"file": "a.md", "range": [40, 10]}]"#;
+        assert!(LineRanges::parse_json(json).is_err());
+    }
+
+    #[test]
+    fn test_merge_combines_both_sources() {
+        let from_specs = LineRanges::parse_specs(&["a.md:1-5".to_string()]).unwrap();
+        let from_json = LineRanges::parse_json(r#"[{"file": "a.md", "range": [20, 25]}]"#).unwrap();
+        let merged = from_specs.merge(from_json);
+        assert!(merged.is_allowed("a.md", 3));
+        assert!(merged.is_allowed("a.md", 22));
+        assert!(!merged.is_allowed("a.md", 10));
+    }
+
+    #[test]
+    fn test_normalize_path_strips_dot_slash_and_backslashes() {
+        let ranges = LineRanges::parse_specs(&["./docs/a.md:1-5".to_string()]).unwrap();
+        assert!(ranges.is_allowed("docs/a.md", 3));
+
+        let ranges = LineRanges::parse_specs(&["docs\\a.md:1-5".to_string()]).unwrap();
+        assert!(ranges.is_allowed("docs/a.md", 3));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::inline_config::InlineConfig;
 use crate::utils::code_block_utils::{CodeBlockContext, CodeBlockUtils};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -57,8 +58,57 @@ lazy_static! {
     static ref BLOCKQUOTE_PREFIX_REGEX: Regex = Regex::new(r"^(\s*>+\s*)").unwrap();
 }
 
+/// Compute the visual (tab-expanded) column width of a leading `prefix`.
+///
+/// Tabs advance to the next multiple of four, matching how CommonMark
+/// (and comrak/pulldown-cmark) expand indentation when computing block
+/// structure. A plain space counts as one column. The result is a 0-based
+/// column suitable for reporting; span math should use the raw byte columns.
+fn visual_column(prefix: &str) -> usize {
+    let mut col = 0;
+    for c in prefix.chars() {
+        if c == '\t' {
+            col += 4 - (col % 4);
+        } else {
+            col += 1;
+        }
+    }
+    col
+}
+
+/// Return `true` if `line` starts a block-level construct that interrupts an open
+/// blockquote paragraph, so the line cannot be a lazy continuation of the quote.
+fn interrupts_blockquote(line: &str, info: &LineInfo) -> bool {
+    // A list marker begins a new block.
+    if info.list_item.is_some() {
+        return true;
+    }
+    let trimmed = line.trim_start();
+    // ATX heading.
+    if trimmed.starts_with('#') {
+        return true;
+    }
+    // Fenced code block.
+    if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+        return true;
+    }
+    // Thematic break (---, ***, ___).
+    let compact = trimmed.trim();
+    if compact.len() >= 3 {
+        for marker in ['-', '*', '_'] {
+            if compact.chars().all(|c| c == marker || c == ' ')
+                && compact.chars().filter(|&c| c == marker).count() >= 3
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
 /// Pre-computed information about a line
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LineInfo {
     /// The actual line content (without newline)
     pub content: String,
@@ -82,6 +132,7 @@ pub struct LineInfo {
 
 /// Information about a list item
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ListItemInfo {
     /// The marker used (*, -, +, or number with . or ))
     pub marker: String,
@@ -97,6 +148,7 @@ pub struct ListItemInfo {
 
 /// Heading style type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum HeadingStyle {
     /// ATX style heading (# Heading)
     ATX,
@@ -186,6 +238,7 @@ pub struct CodeSpan {
 
 /// Information about a heading
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HeadingInfo {
     /// Heading level (1-6 for ATX, 1-2 for Setext)
     pub level: u8,
@@ -193,10 +246,14 @@ pub struct HeadingInfo {
     pub style: HeadingStyle,
     /// The heading marker (# characters or underline)
     pub marker: String,
-    /// Column where the marker starts (0-based)
+    /// Column where the marker starts, measured in bytes (0-based, for span math)
     pub marker_column: usize,
-    /// Column where heading text starts
+    /// Column where heading text starts, measured in bytes (0-based, for span math)
     pub content_column: usize,
+    /// Visual column of the marker with tabs expanded to the next multiple of four
+    pub marker_visual_column: usize,
+    /// Visual column of the heading text with tabs expanded to the next multiple of four
+    pub content_visual_column: usize,
     /// The heading text (without markers and without custom ID syntax)
     pub text: String,
     /// Custom header ID if present (e.g., from {#custom-id} syntax)
@@ -211,13 +268,16 @@ pub struct HeadingInfo {
 
 /// Information about a blockquote line
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BlockquoteInfo {
     /// Nesting level (1 for >, 2 for >>, etc.)
     pub nesting_level: usize,
     /// The indentation before the blockquote marker
     pub indent: String,
-    /// Column where the first > starts (0-based)
+    /// Column where the first > starts, measured in bytes (0-based, for span math)
     pub marker_column: usize,
+    /// Visual column of the first > with tabs expanded to the next multiple of four
+    pub marker_visual_column: usize,
     /// The blockquote prefix (e.g., "> ", ">> ", etc.)
     pub prefix: String,
     /// Content after the blockquote marker(s)
@@ -228,6 +288,8 @@ pub struct BlockquoteInfo {
     pub has_multiple_spaces_after_marker: bool,
     /// Whether this is an empty blockquote line needing MD028 fix
     pub needs_md028_fix: bool,
+    /// Whether this line is a lazy paragraph continuation of the quote (no `>` marker)
+    pub is_lazy_continuation: bool,
 }
 
 /// Information about a list block
@@ -358,6 +420,54 @@ pub struct BareUrl {
     pub url_type: String,
 }
 
+/// A footnote definition together with the full line span of its body.
+///
+/// A definition opens with `[^id]:` and may continue across further indented
+/// block elements (paragraphs, nested lists, blockquotes). The span covers the
+/// opener through the last continuation line so consumers can remove or point
+/// at the complete footnote body.
+#[derive(Debug, Clone)]
+pub struct FootnoteDefinition {
+    /// Footnote id (lowercased).
+    pub id: String,
+    /// 1-indexed line of the `[^id]:` opener.
+    pub start_line: usize,
+    /// 1-indexed last line belonging to the definition body.
+    pub end_line: usize,
+    /// Byte range spanning the opener through the last continuation line.
+    pub byte_range: std::ops::Range<usize>,
+}
+
+/// Precomputed index of footnote references and definitions for a document.
+///
+/// Built once per [`LintContext`] (respecting code blocks, front matter, HTML
+/// comments/blocks, code spans, and blockquote stripping) so footnote-aware
+/// rules share a single scan instead of re-running the footnote regexes on
+/// every line. Ids are lowercased to match footnotes case-insensitively.
+#[derive(Debug, Default, Clone)]
+pub struct FootnoteIndex {
+    /// Reference occurrences keyed by lowercased id, each `(line, byte_offset)`.
+    pub references: std::collections::HashMap<String, Vec<(usize, usize)>>,
+    /// Definition occurrences keyed by lowercased id, each carrying its full
+    /// line span (see [`FootnoteDefinition`]).
+    pub definitions: std::collections::HashMap<String, Vec<FootnoteDefinition>>,
+    /// Pandoc inline footnotes (`^[...]`), recorded as `(line, byte_offset)` of
+    /// the opening `^`. These are self-contained (no label, no definition) and
+    /// are excluded from the orphan checks.
+    pub inline_footnotes: Vec<(usize, usize)>,
+    /// Reference occurrences whose label contains internal whitespace, recorded
+    /// as `(raw_label, line, byte_offset)`. These are also present in
+    /// [`references`](Self::references) keyed by their lowercased id; a rule
+    /// decides whether to treat them as valid (see MD066's `allow_spaces`).
+    pub invalid_labels: Vec<(String, usize, usize)>,
+    /// Ids defined more than once (lowercased).
+    pub duplicate_ids: std::collections::HashSet<String>,
+    /// Referenced ids without a matching definition (lowercased).
+    pub orphan_refs: std::collections::HashSet<String>,
+    /// Defined ids that are never referenced (lowercased).
+    pub orphan_defs: std::collections::HashSet<String>,
+}
+
 pub struct LintContext<'a> {
     pub content: &'a str,
     pub line_offsets: Vec<usize>,
@@ -373,6 +483,9 @@ pub struct LintContext<'a> {
     emphasis_spans_cache: Mutex<Option<Arc<Vec<EmphasisSpan>>>>, // Lazy-loaded emphasis spans
     table_rows_cache: Mutex<Option<Arc<Vec<TableRow>>>>, // Lazy-loaded table rows
     bare_urls_cache: Mutex<Option<Arc<Vec<BareUrl>>>>, // Lazy-loaded bare URLs
+    footnote_index_cache: Mutex<Option<Arc<FootnoteIndex>>>, // Lazy-loaded footnote index
+    inline_config: InlineConfig,          // Parsed `rumdl-disable`/`-enable` directives
+    pub line_ranges: Option<Vec<std::ops::RangeInclusive<usize>>>, // `--lines`/`--diff-only` restriction, if any
 }
 
 impl<'a> LintContext<'a> {
@@ -400,6 +513,9 @@ impl<'a> LintContext<'a> {
         // Compute character frequency for fast content analysis
         let char_frequency = Self::compute_char_frequency(content);
 
+        // Parse `rumdl-disable`/`rumdl-enable`/`rumdl-disable-next-line` comments once
+        let inline_config = InlineConfig::from_content(content);
+
         Self {
             content,
             line_offsets,
@@ -415,9 +531,38 @@ impl<'a> LintContext<'a> {
             emphasis_spans_cache: Mutex::new(None),
             table_rows_cache: Mutex::new(None),
             bare_urls_cache: Mutex::new(None),
+            footnote_index_cache: Mutex::new(None),
+            inline_config,
+            line_ranges: None,
         }
     }
 
+    /// Restrict this context to only the given inclusive line ranges (see
+    /// `--lines`/`--diff-only`). Rules use [`LintContext::line_in_range`] to
+    /// skip warnings, and to leave out-of-range lines byte-for-byte untouched
+    /// when fixing.
+    pub fn with_line_ranges(mut self, ranges: Vec<std::ops::RangeInclusive<usize>>) -> Self {
+        self.line_ranges = Some(ranges);
+        self
+    }
+
+    /// Whether `line` (1-indexed) falls inside the configured line-range
+    /// restriction, or whether there is no restriction at all.
+    pub fn line_in_range(&self, line: usize) -> bool {
+        match &self.line_ranges {
+            None => true,
+            Some(ranges) => ranges.iter().any(|r| r.contains(&line)),
+        }
+    }
+
+    /// Whether `rule_name` has been suppressed at `line` (1-indexed) by an
+    /// inline `<!-- rumdl-disable -->`/`<!-- markdownlint-disable -->` family
+    /// comment. Directives inside fenced code blocks are ignored when this was
+    /// parsed, so examples in docs can't accidentally toggle linting.
+    pub fn is_suppressed(&self, rule_name: &str, line: usize) -> bool {
+        self.inline_config.is_rule_disabled(rule_name, line)
+    }
+
     /// Get code spans - computed lazily on first access
     pub fn code_spans(&self) -> Arc<Vec<CodeSpan>> {
         let mut cache = self.code_spans_cache.lock().unwrap();
@@ -480,6 +625,227 @@ impl<'a> LintContext<'a> {
         cache.as_ref().unwrap().clone()
     }
 
+    /// Get the footnote index - computed lazily on first access.
+    ///
+    /// Provides a single source of truth for footnote reference/definition
+    /// locations so rules like MD066 do not each re-scan the document.
+    pub fn footnote_index(&self) -> Arc<FootnoteIndex> {
+        let mut cache = self.footnote_index_cache.lock().unwrap();
+
+        if cache.is_none() {
+            let index = self.parse_footnote_index();
+            *cache = Some(Arc::new(index));
+        }
+
+        cache.as_ref().unwrap().clone()
+    }
+
+    /// Scan the document for footnote references (`[^id]`) and definitions
+    /// (`[^id]: ...`), skipping code blocks, front matter, HTML comments/blocks,
+    /// and inline code spans, and stripping blockquote prefixes before matching
+    /// definitions. Reference and definition ids are lowercased.
+    fn parse_footnote_index(&self) -> FootnoteIndex {
+        use crate::rules::md066_footnote_validation::{
+            FOOTNOTE_DEF_PATTERN, FOOTNOTE_REF_PATTERN, strip_blockquote_prefix,
+        };
+        use std::collections::{HashMap, HashSet};
+
+        let mut references: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut definitions: HashMap<String, Vec<FootnoteDefinition>> = HashMap::new();
+        let mut invalid_labels: Vec<(String, usize, usize)> = Vec::new();
+        let mut inline_footnotes: Vec<(usize, usize)> = Vec::new();
+
+        // Locate Pandoc inline footnotes `^[...]` within a line, returning their
+        // line-relative byte ranges. Honours nested brackets and backslash
+        // escaping so that `^[see [bracket]]` is treated as one span.
+        let scan_inline = |line: &str| -> Vec<std::ops::Range<usize>> {
+            let chars: Vec<(usize, char)> = line.char_indices().collect();
+            let mut spans = Vec::new();
+            let mut i = 0;
+            while i < chars.len() {
+                let (start, c) = chars[i];
+                if c == '\\' {
+                    i += 2;
+                    continue;
+                }
+                if c == '^' && i + 1 < chars.len() && chars[i + 1].1 == '[' {
+                    let mut depth = 1;
+                    let mut j = i + 2;
+                    let mut end = None;
+                    while j < chars.len() {
+                        match chars[j].1 {
+                            '\\' => {
+                                j += 2;
+                                continue;
+                            }
+                            '[' => depth += 1,
+                            ']' => {
+                                depth -= 1;
+                                if depth == 0 {
+                                    end = Some(chars[j].0 + 1);
+                                    break;
+                                }
+                            }
+                            _ => {}
+                        }
+                        j += 1;
+                    }
+                    if let Some(end) = end {
+                        spans.push(start..end);
+                        i = j + 1;
+                        continue;
+                    }
+                }
+                i += 1;
+            }
+            spans
+        };
+
+        // A continuation line belongs to the preceding definition when its
+        // blockquote-stripped content is indented by at least four spaces or a
+        // tab (per the footnote dialect's continuation rules).
+        let is_continuation = |idx: usize| -> bool {
+            let stripped = strip_blockquote_prefix(self.lines[idx].content(self.content));
+            stripped.starts_with("    ") || stripped.starts_with('\t')
+        };
+        // Last 0-indexed line belonging to a definition opened at `start_idx`,
+        // following indented continuations and the blank lines between them.
+        let definition_block_end = |start_idx: usize| -> usize {
+            let mut end = start_idx;
+            let mut j = start_idx + 1;
+            while j < self.lines.len() {
+                let stripped = strip_blockquote_prefix(self.lines[j].content(self.content));
+                if is_continuation(j) {
+                    end = j;
+                    j += 1;
+                } else if stripped.trim().is_empty() {
+                    // A blank line only belongs to the definition if a further
+                    // continuation line follows it.
+                    let mut k = j;
+                    while k < self.lines.len()
+                        && strip_blockquote_prefix(self.lines[k].content(self.content))
+                            .trim()
+                            .is_empty()
+                    {
+                        k += 1;
+                    }
+                    if k < self.lines.len() && is_continuation(k) {
+                        end = k;
+                        j = k + 1;
+                    } else {
+                        break;
+                    }
+                } else {
+                    break;
+                }
+            }
+            end
+        };
+
+        let code_spans = self.code_spans();
+        for (line_idx, line_info) in self.lines.iter().enumerate() {
+            if line_info.in_code_block
+                || line_info.in_front_matter
+                || line_info.in_html_comment
+                || line_info.in_html_block
+            {
+                continue;
+            }
+
+            let line = line_info.content(self.content);
+            let line_num = line_idx + 1; // 1-indexed
+
+            // Pandoc inline footnotes `^[...]`, skipping any that open inside a
+            // code span. Their document byte ranges also suppress nested `[^id]`
+            // tokens from being counted as ordinary references.
+            let inline_spans: Vec<std::ops::Range<usize>> = scan_inline(line)
+                .into_iter()
+                .map(|r| (line_info.byte_offset + r.start)..(line_info.byte_offset + r.end))
+                .filter(|r| {
+                    !code_spans
+                        .iter()
+                        .any(|span| r.start >= span.byte_offset && r.start < span.byte_end)
+                })
+                .collect();
+            for span in &inline_spans {
+                inline_footnotes.push((line_num, span.start));
+            }
+
+            // References: `[^id]` not followed by `:` and not inside a code span.
+            for caps in FOOTNOTE_REF_PATTERN.captures_iter(line).flatten() {
+                if let Some(id_match) = caps.get(1) {
+                    let match_start = caps.get(0).unwrap().start();
+                    let byte_offset = line_info.byte_offset + match_start;
+                    let in_code_span = code_spans
+                        .iter()
+                        .any(|span| byte_offset >= span.byte_offset && byte_offset < span.byte_end);
+                    let in_inline = inline_spans.iter().any(|r| byte_offset >= r.start && byte_offset < r.end);
+                    if !in_code_span && !in_inline {
+                        let label = id_match.as_str();
+                        // A label that carries internal whitespace (or would span a
+                        // line break) is rejected by stricter footnote dialects. We
+                        // still index it so rules can opt back in via `allow_spaces`.
+                        if label.chars().any(char::is_whitespace) {
+                            invalid_labels.push((label.to_string(), line_num, byte_offset));
+                        }
+                        references
+                            .entry(label.to_lowercase())
+                            .or_default()
+                            .push((line_num, byte_offset));
+                    }
+                }
+            }
+
+            // Definitions: `[^id]:` at line start after stripping blockquote prefixes.
+            let line_stripped = strip_blockquote_prefix(line);
+            if let Some(caps) = FOOTNOTE_DEF_PATTERN.captures(line_stripped)
+                && let Some(id_match) = caps.get(1)
+            {
+                let end_idx = definition_block_end(line_idx);
+                let end_info = &self.lines[end_idx];
+                definitions.entry(id_match.as_str().to_lowercase()).or_default().push(
+                    FootnoteDefinition {
+                        id: id_match.as_str().to_lowercase(),
+                        start_line: line_num,
+                        end_line: end_idx + 1,
+                        byte_range: line_info.byte_offset..(end_info.byte_offset + end_info.byte_len),
+                    },
+                );
+            }
+        }
+
+        for occurrences in references.values_mut() {
+            occurrences.sort();
+            occurrences.dedup();
+        }
+
+        let duplicate_ids: HashSet<String> = definitions
+            .iter()
+            .filter(|(_, occ)| occ.len() > 1)
+            .map(|(id, _)| id.clone())
+            .collect();
+        let orphan_refs: HashSet<String> = references
+            .keys()
+            .filter(|id| !definitions.contains_key(*id))
+            .cloned()
+            .collect();
+        let orphan_defs: HashSet<String> = definitions
+            .keys()
+            .filter(|id| !references.contains_key(*id))
+            .cloned()
+            .collect();
+
+        FootnoteIndex {
+            references,
+            definitions,
+            inline_footnotes,
+            invalid_labels,
+            duplicate_ids,
+            orphan_refs,
+            orphan_defs,
+        }
+    }
+
     /// Map a byte offset to (line, column)
     pub fn offset_to_line_col(&self, offset: usize) -> (usize, usize) {
         match self.line_offsets.binary_search(&offset) {
@@ -491,6 +857,29 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Map a byte offset to an LSP `Position`: a 0-indexed line paired with a
+    /// UTF-16 code-unit column, as the Language Server Protocol requires
+    /// (VS Code and most other LSP clients count `character` in UTF-16 units,
+    /// not bytes or Unicode scalar values).
+    pub fn offset_to_lsp_position(&self, offset: usize) -> crate::rule::LspPosition {
+        let (line, byte_col) = self.offset_to_line_col(offset);
+        let line_start = self.line_offsets.get(line - 1).copied().unwrap_or(0);
+        let line_end = self
+            .line_offsets
+            .get(line)
+            .copied()
+            .unwrap_or(self.content.len())
+            .min(self.content.len());
+        let byte_col = (byte_col - 1).min(line_end - line_start);
+        let line_text = &self.content[line_start..line_end];
+        let character = line_text[..byte_col.min(line_text.len())].encode_utf16().count();
+
+        crate::rule::LspPosition {
+            line: (line - 1) as u32,
+            character: character as u32,
+        }
+    }
+
     /// Check if a position is within a code block or code span
     pub fn is_in_code_block_or_span(&self, pos: usize) -> bool {
         // Check code blocks first
@@ -1031,14 +1420,19 @@ impl<'a> LintContext<'a> {
             });
         }
 
-        // Second pass: detect headings (including Setext which needs look-ahead) and blockquotes
+        // Second pass: detect headings (including Setext which needs look-ahead) and blockquotes.
+        // `open_bq_level` tracks the nesting level of the blockquote paragraph currently open,
+        // so a following marker-less line can be recognized as a lazy continuation.
+        let mut open_bq_level: usize = 0;
         for i in 0..content_lines.len() {
             if lines[i].in_code_block {
+                open_bq_level = 0;
                 continue;
             }
 
             // Skip lines in front matter
             if in_front_matter && i <= front_matter_end {
+                open_bq_level = 0;
                 continue;
             }
 
@@ -1053,6 +1447,7 @@ impl<'a> LintContext<'a> {
 
                 let nesting_level = markers.chars().filter(|&c| c == '>').count();
                 let marker_column = indent_str.len();
+                let marker_visual_column = visual_column(indent_str);
 
                 // Build the prefix (indentation + markers + space)
                 let prefix = format!("{indent_str}{markers}{spaces_after}");
@@ -1069,12 +1464,39 @@ impl<'a> LintContext<'a> {
                     nesting_level,
                     indent: indent_str.to_string(),
                     marker_column,
+                    marker_visual_column,
                     prefix,
                     content: content.to_string(),
                     has_no_space_after_marker: has_no_space,
                     has_multiple_spaces_after_marker: has_multiple_spaces,
                     needs_md028_fix,
+                    is_lazy_continuation: false,
+                });
+
+                // A non-empty quoted paragraph line opens (or sustains) a quote
+                // that a following marker-less paragraph line can lazily continue.
+                open_bq_level = if content.trim().is_empty() { 0 } else { nesting_level };
+            } else if lines[i].is_blank {
+                // A blank line always terminates lazy continuation.
+                open_bq_level = 0;
+            } else if open_bq_level > 0 && !interrupts_blockquote(line, &lines[i]) {
+                // Lazy continuation: a marker-less paragraph line inside an open
+                // quote inherits the quote's nesting level (CommonMark §5.1).
+                lines[i].blockquote = Some(BlockquoteInfo {
+                    nesting_level: open_bq_level,
+                    indent: String::new(),
+                    marker_column: 0,
+                    marker_visual_column: 0,
+                    prefix: String::new(),
+                    content: line.to_string(),
+                    has_no_space_after_marker: false,
+                    has_multiple_spaces_after_marker: false,
+                    needs_md028_fix: false,
+                    is_lazy_continuation: true,
                 });
+            } else {
+                // A block-level construct interrupts the quote.
+                open_bq_level = 0;
             }
 
             // Skip heading detection for blank lines
@@ -1151,6 +1573,8 @@ impl<'a> LintContext<'a> {
                 };
 
                 let content_column = marker_column + hashes.len() + spaces_after.len();
+                let marker_visual_column = visual_column(leading_spaces);
+                let content_visual_column = visual_column(&format!("{leading_spaces}{hashes}{spaces_after}"));
 
                 // Extract custom header ID if present
                 let raw_text = text.trim().to_string();
@@ -1174,6 +1598,8 @@ impl<'a> LintContext<'a> {
                     marker: hashes.to_string(),
                     marker_column,
                     content_column,
+                    marker_visual_column,
+                    content_visual_column,
                     text: clean_text,
                     custom_id,
                     raw_text,
@@ -1220,6 +1646,10 @@ impl<'a> LintContext<'a> {
                         marker: underline.to_string(),
                         marker_column: next_line.len() - next_line.trim_start().len(),
                         content_column: lines[i].indent,
+                        marker_visual_column: visual_column(
+                            &next_line[..next_line.len() - next_line.trim_start().len()],
+                        ),
+                        content_visual_column: visual_column(&line[..line.len() - line.trim_start().len()]),
                         text: clean_text,
                         custom_id,
                         raw_text,
@@ -2382,6 +2812,32 @@ mod tests {
         assert_eq!(ctx.offset_to_line_col(3), (1, 4));
     }
 
+    #[test]
+    fn test_heading_visual_column_expands_tabs() {
+        // A leading tab is one byte but four visual columns.
+        let ctx = LintContext::new("\t# Heading\n");
+        let heading = ctx.lines[0].heading.as_ref().expect("tab-indented heading");
+        assert_eq!(heading.marker_column, 1); // one byte of indent
+        assert_eq!(heading.marker_visual_column, 4); // tab expands to column 4
+    }
+
+    #[test]
+    fn test_blockquote_lazy_continuation() {
+        // The second line has no `>` marker but continues the quote paragraph.
+        let ctx = LintContext::new("> first line\nsecond line\n");
+        let first = ctx.lines[0].blockquote.as_ref().expect("marker line is a quote");
+        assert!(!first.is_lazy_continuation);
+        let second = ctx.lines[1].blockquote.as_ref().expect("lazy line is attributed to the quote");
+        assert!(second.is_lazy_continuation);
+        assert_eq!(second.nesting_level, 1);
+    }
+
+    #[test]
+    fn test_blockquote_lazy_continuation_stops_at_blank() {
+        let ctx = LintContext::new("> quoted\n\nplain paragraph\n");
+        assert!(ctx.lines[2].blockquote.is_none());
+    }
+
     #[test]
     fn test_multi_line() {
         let content = "# Title\n\nSecond line\nThird line";
@@ -2477,4 +2933,70 @@ mod tests {
         assert_eq!(ctx.offset_to_line_col(4), (3, 1)); // 'c'
         assert_eq!(ctx.offset_to_line_col(5), (3, 2)); // after 'c'
     }
+
+    #[test]
+    fn test_footnote_index() {
+        let content = "A note[^used] and [^orphan].\n\n[^used]: defined\n[^used]: again\n[^unused]: never referenced\n";
+        let ctx = LintContext::new(content);
+        let index = ctx.footnote_index();
+
+        // References are keyed by lowercased id.
+        assert!(index.references.contains_key("used"));
+        assert!(index.references.contains_key("orphan"));
+        assert!(index.definitions.contains_key("used"));
+        assert!(index.definitions.contains_key("unused"));
+
+        // `used` is defined twice, so it is a duplicate.
+        assert!(index.duplicate_ids.contains("used"));
+        // `orphan` is referenced but never defined.
+        assert!(index.orphan_refs.contains("orphan"));
+        // `unused` is defined but never referenced.
+        assert!(index.orphan_defs.contains("unused"));
+        assert!(!index.orphan_defs.contains("used"));
+    }
+
+    #[test]
+    fn test_footnote_definition_block_span() {
+        // A multi-paragraph definition: opener on line 1, an indented second
+        // paragraph on line 3 (blank line 2 between them).
+        let content = "[^note]: First paragraph.\n\n    Second paragraph.\nback to prose.\n";
+        let ctx = LintContext::new(content);
+        let index = ctx.footnote_index();
+
+        let defs = index.definitions.get("note").expect("definition indexed");
+        assert_eq!(defs.len(), 1);
+        assert_eq!(defs[0].start_line, 1);
+        assert_eq!(defs[0].end_line, 3);
+    }
+
+    #[test]
+    fn test_inline_footnotes_recorded_and_suppress_nested_refs() {
+        let content = "Text^[an inline note with [^nested] inside] and a real[^r].\n\n[^r]: def.";
+        let ctx = LintContext::new(content);
+        let index = ctx.footnote_index();
+
+        assert_eq!(index.inline_footnotes.len(), 1, "one inline footnote");
+        // The `[^nested]` inside the inline note must not count as a reference.
+        assert!(!index.references.contains_key("nested"));
+        // The real reference outside the inline note is still indexed.
+        assert!(index.references.contains_key("r"));
+    }
+
+    #[test]
+    fn test_inline_footnote_ignored_in_code_span() {
+        let content = "Literal `^[not a note]` here.";
+        let ctx = LintContext::new(content);
+        let index = ctx.footnote_index();
+        assert!(index.inline_footnotes.is_empty());
+    }
+
+    #[test]
+    fn test_footnote_index_skips_code_spans_and_blocks() {
+        let content = "Inline `[^code]` span.\n\n```\n[^fenced]: nope\n```\n";
+        let ctx = LintContext::new(content);
+        let index = ctx.footnote_index();
+
+        assert!(!index.references.contains_key("code"));
+        assert!(!index.definitions.contains_key("fenced"));
+    }
 }
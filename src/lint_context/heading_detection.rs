@@ -92,6 +92,7 @@ pub(super) fn detect_headings_and_blockquotes(
                 has_no_space_after_marker: has_no_space,
                 has_multiple_spaces_after_marker: has_multiple_spaces,
                 needs_md028_fix,
+                is_lazy_continuation: false,
             });
 
             // Update is_horizontal_rule for blockquote content
@@ -128,7 +128,15 @@ pub fn warning_to_code_actions(warning: &crate::rule::LintWarning, uri: &Url, do
     let mut actions = Vec::new();
 
     // Add fix action if available (marked as preferred)
-    if let Some(fix_action) = create_fix_action(warning, uri, document_text) {
+    if let Some(mut fix_action) = create_fix_action(warning, uri, document_text) {
+        // Give MD063 heading-capitalization fixes a style-specific title, the
+        // way rust-analyzer surfaces its "incorrect case" rename, instead of
+        // the generic "Fix: <message>" wording.
+        if warning.rule_name.as_deref() == Some("MD063")
+            && let Some(title) = heading_case_action_title(&warning.message)
+        {
+            fix_action.title = title;
+        }
         actions.push(fix_action);
     }
 
@@ -149,6 +157,22 @@ pub fn warning_to_code_actions(warning: &crate::rule::LintWarning, uri: &Url, do
     actions
 }
 
+/// Build the title for an MD063 heading-capitalization quick fix from its
+/// diagnostic message (e.g. "Change heading to Title Case"). Returns `None`
+/// if the style can't be recognized, so the generic fix title is kept.
+fn heading_case_action_title(message: &str) -> Option<String> {
+    let style = if message.contains("title case") {
+        "Title Case"
+    } else if message.contains("sentence case") {
+        "Sentence case"
+    } else if message.contains("ALL CAPS") {
+        "ALL CAPS"
+    } else {
+        return None;
+    };
+    Some(format!("Change heading to {style}"))
+}
+
 /// Create a fix code action from a rumdl warning with fix
 fn create_fix_action(warning: &crate::rule::LintWarning, uri: &Url, document_text: &str) -> Option<CodeAction> {
     if let Some(fix) = &warning.fix {
@@ -528,6 +552,34 @@ mod tests {
         assert_eq!(edits[0].new_text, "Fixed");
     }
 
+    #[test]
+    fn test_md063_heading_case_action_title() {
+        let warning = LintWarning {
+            line: 1,
+            column: 3,
+            end_line: 1,
+            end_column: 15,
+            rule_name: Some("MD063".to_string()),
+            message: "Heading should use title case: 'heading title' -> 'Heading Title'".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: 0..15,
+                replacement: "# Heading Title".to_string(),
+            }),
+        };
+
+        let uri = Url::parse("file:///test.md").unwrap();
+        let actions = warning_to_code_actions(&warning, &uri, "# heading title");
+        let action = &actions[0];
+
+        // The fix is surfaced with a style-specific title and produces the
+        // corrected heading text.
+        assert_eq!(action.title, "Change heading to Title Case");
+        assert_eq!(action.kind, Some(CodeActionKind::QUICKFIX));
+        let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri];
+        assert_eq!(edits[0].new_text, "# Heading Title");
+    }
+
     #[test]
     fn test_warning_to_code_action_no_fix() {
         let warning = LintWarning {
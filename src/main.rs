@@ -212,6 +212,18 @@ enum Commands {
     Check(CheckArgs),
     /// Format Markdown files (alias for check --fix)
     Fmt(CheckArgs),
+    /// Apply fixes from a `check --output-format=fixes-json` document
+    Apply {
+        /// Path to the fixes-json document (reads stdin if omitted or "-")
+        #[arg(default_value = "-")]
+        input: String,
+        /// Also apply fixes marked as unsafe
+        #[arg(long, help = "Apply fixes marked as unsafe (potentially content-altering)")]
+        unsafe_fixes: bool,
+        /// Only apply fixes from these rules (comma-separated, e.g. MD047,MD009)
+        #[arg(long)]
+        rules: Option<String>,
+    },
     /// Initialize a new configuration file
     Init {
         /// Generate configuration for pyproject.toml instead of .rumdl.toml
@@ -281,6 +293,41 @@ enum Commands {
         #[arg(long)]
         status: bool,
     },
+    /// Run or regenerate fixture snapshots
+    Snapshot {
+        /// Directory containing `*.md` fixtures (searched recursively)
+        #[arg(default_value = "tests/fixtures")]
+        dir: String,
+        /// Regenerate snapshots instead of checking them
+        #[arg(long)]
+        bless: bool,
+    },
+    /// Dump the detected document structure (headings, blockquotes, HTML blocks, rules)
+    Ast {
+        /// Markdown file to analyze
+        file: String,
+        /// Output format: json or sexpr
+        #[arg(long, default_value = "json")]
+        format: String,
+    },
+    /// Benchmark lint throughput and latency over a set of files
+    Bench {
+        /// Files or directories to benchmark (searched recursively for `*.md`)
+        #[arg(required = true)]
+        paths: Vec<String>,
+        /// Warmup iterations per file (discarded)
+        #[arg(long, default_value = "3")]
+        warmup: usize,
+        /// Measured iterations per file
+        #[arg(long, default_value = "20")]
+        iterations: usize,
+        /// Write the run to a JSON file for later comparison
+        #[arg(long)]
+        baseline: Option<String>,
+        /// Compare against a stored JSON baseline and flag regressions
+        #[arg(long)]
+        compare: Option<String>,
+    },
     /// Show version information
     Version,
 }
@@ -291,6 +338,8 @@ enum ConfigSubcommand {
     Get { key: String },
     /// Show the absolute path of the configuration file that was loaded
     File,
+    /// Show only the config keys that differ from the defaults, with provenance
+    Diff,
 }
 
 #[derive(Args, Debug)]
@@ -311,11 +360,15 @@ pub struct CheckArgs {
     #[arg(short, long, default_value = "false")]
     list_rules: bool,
 
-    /// Disable specific rules (comma-separated)
+    /// Disable specific rules (comma-separated). Accepts exact rule names
+    /// (MD013), numeric-code prefixes (MD01 for MD010-MD019), or category
+    /// names (lists, headings, ...)
     #[arg(short, long)]
     disable: Option<String>,
 
-    /// Enable only specific rules (comma-separated)
+    /// Enable only specific rules (comma-separated). Accepts exact rule names
+    /// (MD013), numeric-code prefixes (MD01 for MD010-MD019), or category
+    /// names (lists, headings, ...)
     #[arg(short, long)]
     enable: Option<String>,
 
@@ -327,6 +380,14 @@ pub struct CheckArgs {
     #[arg(long)]
     extend_disable: Option<String>,
 
+    /// Enable only rules with one of these tags (comma-separated, e.g. "heading,table")
+    #[arg(long)]
+    enable_tags: Option<String>,
+
+    /// Disable rules with one of these tags (comma-separated, e.g. "blockquote,whitespace")
+    #[arg(long)]
+    disable_tags: Option<String>,
+
     /// Exclude specific files or directories (comma-separated glob patterns)
     #[arg(long)]
     exclude: Option<String>,
@@ -368,8 +429,8 @@ pub struct CheckArgs {
     output: String,
 
     /// Output format for linting results
-    #[arg(long, value_parser = ["text", "full", "concise", "grouped", "json", "json-lines", "github", "gitlab", "pylint", "azure", "sarif", "junit"],
-          help = "Output format for linting results (text, full, concise, grouped, json, json-lines, github, gitlab, pylint, azure, sarif, junit)")]
+    #[arg(long, value_parser = ["text", "full", "concise", "grouped", "json", "json-lines", "github", "gitlab", "pylint", "azure", "sarif", "junit", "checkstyle", "fixes-json", "annotated"],
+          help = "Output format for linting results (text, full, concise, grouped, json, json-lines, github, gitlab, pylint, azure, sarif, junit, checkstyle, fixes-json, annotated)")]
     output_format: Option<String>,
 
     /// Read from stdin instead of files
@@ -406,6 +467,39 @@ pub struct CheckArgs {
     /// Directory to store cache files
     #[arg(long, help = "Directory to store cache files (default: .rumdl-cache)")]
     cache_dir: Option<String>,
+
+    /// Also apply fixes marked as unsafe (potentially content-altering)
+    #[arg(long, help = "Apply fixes that may change rendered content (e.g. stripping inline HTML)")]
+    unsafe_fixes: bool,
+
+    /// Use a named style preset ("relaxed", "strict") or a path to a preset config
+    /// file as a curated baseline, overriding any `style` set in a config file
+    #[arg(long, help = "Use a style preset (\"relaxed\", \"strict\", or a preset file path) as a baseline config")]
+    style: Option<String>,
+
+    /// Restrict reported warnings to specific line ranges (may be passed multiple
+    /// times). Rules still see the whole file, but warnings outside these ranges
+    /// are dropped; useful for linting only the lines a diff touched.
+    #[arg(long, value_name = "PATH:START-END", help = "Only report warnings in PATH between lines START and END (repeatable)")]
+    file_lines: Vec<String>,
+
+    /// Same as `--file-lines`, but as a single JSON array:
+    /// `[{"file":"a.md","range":[10,40]}]`. Combined with `--file-lines` if both are given.
+    #[arg(long, value_name = "JSON", help = "Only report warnings per a JSON array of {file, range} restrictions")]
+    file_lines_json: Option<String>,
+
+    /// Restrict linting of the current file to specific line ranges (may be
+    /// passed multiple times). Unlike `--file-lines`, this is threaded into
+    /// `LintContext` itself: warnings outside these ranges are dropped, and
+    /// MD016 skips out-of-range lines in its own checks. Fixes applied with
+    /// `--fix` or `--diff` are not limited to these ranges.
+    #[arg(long, value_name = "START:END", help = "Only report warnings for lines START through END, inclusive (repeatable)")]
+    lines: Vec<String>,
+
+    /// Restrict linting to the lines touched by `git diff` against the
+    /// working tree, parsed from unified diff hunk headers.
+    #[arg(long, help = "Only report warnings for lines changed according to `git diff`")]
+    diff_only: bool,
 }
 
 /// Offer to install the VS Code extension during init
@@ -641,6 +735,13 @@ build-backend = "setuptools.build_meta"
                     run_check(&args, cli.config.as_deref(), cli.no_config || cli.isolated);
                 }
             }
+            Commands::Apply {
+                input,
+                unsafe_fixes,
+                rules,
+            } => {
+                rumdl_lib::commands::apply::handle_apply(&input, unsafe_fixes, rules.as_deref());
+            }
             Commands::Rule { rule } => {
                 use rumdl_lib::rules::*;
                 let all_rules: Vec<Box<dyn Rule>> = vec![
@@ -816,6 +917,16 @@ build-backend = "setuptools.build_meta"
                                             None
                                         }
                                     }
+                                    "style" => {
+                                        if let Some(ref style) = final_config.global.style {
+                                            Some((
+                                                toml::Value::String(style.clone()),
+                                                sourced.global.style.as_ref().map(|v| v.source).unwrap_or(ConfigSource::Default),
+                                            ))
+                                        } else {
+                                            None
+                                        }
+                                    }
                                     "flavor" => Some((
                                         toml::Value::String(format!("{:?}", final_config.global.flavor).to_lowercase()),
                                         sourced.global.flavor.source,
@@ -908,6 +1019,12 @@ build-backend = "setuptools.build_meta"
                         }
                     }
                 }
+                // Show keys that diverge from the defaults, with provenance
+                else if let Some(ConfigSubcommand::Diff) = subcmd {
+                    let sourced =
+                        load_config_with_cli_error_handling(cli.config.as_deref(), cli.no_config || cli.isolated);
+                    formatter::print_config_diff(&sourced);
+                }
                 // --- Fallthrough logic for `rumdl config` (no subcommand) ---
                 // This code now runs ONLY if `subcmd` is None
                 else {
@@ -1272,6 +1389,21 @@ build-backend = "setuptools.build_meta"
                     }
                 }
             }
+            Commands::Snapshot { dir, bless } => {
+                handle_snapshot_command(&dir, bless);
+            }
+            Commands::Ast { file, format } => {
+                handle_ast_command(&file, &format);
+            }
+            Commands::Bench {
+                paths,
+                warmup,
+                iterations,
+                baseline,
+                compare,
+            } => {
+                handle_bench_command(&paths, warmup, iterations, baseline.as_deref(), compare.as_deref());
+            }
             Commands::Version => {
                 // Use clap's version info
                 println!("rumdl {}", env!("CARGO_PKG_VERSION"));
@@ -1329,7 +1461,14 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
     };
 
     // 2. Load sourced config (for provenance and validation)
-    let sourced = load_config_with_cli_error_handling_with_dir(global_config_path, isolated, discovery_dir);
+    let mut sourced = load_config_with_cli_error_handling_with_dir(global_config_path, isolated, discovery_dir);
+
+    // 2a. Resolve `--style`/`style = "..."` into a preset fragment, merged in
+    // beneath whatever the config files above already set.
+    if let Err(e) = sourced.apply_style(args.style.as_deref()) {
+        eprintln!("{}: {}", "Config error".red().bold(), e);
+        exit::tool_error();
+    }
 
     // 3. Validate configuration
     let all_rules = rumdl_lib::rules::all_rules(&rumdl_config::Config::default());
@@ -1343,7 +1482,12 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
     }
 
     // 4. Convert to Config for the rest of the linter
-    let config: rumdl_config::Config = sourced.into();
+    let mut config: rumdl_config::Config = sourced.into();
+
+    // `--unsafe-fixes` is opt-in and overrides any config-file value.
+    if args.unsafe_fixes {
+        config.global.unsafe_fixes = true;
+    }
 
     // 5. Initialize cache if enabled
     let cache_enabled = !args.no_cache;
@@ -1378,6 +1522,210 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
     }
 }
 
+// Handle snapshot command
+//
+// Runs every `*.md` fixture under `dir` through the full rule set and compares
+// the rendered diagnostics against a committed `*.md.snap` file next to each
+// fixture. With `--bless`, the snapshots are regenerated instead of checked.
+fn handle_snapshot_command(dir: &str, bless: bool) {
+    use rumdl_lib::output::{ConciseFormatter, OutputFormatter};
+
+    let root = std::path::Path::new(dir);
+    if !root.is_dir() {
+        eprintln!("{}: snapshot directory '{dir}' does not exist", "Error".red().bold());
+        exit::tool_error();
+    }
+
+    let config = rumdl_config::Config::default();
+    let rules = rumdl_lib::rules::all_rules(&config);
+    let formatter = ConciseFormatter::new();
+
+    let mut fixtures = Vec::new();
+    collect_snapshot_fixtures(root, &mut fixtures);
+    fixtures.sort();
+
+    let mut failed = 0;
+    let mut blessed = 0;
+
+    for fixture in &fixtures {
+        let content = match fs::read_to_string(fixture) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Error reading {}: {e}", fixture.display());
+                failed += 1;
+                continue;
+            }
+        };
+
+        let warnings = rumdl_lib::lint(&content, &rules, false).unwrap_or_default();
+        let display = fixture.display().to_string();
+        let actual = formatter.format_warnings(&warnings, &display);
+
+        let snap_path = fixture.with_extension("md.snap");
+
+        if bless {
+            if let Err(e) = fs::write(&snap_path, &actual) {
+                eprintln!("Error writing {}: {e}", snap_path.display());
+                failed += 1;
+            } else {
+                blessed += 1;
+            }
+            continue;
+        }
+
+        let expected = fs::read_to_string(&snap_path).unwrap_or_default();
+        if expected != actual {
+            failed += 1;
+            println!("{}: snapshot mismatch", fixture.display().to_string().yellow());
+            print!("{}", formatter::generate_diff(&expected, &actual, &display));
+        }
+    }
+
+    if bless {
+        println!("Blessed {blessed} snapshot(s)");
+        return;
+    }
+
+    if failed > 0 {
+        eprintln!("{failed} snapshot(s) out of date; re-run with --bless to update");
+        exit::violations_found();
+    }
+
+    println!("All {} snapshot(s) up to date", fixtures.len());
+}
+
+// Recursively collect `*.md` fixtures below `dir`.
+fn collect_snapshot_fixtures(dir: &std::path::Path, out: &mut Vec<std::path::PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_snapshot_fixtures(&path, out);
+        } else if path.extension().and_then(|e| e.to_str()) == Some("md") {
+            out.push(path);
+        }
+    }
+}
+
+// Handle bench command
+//
+// Collects `*.md` files under the given paths, benchmarks the default rule set
+// over them, prints a human summary, and optionally writes a baseline or
+// compares against a stored one to flag throughput regressions.
+fn handle_bench_command(
+    paths: &[String],
+    warmup: usize,
+    iterations: usize,
+    baseline: Option<&str>,
+    compare: Option<&str>,
+) {
+    use rumdl_lib::bench::{BenchConfig, BenchReport, bench};
+
+    let mut files = Vec::new();
+    for path in paths {
+        let p = std::path::Path::new(path);
+        if p.is_dir() {
+            collect_snapshot_fixtures(p, &mut files);
+        } else if p.is_file() {
+            files.push(p.to_path_buf());
+        } else {
+            eprintln!("{}: '{path}' does not exist", "Error".red().bold());
+            exit::tool_error();
+        }
+    }
+    files.sort();
+
+    if files.is_empty() {
+        eprintln!("{}: no files to benchmark", "Error".red().bold());
+        exit::tool_error();
+    }
+
+    let config = rumdl_config::Config::default();
+    let rules = rumdl_lib::rules::all_rules(&config);
+
+    let mut inputs = Vec::with_capacity(files.len());
+    for file in &files {
+        match fs::read_to_string(file) {
+            Ok(content) => inputs.push((file.display().to_string(), content)),
+            Err(e) => eprintln!("Error reading {}: {e}", file.display()),
+        }
+    }
+
+    let report = bench(&inputs, &rules, BenchConfig { warmup, measured: iterations });
+    print!("{}", report.render());
+
+    if let Some(path) = baseline {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => {
+                if let Err(e) = fs::write(path, json) {
+                    eprintln!("Error writing baseline {path}: {e}");
+                    exit::tool_error();
+                }
+                println!("\nBaseline written to {path}");
+            }
+            Err(e) => eprintln!("Error serializing baseline: {e}"),
+        }
+    }
+
+    if let Some(path) = compare {
+        let stored = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("Error reading baseline {path}: {e}");
+                exit::tool_error();
+            }
+        };
+        let prev: BenchReport = match serde_json::from_str(&stored) {
+            Ok(prev) => prev,
+            Err(e) => {
+                eprintln!("Error parsing baseline {path}: {e}");
+                exit::tool_error();
+            }
+        };
+        let regressions = report.regressions(&prev, 0.10);
+        if regressions.is_empty() {
+            println!("\nNo regressions against {path}");
+        } else {
+            eprintln!("\n{}", "Performance regressions:".red().bold());
+            for line in &regressions {
+                eprintln!("  {line}");
+            }
+            exit::violations_found();
+        }
+    }
+}
+
+// Handle ast command
+//
+// Runs the detection pipeline over a single file and prints the resulting
+// document model as JSON or as a compact S-expression.
+fn handle_ast_command(file: &str, format: &str) {
+    use rumdl_lib::lint_context::LintContext;
+    use rumdl_lib::utils::document_model::DocumentModel;
+
+    let content = match fs::read_to_string(file) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}: could not read '{file}': {e}", "Error".red().bold());
+            exit::tool_error();
+        }
+    };
+
+    let ctx = LintContext::new(&content, rumdl_config::MarkdownFlavor::Standard, None);
+    let model = DocumentModel::from_lines(&ctx.lines);
+
+    match format {
+        "json" => println!("{}", model.to_json()),
+        "sexpr" => print!("{}", model.to_sexpr()),
+        other => {
+            eprintln!("{}: unknown ast format '{other}' (expected json or sexpr)", "Error".red().bold());
+            exit::tool_error();
+        }
+    }
+}
+
 // Handle explain command
 fn handle_explain_command(rule_query: &str) {
     use rumdl_lib::rules::*;
@@ -0,0 +1,358 @@
+//! Annotated-snippet output formatter (rustc / `annotate-snippets` style)
+//!
+//! Renders each violation as an underlined excerpt of the offending source
+//! line with a caret span and an inline label or — for fixable rules — the
+//! suggested replacement as a `help:` annotation instead. Multiple violations on the
+//! same line are grouped into a single snippet. Byte offsets are mapped to
+//! display columns so the underline lines up under wide Unicode and tabs.
+
+use crate::output::OutputFormatter;
+use crate::rule::LintWarning;
+use colored::*;
+use unicode_width::UnicodeWidthChar;
+
+/// Annotated-snippet formatter.
+pub struct AnnotatedFormatter {
+    use_colors: bool,
+    /// Number of unannotated source lines shown before and after each snippet.
+    context_lines: usize,
+}
+
+impl Default for AnnotatedFormatter {
+    fn default() -> Self {
+        Self {
+            use_colors: true,
+            context_lines: 2,
+        }
+    }
+}
+
+/// A single underline span within a source line, in display columns.
+struct Annotation {
+    /// 0-based display column of the underline start.
+    start: usize,
+    /// Number of display columns to underline (at least 1).
+    width: usize,
+    /// Label shown after the carets when there is no `help` (the diagnostic message).
+    label: String,
+    /// Optional `help:` text (the suggested replacement) for fixable rules.
+    help: Option<String>,
+}
+
+impl AnnotatedFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn without_colors() -> Self {
+        Self {
+            use_colors: false,
+            context_lines: 2,
+        }
+    }
+
+    /// Set how many unannotated lines of surrounding context to show around
+    /// each snippet.
+    pub fn with_context(mut self, context_lines: usize) -> Self {
+        self.context_lines = context_lines;
+        self
+    }
+
+    /// Display width of a single char at the given running column, expanding
+    /// tabs to the next 8-column tab stop and honoring wide/zero-width chars.
+    fn char_width(ch: char, col: usize) -> usize {
+        if ch == '\t' {
+            8 - (col % 8)
+        } else {
+            UnicodeWidthChar::width(ch).unwrap_or(0)
+        }
+    }
+
+    /// Map a 0-based byte offset within `line` to its 0-based display column.
+    fn display_column(line: &str, byte_offset: usize) -> usize {
+        let mut col = 0;
+        let mut byte = 0;
+        for ch in line.chars() {
+            if byte >= byte_offset {
+                break;
+            }
+            col += Self::char_width(ch, col);
+            byte += ch.len_utf8();
+        }
+        col
+    }
+
+    /// Turn a warning into a display-column annotation for `line`.
+    fn annotation_for(warning: &LintWarning, line: &str) -> Annotation {
+        let start_byte = warning.column.saturating_sub(1);
+        let end_byte = warning.end_column.saturating_sub(1).max(warning.column);
+        let start = Self::display_column(line, start_byte);
+        let end = Self::display_column(line, end_byte);
+        let help = warning
+            .fix
+            .as_ref()
+            .map(|fix| fix.replacement.trim_end_matches('\n').to_string());
+        Annotation {
+            start,
+            width: end.saturating_sub(start).max(1),
+            label: warning.message.clone(),
+            help,
+        }
+    }
+
+    fn blue(&self, s: &str) -> String {
+        if self.use_colors {
+            s.blue().bold().to_string()
+        } else {
+            s.to_string()
+        }
+    }
+}
+
+impl OutputFormatter for AnnotatedFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        // Without source content we can only print the header and location.
+        let mut output = String::new();
+        for (i, warning) in warnings.iter().enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+            let rule = warning.rule_name.as_deref().unwrap_or("unknown");
+            let head = format!("{rule}: {}", warning.message);
+            output.push_str(&if self.use_colors {
+                format!("{}\n", head.yellow().bold())
+            } else {
+                format!("{head}\n")
+            });
+            output.push_str(&format!(
+                " {} {file_path}:{}:{}\n",
+                self.blue("-->"),
+                warning.line,
+                warning.column
+            ));
+        }
+        if output.ends_with('\n') {
+            output.pop();
+        }
+        output
+    }
+
+    fn format_warnings_with_content(&self, warnings: &[LintWarning], file_path: &str, content: &str) -> String {
+        if content.is_empty() || warnings.is_empty() {
+            return self.format_warnings(warnings, file_path);
+        }
+
+        let lines: Vec<&str> = content.lines().collect();
+        let pipe = self.blue("|");
+
+        // Group warnings by (1-based) line so several annotations share one
+        // source excerpt.
+        let mut groups: Vec<(usize, Vec<&LintWarning>)> = Vec::new();
+        for warning in warnings {
+            match groups.iter_mut().find(|(line, _)| *line == warning.line) {
+                Some((_, group)) => group.push(warning),
+                None => groups.push((warning.line, vec![warning])),
+            }
+        }
+
+        let mut output = String::new();
+        for (idx, (line_num, group)) in groups.iter().enumerate() {
+            if idx > 0 {
+                output.push('\n');
+            }
+
+            // Header: rule + message from the first warning on the line.
+            let first = group[0];
+            let rule = first.rule_name.as_deref().unwrap_or("unknown");
+            let head = format!("{rule}: {}", first.message);
+            output.push_str(&if self.use_colors {
+                format!("{}\n", head.yellow().bold())
+            } else {
+                format!("{head}\n")
+            });
+            output.push_str(&format!(
+                " {} {file_path}:{}:{}\n",
+                self.blue("-->"),
+                line_num,
+                first.column
+            ));
+
+            let line_idx = line_num.saturating_sub(1);
+            let Some(source_line) = lines.get(line_idx) else {
+                continue;
+            };
+
+            // Size the gutter for the widest line number we will print,
+            // including the trailing context lines.
+            let last_context = (line_num + self.context_lines).min(lines.len());
+            let gutter_width = last_context.to_string().len().max(2);
+            let empty_gutter = " ".repeat(gutter_width);
+
+            output.push_str(&format!("{empty_gutter} {pipe}\n"));
+
+            // Preceding context lines (no carets).
+            let first_context = line_num.saturating_sub(self.context_lines).max(1);
+            for ctx_num in first_context..*line_num {
+                if let Some(ctx_line) = lines.get(ctx_num - 1) {
+                    output.push_str(&format!(
+                        "{} {pipe} {ctx_line}\n",
+                        self.blue(&format!("{ctx_num:>gutter_width$}"))
+                    ));
+                }
+            }
+
+            output.push_str(&format!(
+                "{} {pipe} {source_line}\n",
+                self.blue(&format!("{line_num:>gutter_width$}"))
+            ));
+
+            // One caret row per annotation on this line, in column order.
+            let mut annotations: Vec<Annotation> =
+                group.iter().map(|w| Self::annotation_for(w, source_line)).collect();
+            annotations.sort_by_key(|a| a.start);
+
+            for annotation in &annotations {
+                let padding = " ".repeat(annotation.start);
+                let carets = "^".repeat(annotation.width);
+                let carets = if self.use_colors {
+                    carets.yellow().bold().to_string()
+                } else {
+                    carets
+                };
+                let mut row = format!("{empty_gutter} {pipe} {padding}{carets}");
+                if let Some(help) = &annotation.help {
+                    row.push_str(&format!(" help: {help}"));
+                } else {
+                    row.push_str(&format!(" {}", annotation.label));
+                }
+                output.push_str(&row);
+                output.push('\n');
+            }
+
+            // Following context lines (no carets).
+            for ctx_num in (line_num + 1)..=last_context {
+                if let Some(ctx_line) = lines.get(ctx_num - 1) {
+                    output.push_str(&format!(
+                        "{} {pipe} {ctx_line}\n",
+                        self.blue(&format!("{ctx_num:>gutter_width$}"))
+                    ));
+                }
+            }
+
+            output.push_str(&format!("{empty_gutter} {pipe}\n"));
+        }
+
+        if output.ends_with('\n') {
+            output.pop();
+        }
+        output
+    }
+
+    fn use_colors(&self) -> bool {
+        self.use_colors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{Fix, Severity};
+
+    fn warning(line: usize, column: usize, end_column: usize, rule: &str, message: &str) -> LintWarning {
+        LintWarning {
+            line,
+            column,
+            end_line: line,
+            end_column,
+            rule_name: Some(rule.to_string()),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_underline_span() {
+        let formatter = AnnotatedFormatter::without_colors();
+        let content = "# heading title\n";
+        let warnings = vec![warning(1, 3, 16, "MD063", "Heading should use title case")];
+        let output = formatter.format_warnings_with_content(&warnings, "doc.md", content);
+        assert!(output.contains("MD063: Heading should use title case"));
+        assert!(output.contains(" --> doc.md:1:3"));
+        assert!(output.contains("1 | # heading title"));
+        // Two spaces of column padding for "# " + a caret under each of the 13
+        // columns of "heading title".
+        assert!(output.contains("  ^^^^^^^^^^^^^"));
+    }
+
+    #[test]
+    fn test_fix_shown_as_help() {
+        let formatter = AnnotatedFormatter::without_colors();
+        let content = "# heading title\n";
+        let mut w = warning(1, 1, 16, "MD063", "Heading should use title case");
+        w.fix = Some(Fix {
+            range: 0..15,
+            replacement: "# Heading Title".to_string(),
+        });
+        let output = formatter.format_warnings_with_content(&[w], "doc.md", content);
+        assert!(output.contains("help: # Heading Title"));
+    }
+
+    #[test]
+    fn test_tab_aware_display_column() {
+        let formatter = AnnotatedFormatter::without_colors();
+        // A leading tab expands to 8 columns, so the caret starts at column 8.
+        let content = "\tword\n";
+        let warnings = vec![warning(1, 2, 6, "MD010", "Hard tab")];
+        let output = formatter.format_warnings_with_content(&warnings, "doc.md", content);
+        assert!(output.contains(&format!("{}^^^^", " ".repeat(8))));
+    }
+
+    #[test]
+    fn test_wide_unicode_display_column() {
+        let formatter = AnnotatedFormatter::without_colors();
+        // The CJK char is two display columns wide, so "x" underlines at col 2.
+        let content = "世x\n";
+        let warnings = vec![warning(1, 4, 5, "MD063", "test")];
+        let output = formatter.format_warnings_with_content(&warnings, "doc.md", content);
+        assert!(output.contains("  ^ "));
+    }
+
+    #[test]
+    fn test_multiple_annotations_grouped() {
+        let formatter = AnnotatedFormatter::without_colors();
+        let content = "foo bar baz\n";
+        let warnings = vec![
+            warning(1, 1, 4, "MD001", "first"),
+            warning(1, 9, 12, "MD002", "second"),
+        ];
+        let output = formatter.format_warnings_with_content(&warnings, "doc.md", content);
+        // A single source excerpt carries both caret rows.
+        assert_eq!(output.matches("foo bar baz").count(), 1);
+        assert!(output.contains("first"));
+        assert!(output.contains("second"));
+    }
+
+    #[test]
+    fn test_surrounding_context_lines() {
+        let formatter = AnnotatedFormatter::without_colors().with_context(1);
+        let content = "line one\nline two\nline three\nline four\n";
+        let warnings = vec![warning(3, 1, 5, "MD001", "issue on line three")];
+        let output = formatter.format_warnings_with_content(&warnings, "doc.md", content);
+        // One line of context on each side of line 3.
+        assert!(output.contains("2 | line two"));
+        assert!(output.contains("3 | line three"));
+        assert!(output.contains("4 | line four"));
+        // Out-of-range context is not shown.
+        assert!(!output.contains("line one"));
+    }
+
+    #[test]
+    fn test_without_content_falls_back() {
+        let formatter = AnnotatedFormatter::without_colors();
+        let warnings = vec![warning(2, 1, 5, "MD001", "msg")];
+        let output = formatter.format_warnings(&warnings, "doc.md");
+        assert!(output.contains("MD001: msg"));
+        assert!(output.contains(" --> doc.md:2:1"));
+    }
+}
@@ -0,0 +1,245 @@
+//! Checkstyle XML output format
+
+use crate::output::OutputFormatter;
+use crate::rule::{LintWarning, Severity};
+
+/// Checkstyle XML formatter for CI systems that already parse Checkstyle reports
+/// (Jenkins, GitLab, GitHub code-scanning, etc.)
+pub struct CheckstyleFormatter;
+
+impl Default for CheckstyleFormatter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl CheckstyleFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for CheckstyleFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        let file_warnings = vec![(file_path.to_string(), warnings.to_vec())];
+        format_checkstyle_report(&file_warnings)
+    }
+}
+
+/// Map rumdl's severity to the Checkstyle severity vocabulary
+fn checkstyle_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+/// Format all warnings from multiple files as a single Checkstyle XML report
+pub fn format_checkstyle_report(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push('\n');
+    xml.push_str(r#"<checkstyle version="4.3">"#);
+    xml.push('\n');
+
+    for (file_path, warnings) in all_warnings {
+        xml.push_str(&format!("  <file name=\"{}\">\n", xml_escape(file_path)));
+
+        for warning in warnings {
+            let rule_name = warning.rule_name.unwrap_or("unknown");
+            xml.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\"/>\n",
+                warning.line,
+                warning.column,
+                checkstyle_severity(warning.severity),
+                xml_escape(&warning.message),
+                xml_escape(rule_name)
+            ));
+        }
+
+        xml.push_str("  </file>\n");
+    }
+
+    xml.push_str("</checkstyle>\n");
+    xml
+}
+
+/// Escape special XML characters
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{Fix, Severity};
+
+    #[test]
+    fn test_checkstyle_formatter_default() {
+        let _formatter = CheckstyleFormatter;
+    }
+
+    #[test]
+    fn test_checkstyle_formatter_new() {
+        let _formatter = CheckstyleFormatter::new();
+    }
+
+    #[test]
+    fn test_format_warnings_empty() {
+        let formatter = CheckstyleFormatter::new();
+        let output = formatter.format_warnings(&[], "test.md");
+
+        assert!(output.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(output.contains(r#"<checkstyle version="4.3">"#));
+        assert!(output.contains(r#"<file name="test.md">"#));
+        assert!(output.contains("</checkstyle>"));
+    }
+
+    #[test]
+    fn test_format_single_warning() {
+        let formatter = CheckstyleFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 10,
+            column: 5,
+            end_line: 10,
+            end_column: 15,
+            rule_name: Some("MD001"),
+            message: "Heading levels should only increment by one level at a time".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }];
+
+        let output = formatter.format_warnings(&warnings, "README.md");
+
+        assert!(output.contains(r#"<file name="README.md">"#));
+        assert!(output.contains(r#"line="10""#));
+        assert!(output.contains(r#"column="5""#));
+        assert!(output.contains(r#"severity="warning""#));
+        assert!(output.contains(
+            r#"message="Heading levels should only increment by one level at a time""#
+        ));
+        assert!(output.contains(r#"source="MD001""#));
+    }
+
+    #[test]
+    fn test_format_warning_unknown_rule() {
+        let formatter = CheckstyleFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 5,
+            rule_name: None,
+            message: "Unknown rule warning".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }];
+
+        let output = formatter.format_warnings(&warnings, "file.md");
+        assert!(output.contains(r#"source="unknown""#));
+    }
+
+    #[test]
+    fn test_severity_mapping() {
+        let formatter = CheckstyleFormatter::new();
+        let warnings = vec![
+            LintWarning {
+                line: 1,
+                column: 1,
+                end_line: 1,
+                end_column: 5,
+                rule_name: Some("MD001"),
+                message: "An error".to_string(),
+                severity: Severity::Error,
+                fix: None,
+            },
+            LintWarning {
+                line: 2,
+                column: 1,
+                end_line: 2,
+                end_column: 5,
+                rule_name: Some("MD002"),
+                message: "Some info".to_string(),
+                severity: Severity::Info,
+                fix: None,
+            },
+        ];
+
+        let output = formatter.format_warnings(&warnings, "test.md");
+        assert!(output.contains(r#"severity="error""#));
+        assert!(output.contains(r#"severity="info""#));
+    }
+
+    #[test]
+    fn test_xml_escape_in_message_and_path() {
+        let formatter = CheckstyleFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 5,
+            rule_name: Some("MD001"),
+            message: "Warning with < > & \" ' special chars".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }];
+
+        let output = formatter.format_warnings(&warnings, "path/with<special>&chars.md");
+
+        assert!(output.contains("path/with&lt;special&gt;&amp;chars.md"));
+        assert!(output.contains("Warning with &lt; &gt; &amp; &quot; &apos; special chars"));
+    }
+
+    #[test]
+    fn test_format_warning_with_fix_has_no_fixable_marker() {
+        // Checkstyle has no concept of fixability; make sure we don't emit one.
+        let formatter = CheckstyleFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 5,
+            rule_name: Some("MD001"),
+            message: "Test".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: 0..5,
+                replacement: "fixed".to_string(),
+            }),
+        }];
+
+        let output = formatter.format_warnings(&warnings, "test.md");
+        assert!(!output.contains("fixable"));
+    }
+
+    #[test]
+    fn test_checkstyle_report_multiple_files() {
+        let all_warnings = vec![
+            (
+                "file1.md".to_string(),
+                vec![LintWarning {
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 5,
+                    rule_name: Some("MD001"),
+                    message: "Warning in file 1".to_string(),
+                    severity: Severity::Warning,
+                    fix: None,
+                }],
+            ),
+            ("file2.md".to_string(), vec![]),
+        ];
+
+        let output = format_checkstyle_report(&all_warnings);
+
+        assert!(output.contains(r#"<file name="file1.md">"#));
+        assert!(output.contains(r#"<file name="file2.md">"#));
+        assert_eq!(output.matches("<error").count(), 1);
+    }
+}
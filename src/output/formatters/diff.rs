@@ -0,0 +1,303 @@
+//! Unified-diff output format (rustfmt's `--emit diff` / `--check` style)
+//!
+//! Renders the fixes a run would apply as a standard unified diff, so the
+//! change can be reviewed or piped straight into `git apply` without rumdl
+//! ever touching the file on disk.
+
+use crate::output::OutputFormatter;
+use crate::rule::LintWarning;
+use colored::*;
+
+/// Unified-diff formatter.
+pub struct DiffFormatter {
+    use_colors: bool,
+    /// Number of unchanged context lines shown around each changed hunk.
+    context_lines: usize,
+}
+
+impl Default for DiffFormatter {
+    fn default() -> Self {
+        Self {
+            use_colors: true,
+            context_lines: 3,
+        }
+    }
+}
+
+impl DiffFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn without_colors() -> Self {
+        Self {
+            use_colors: false,
+            context_lines: 3,
+        }
+    }
+
+    /// Apply every fix in `warnings` to `content`, rightmost edit first so
+    /// earlier byte offsets stay valid.
+    fn apply_fixes(content: &str, warnings: &[LintWarning]) -> String {
+        let mut fixes: Vec<_> = warnings.iter().filter_map(|w| w.fix.as_ref()).collect();
+        fixes.sort_by(|a, b| b.range.start.cmp(&a.range.start));
+
+        let mut fixed = content.to_string();
+        for fix in fixes {
+            if fix.range.start <= fixed.len() && fix.range.end <= fixed.len() {
+                fixed.replace_range(fix.range.clone(), &fix.replacement);
+            }
+        }
+        fixed
+    }
+
+    /// Render a unified diff between `old` and `new`, using a classic
+    /// longest-common-subsequence line match so unchanged lines outside the
+    /// edited hunks are never shown as removed-then-readded.
+    fn unified_diff(&self, file_path: &str, old: &str, new: &str) -> String {
+        let old_lines: Vec<&str> = old.lines().collect();
+        let new_lines: Vec<&str> = new.lines().collect();
+
+        let ops = diff_ops(&old_lines, &new_lines);
+        if ops.iter().all(|op| matches!(op, DiffOp::Equal(_, _))) {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        let header = format!("--- a/{file_path}\n+++ b/{file_path}\n");
+        out.push_str(&if self.use_colors { header.bold().to_string() } else { header });
+
+        for hunk in group_into_hunks(&ops, self.context_lines) {
+            out.push_str(&self.render_hunk(&hunk, &old_lines, &new_lines));
+        }
+
+        out
+    }
+
+    fn render_hunk(&self, hunk: &Hunk, old_lines: &[&str], new_lines: &[&str]) -> String {
+        let header = format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk.old_start + 1,
+            hunk.old_len,
+            hunk.new_start + 1,
+            hunk.new_len
+        );
+        let mut out = if self.use_colors { header.cyan().to_string() } else { header };
+
+        for op in &hunk.ops {
+            match op {
+                DiffOp::Equal(o, n) => {
+                    out.push_str(&format!(" {}\n", old_lines[*o]));
+                    let _ = n;
+                }
+                DiffOp::Delete(o) => {
+                    let line = format!("-{}\n", old_lines[*o]);
+                    out.push_str(&if self.use_colors { line.red().to_string() } else { line });
+                }
+                DiffOp::Insert(n) => {
+                    let line = format!("+{}\n", new_lines[*n]);
+                    out.push_str(&if self.use_colors { line.green().to_string() } else { line });
+                }
+            }
+        }
+        out
+    }
+}
+
+/// A single line-level diff operation, carrying the index into the old
+/// and/or new line vector it refers to.
+#[derive(Debug, Clone, Copy)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// A contiguous run of diff ops plus enough surrounding `Equal` context to
+/// form a standalone unified-diff hunk.
+struct Hunk {
+    old_start: usize,
+    old_len: usize,
+    new_start: usize,
+    new_len: usize,
+    ops: Vec<DiffOp>,
+}
+
+/// Classic dynamic-programming longest-common-subsequence diff. Quadratic in
+/// the line counts (an `O(n*m)` table) for every input, which is fine for the
+/// markdown-sized documents rumdl lints; large files get no cheaper fallback.
+fn diff_ops(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(DiffOp::Delete(i));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(j));
+            j += 1;
+        }
+    }
+    while i < n {
+        ops.push(DiffOp::Delete(i));
+        i += 1;
+    }
+    while j < m {
+        ops.push(DiffOp::Insert(j));
+        j += 1;
+    }
+    ops
+}
+
+/// Split a flat op list into hunks, each keeping up to `context` lines of
+/// unchanged context on either side of its changes, merging hunks whose
+/// context windows overlap.
+fn group_into_hunks(ops: &[DiffOp], context: usize) -> Vec<Hunk> {
+    let mut changed_at: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal(_, _)))
+        .map(|(idx, _)| idx)
+        .collect();
+    if changed_at.is_empty() {
+        return Vec::new();
+    }
+    changed_at.sort_unstable();
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for idx in changed_at {
+        let start = idx.saturating_sub(context);
+        let end = (idx + context + 1).min(ops.len());
+        match ranges.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            let slice = &ops[start..end];
+            let (mut old_start, mut new_start) = (None, None);
+            let (mut old_len, mut new_len) = (0, 0);
+            for op in slice {
+                match op {
+                    DiffOp::Equal(o, n) => {
+                        old_start.get_or_insert(*o);
+                        new_start.get_or_insert(*n);
+                        old_len += 1;
+                        new_len += 1;
+                    }
+                    DiffOp::Delete(o) => {
+                        old_start.get_or_insert(*o);
+                        old_len += 1;
+                    }
+                    DiffOp::Insert(n) => {
+                        new_start.get_or_insert(*n);
+                        new_len += 1;
+                    }
+                }
+            }
+            Hunk {
+                old_start: old_start.unwrap_or(0),
+                old_len,
+                new_start: new_start.unwrap_or(0),
+                new_len,
+                ops: slice.to_vec(),
+            }
+        })
+        .collect()
+}
+
+impl OutputFormatter for DiffFormatter {
+    fn format_warnings(&self, _warnings: &[LintWarning], _file_path: &str) -> String {
+        // A diff needs the original source; without it there is nothing to render.
+        String::new()
+    }
+
+    fn format_warnings_with_content(&self, warnings: &[LintWarning], file_path: &str, content: &str) -> String {
+        if content.is_empty() || warnings.is_empty() {
+            return String::new();
+        }
+        let fixed = Self::apply_fixes(content, warnings);
+        self.unified_diff(file_path, content, &fixed)
+    }
+
+    fn use_colors(&self) -> bool {
+        self.use_colors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{Fix, Severity};
+
+    fn warning(line: usize, fix: Fix) -> LintWarning {
+        LintWarning {
+            rule_name: Some("MD009"),
+            message: "Trailing spaces".to_string(),
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 1,
+            severity: Severity::Warning,
+            fix: Some(fix),
+        }
+    }
+
+    #[test]
+    fn test_no_content_produces_no_diff() {
+        let formatter = DiffFormatter::without_colors();
+        let warnings = vec![warning(1, Fix { range: 0..1, replacement: String::new() })];
+        assert_eq!(formatter.format_warnings(&warnings, "a.md"), "");
+    }
+
+    #[test]
+    fn test_single_line_fix_renders_unified_diff() {
+        let formatter = DiffFormatter::without_colors();
+        let content = "line one   \nline two\n";
+        let warnings = vec![warning(1, Fix { range: 8..11, replacement: String::new() })];
+
+        let diff = formatter.format_warnings_with_content(&warnings, "a.md", content);
+
+        assert!(diff.contains("--- a/a.md"));
+        assert!(diff.contains("+++ b/a.md"));
+        assert!(diff.contains("-line one   "));
+        assert!(diff.contains("+line one"));
+        assert!(diff.contains(" line two"));
+    }
+
+    #[test]
+    fn test_no_fixes_produces_no_diff() {
+        let formatter = DiffFormatter::without_colors();
+        let content = "line one\n";
+        let warnings = vec![LintWarning {
+            rule_name: Some("MD001"),
+            message: "no fix".to_string(),
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 1,
+            severity: Severity::Warning,
+            fix: None,
+        }];
+        assert_eq!(formatter.format_warnings_with_content(&warnings, "a.md", content), "");
+    }
+}
@@ -0,0 +1,60 @@
+//! Machine-readable fix export formatter
+//!
+//! Emits, per file, a JSON array of the fixes rumdl would apply — without
+//! touching any file — so editors, review bots, and CI can inspect, filter,
+//! or selectively apply them via `rumdl apply`.
+
+use crate::fix_coordinator::FixExport;
+use crate::output::OutputFormatter;
+use crate::rule::LintWarning;
+use crate::rules::rule_applicability;
+
+/// Formatter for `--output-format=fixes-json`.
+#[derive(Default)]
+pub struct FixesJsonFormatter;
+
+impl FixesJsonFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for FixesJsonFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], _file_path: &str) -> String {
+        serde_json::to_string_pretty(&exports_for(warnings)).unwrap_or_default()
+    }
+}
+
+/// Build the fix exports for a single file's warnings.
+fn exports_for(warnings: &[LintWarning]) -> Vec<FixExport> {
+    warnings
+        .iter()
+        .filter_map(|warning| {
+            let fix = warning.fix.as_ref()?;
+            let rule = warning.rule_name.unwrap_or("unknown");
+            Some(FixExport {
+                rule: rule.to_string(),
+                message: warning.message.clone(),
+                byte_range: [fix.range.start, fix.range.end],
+                replacement: fix.replacement.clone(),
+                applicability: rule_applicability(rule),
+            })
+        })
+        .collect()
+}
+
+/// Emit a single JSON document mapping each file path to its array of fix
+/// exports, suitable for `rumdl apply`.
+pub fn format_all_fixes_as_json(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
+    // Preserve input order with an insertion-ordered map of file -> exports.
+    let map: serde_json::Map<String, serde_json::Value> = all_warnings
+        .iter()
+        .map(|(file, warnings)| {
+            (
+                file.clone(),
+                serde_json::to_value(exports_for(warnings)).unwrap_or_default(),
+            )
+        })
+        .collect();
+    serde_json::to_string_pretty(&serde_json::Value::Object(map)).unwrap_or_default()
+}
@@ -1,9 +1,33 @@
 //! JSON output formatter
 
 use crate::output::OutputFormatter;
-use crate::rule::LintWarning;
+use crate::rule::{Fix, LintWarning};
 use serde_json::{Value, json};
 
+/// Build the rustfix-compatible `suggestions` array for a warning.
+///
+/// Each suggestion mirrors the rustc/rustfix contract: one or more
+/// `replacements` carrying a byte-offset span, the line/column of the
+/// diagnostic, the replacement text, and an `applicability` marker. rumdl
+/// fixes are computed as exact span edits, so they are machine-applicable
+/// by default.
+fn suggestions_for(warning: &LintWarning) -> Option<Value> {
+    warning.fix.as_ref().map(|fix: &Fix| {
+        json!([{
+            "applicability": "machine-applicable",
+            "replacements": [{
+                "span": {
+                    "start": fix.range.start,
+                    "end": fix.range.end
+                },
+                "line": warning.line,
+                "column": warning.column,
+                "replacement": fix.replacement
+            }]
+        }])
+    })
+}
+
 /// JSON formatter for machine-readable output
 #[derive(Default)]
 pub struct JsonFormatter {
@@ -48,7 +72,8 @@ impl OutputFormatter for JsonFormatter {
                             },
                             "replacement": f.replacement
                         })
-                    })
+                    }),
+                    "suggestions": suggestions_for(warning)
                 })
             })
             .collect();
@@ -79,7 +104,8 @@ pub fn format_all_warnings_as_json(all_warnings: &[(String, Vec<LintWarning>)])
                         },
                         "replacement": f.replacement
                     })
-                })
+                }),
+                "suggestions": suggestions_for(warning)
             }));
         }
     }
@@ -372,6 +398,55 @@ mod tests {
         assert!(output.contains("  "));
     }
 
+    #[test]
+    fn test_format_warning_with_suggestions() {
+        let formatter = JsonFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 15,
+            column: 1,
+            end_line: 15,
+            end_column: 10,
+            rule_name: Some("MD013"),
+            message: "Line too long".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: 100..110,
+                replacement: "wrapped".to_string(),
+            }),
+        }];
+
+        let output = formatter.format_warnings(&warnings, "doc.md");
+        let parsed: Vec<Value> = serde_json::from_str(&output).unwrap();
+
+        let suggestions = &parsed[0]["suggestions"];
+        assert_eq!(suggestions[0]["applicability"], "machine-applicable");
+        let replacement = &suggestions[0]["replacements"][0];
+        assert_eq!(replacement["span"]["start"], 100);
+        assert_eq!(replacement["span"]["end"], 110);
+        assert_eq!(replacement["line"], 15);
+        assert_eq!(replacement["column"], 1);
+        assert_eq!(replacement["replacement"], "wrapped");
+    }
+
+    #[test]
+    fn test_suggestions_absent_without_fix() {
+        let formatter = JsonFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 5,
+            rule_name: Some("MD001"),
+            message: "No fix".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }];
+
+        let output = formatter.format_warnings(&warnings, "test.md");
+        let parsed: Vec<Value> = serde_json::from_str(&output).unwrap();
+        assert!(parsed[0]["suggestions"].is_null());
+    }
+
     #[test]
     fn test_edge_cases() {
         let formatter = JsonFormatter::new();
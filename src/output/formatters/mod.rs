@@ -1,7 +1,11 @@
 //! Output formatter implementations
 
+pub mod annotated;
 pub mod azure;
+pub mod checkstyle;
 pub mod concise;
+pub mod diff;
+pub mod fixes_json;
 pub mod github;
 pub mod gitlab;
 pub mod grouped;
@@ -12,8 +16,12 @@ pub mod pylint;
 pub mod sarif;
 pub mod text;
 
+pub use annotated::AnnotatedFormatter;
 pub use azure::AzureFormatter;
+pub use checkstyle::CheckstyleFormatter;
 pub use concise::ConciseFormatter;
+pub use diff::DiffFormatter;
+pub use fixes_json::FixesJsonFormatter;
 pub use github::GitHubFormatter;
 pub use gitlab::GitLabFormatter;
 pub use grouped::GroupedFormatter;
@@ -8,15 +8,23 @@ use std::io::{self, Write};
 use std::str::FromStr;
 
 pub mod formatters;
+pub mod streaming;
 
 // Re-export formatters
 pub use formatters::*;
+pub use streaming::{JsonFormatter as StreamingJsonFormatter, LintFormatter, PrettyFormatter, TerseFormatter};
 
 /// Trait for output formatters
 pub trait OutputFormatter {
     /// Format a collection of warnings for output
     fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String;
 
+    /// Format warnings with access to the source content, for renderers that
+    /// draw source excerpts. Defaults to the content-free [`format_warnings`].
+    fn format_warnings_with_content(&self, warnings: &[LintWarning], file_path: &str, _content: &str) -> String {
+        self.format_warnings(warnings, file_path)
+    }
+
     /// Format a summary of results across multiple files
     fn format_summary(&self, _files_processed: usize, _total_warnings: usize, _duration_ms: u64) -> Option<String> {
         // Default: no summary
@@ -54,6 +62,14 @@ pub enum OutputFormat {
     Sarif,
     /// JUnit XML format
     Junit,
+    /// Checkstyle XML format
+    Checkstyle,
+    /// Machine-readable fix suggestions (one JSON array per file)
+    FixesJson,
+    /// Annotated source snippets with underlined spans (rustc style)
+    Annotated,
+    /// Unified diff of the fixes this run would apply (rustfmt `--emit diff` style)
+    Diff,
 }
 
 impl FromStr for OutputFormat {
@@ -72,6 +88,10 @@ impl FromStr for OutputFormat {
             "azure" => Ok(OutputFormat::Azure),
             "sarif" => Ok(OutputFormat::Sarif),
             "junit" => Ok(OutputFormat::Junit),
+            "checkstyle" => Ok(OutputFormat::Checkstyle),
+            "fixes-json" | "fixesjson" => Ok(OutputFormat::FixesJson),
+            "annotated" | "annotate" | "snippet" => Ok(OutputFormat::Annotated),
+            "diff" => Ok(OutputFormat::Diff),
             _ => Err(format!("Unknown output format: {s}")),
         }
     }
@@ -92,6 +112,10 @@ impl OutputFormat {
             OutputFormat::Azure => Box::new(AzureFormatter::new()),
             OutputFormat::Sarif => Box::new(SarifFormatter::new()),
             OutputFormat::Junit => Box::new(JunitFormatter::new()),
+            OutputFormat::Checkstyle => Box::new(CheckstyleFormatter::new()),
+            OutputFormat::FixesJson => Box::new(FixesJsonFormatter::new()),
+            OutputFormat::Annotated => Box::new(AnnotatedFormatter::new()),
+            OutputFormat::Diff => Box::new(DiffFormatter::new()),
         }
     }
 }
@@ -203,6 +227,7 @@ mod tests {
         assert_eq!(OutputFormat::from_str("azure").unwrap(), OutputFormat::Azure);
         assert_eq!(OutputFormat::from_str("sarif").unwrap(), OutputFormat::Sarif);
         assert_eq!(OutputFormat::from_str("junit").unwrap(), OutputFormat::Junit);
+        assert_eq!(OutputFormat::from_str("checkstyle").unwrap(), OutputFormat::Checkstyle);
 
         // Case insensitive
         assert_eq!(OutputFormat::from_str("TEXT").unwrap(), OutputFormat::Text);
@@ -230,6 +255,7 @@ mod tests {
             OutputFormat::Azure,
             OutputFormat::Sarif,
             OutputFormat::Junit,
+            OutputFormat::Checkstyle,
         ];
 
         for format in &formats {
@@ -414,6 +440,7 @@ mod tests {
             OutputFormat::Azure,
             OutputFormat::Sarif,
             OutputFormat::Junit,
+            OutputFormat::Checkstyle,
         ];
 
         for format in &formats {
@@ -0,0 +1,223 @@
+//! Streaming diagnostic emitters
+//!
+//! The [`OutputFormatter`](crate::output::OutputFormatter) trait buffers the
+//! full warning set into a `String` before printing. For very large documents
+//! that is wasteful — a consumer streaming NDJSON wants each record as soon as
+//! it is produced. This module mirrors libtest's `json`/`pretty`/`terse`
+//! formatter split with a streaming [`LintFormatter`] trait whose
+//! `start`/`emit`/`finish` methods write directly to an underlying writer.
+
+use crate::rule::{LintWarning, Severity};
+use std::io::{self, Write};
+
+/// A streaming sink for lint warnings.
+///
+/// `start` is called once before any warnings for a file, `emit` once per
+/// warning, and `finish` once at the end to flush any trailing output.
+pub trait LintFormatter {
+    /// Begin output for `file`.
+    fn start(&mut self, file: &str) -> io::Result<()>;
+    /// Emit a single warning.
+    fn emit(&mut self, warning: &LintWarning) -> io::Result<()>;
+    /// Finish output, flushing the writer.
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+fn severity_str(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+/// One line per issue: `file:line:col: [RULE] message`.
+pub struct TerseFormatter<W: Write> {
+    writer: W,
+    file: String,
+}
+
+impl<W: Write> TerseFormatter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            file: String::new(),
+        }
+    }
+}
+
+impl<W: Write> LintFormatter for TerseFormatter<W> {
+    fn start(&mut self, file: &str) -> io::Result<()> {
+        self.file = file.to_string();
+        Ok(())
+    }
+
+    fn emit(&mut self, warning: &LintWarning) -> io::Result<()> {
+        let rule = warning.rule_name.unwrap_or("unknown");
+        writeln!(
+            self.writer,
+            "{}:{}:{}: [{}] {}",
+            self.file, warning.line, warning.column, rule, warning.message
+        )
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Human-readable output with a severity label and fix hint.
+pub struct PrettyFormatter<W: Write> {
+    writer: W,
+    file: String,
+    header_written: bool,
+}
+
+impl<W: Write> PrettyFormatter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            file: String::new(),
+            header_written: false,
+        }
+    }
+}
+
+impl<W: Write> LintFormatter for PrettyFormatter<W> {
+    fn start(&mut self, file: &str) -> io::Result<()> {
+        self.file = file.to_string();
+        self.header_written = false;
+        Ok(())
+    }
+
+    fn emit(&mut self, warning: &LintWarning) -> io::Result<()> {
+        if !self.header_written {
+            writeln!(self.writer, "{}:", self.file)?;
+            self.header_written = true;
+        }
+        let rule = warning.rule_name.unwrap_or("unknown");
+        let fixable = if warning.fix.is_some() { " [fixable]" } else { "" };
+        writeln!(
+            self.writer,
+            "  {}:{} {}: {} ({}){}",
+            warning.line,
+            warning.column,
+            severity_str(warning.severity),
+            warning.message,
+            rule,
+            fixable
+        )
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Machine-readable NDJSON: one JSON object per line so consumers can
+/// stream-parse without loading the whole array.
+pub struct JsonFormatter<W: Write> {
+    writer: W,
+    file: String,
+}
+
+impl<W: Write> JsonFormatter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            file: String::new(),
+        }
+    }
+}
+
+impl<W: Write> LintFormatter for JsonFormatter<W> {
+    fn start(&mut self, file: &str) -> io::Result<()> {
+        self.file = file.to_string();
+        Ok(())
+    }
+
+    fn emit(&mut self, warning: &LintWarning) -> io::Result<()> {
+        let record = serde_json::json!({
+            "file": self.file,
+            "rule": warning.rule_name,
+            "line": warning.line,
+            "column": warning.column,
+            "severity": severity_str(warning.severity),
+            "message": warning.message,
+            "fixable": warning.fix.is_some(),
+        });
+        writeln!(self.writer, "{record}")
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{Fix, Severity};
+
+    fn warning(fixable: bool) -> LintWarning {
+        LintWarning {
+            line: 3,
+            column: 7,
+            end_line: 3,
+            end_column: 9,
+            message: "trailing space".to_string(),
+            severity: Severity::Warning,
+            rule_name: Some("MD009"),
+            fix: fixable.then(|| Fix {
+                range: 0..1,
+                replacement: String::new(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_terse_one_line_per_issue() {
+        let mut buf = Vec::new();
+        {
+            let mut f = TerseFormatter::new(&mut buf);
+            f.start("a.md").unwrap();
+            f.emit(&warning(false)).unwrap();
+            f.finish().unwrap();
+        }
+        assert_eq!(String::from_utf8(buf).unwrap(), "a.md:3:7: [MD009] trailing space\n");
+    }
+
+    #[test]
+    fn test_json_is_ndjson() {
+        let mut buf = Vec::new();
+        {
+            let mut f = JsonFormatter::new(&mut buf);
+            f.start("a.md").unwrap();
+            f.emit(&warning(true)).unwrap();
+            f.emit(&warning(false)).unwrap();
+            f.finish().unwrap();
+        }
+        let out = String::from_utf8(buf).unwrap();
+        // One JSON object per line.
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["rule"], "MD009");
+            assert_eq!(value["line"], 3);
+        }
+    }
+
+    #[test]
+    fn test_pretty_includes_fix_hint() {
+        let mut buf = Vec::new();
+        {
+            let mut f = PrettyFormatter::new(&mut buf);
+            f.start("a.md").unwrap();
+            f.emit(&warning(true)).unwrap();
+            f.finish().unwrap();
+        }
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.contains("a.md:"));
+        assert!(out.contains("[fixable]"));
+    }
+}
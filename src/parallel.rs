@@ -2,7 +2,8 @@
 ///
 /// This module implements file-level parallel execution of markdown linting
 /// to improve performance when processing multiple files.
-use crate::rule::{LintResult, Rule};
+use crate::lint_context::LintContext;
+use crate::rule::{LintResult, LintWarning, Rule};
 use rayon::prelude::*;
 use std::time::Instant;
 
@@ -107,6 +108,62 @@ impl FileParallelProcessor {
     }
 }
 
+/// Sort warnings into a deterministic order so parallel and sequential runs
+/// produce byte-for-byte identical output: by line, then column, then rule.
+fn sort_warnings(warnings: &mut [LintWarning]) {
+    warnings.sort_by(|a, b| {
+        a.line
+            .cmp(&b.line)
+            .then(a.column.cmp(&b.column))
+            .then_with(|| a.rule_name.unwrap_or("").cmp(b.rule_name.unwrap_or("")))
+    });
+}
+
+/// Run the merge of every rule's warnings over a shared, immutable
+/// `LintContext`.
+fn run_rules_parallel(ctx: &LintContext, rules: &[Box<dyn Rule>]) -> LintResult {
+    let per_rule: Vec<LintResult> = rules.par_iter().map(|rule| rule.check(ctx)).collect();
+    let mut warnings = Vec::new();
+    for result in per_rule {
+        warnings.extend(result?);
+    }
+    sort_warnings(&mut warnings);
+    Ok(warnings)
+}
+
+fn run_rules_sequential(ctx: &LintContext, rules: &[Box<dyn Rule>]) -> LintResult {
+    let mut warnings = Vec::new();
+    for rule in rules {
+        warnings.extend(rule.check(ctx)?);
+    }
+    sort_warnings(&mut warnings);
+    Ok(warnings)
+}
+
+/// Run every rule's `check` against a single shared `LintContext`, fanning the
+/// independent checks out across a rayon thread pool.
+///
+/// `LintContext` is immutable during checking, so it can safely be shared by
+/// reference across threads. Results are merged in a deterministic order (line,
+/// column, rule name) so the output is stable regardless of thread scheduling.
+///
+/// `threads` selects the pool size: `Some(1)` forces the reproducible
+/// single-threaded path, `Some(n)` builds an `n`-thread pool, and `None`
+/// defaults to the number of available cores via the global pool.
+pub fn lint_parallel(ctx: &LintContext, rules: &[Box<dyn Rule>], threads: Option<usize>) -> LintResult {
+    match threads {
+        Some(1) => run_rules_sequential(ctx, rules),
+        Some(n) => match rayon::ThreadPoolBuilder::new().num_threads(n).build() {
+            Ok(pool) => pool.install(|| run_rules_parallel(ctx, rules)),
+            Err(e) => {
+                log::warn!("Failed to build {n}-thread pool ({e}); using the global pool");
+                run_rules_parallel(ctx, rules)
+            }
+        },
+        None => run_rules_parallel(ctx, rules),
+    }
+}
+
 /// Performance comparison utilities
 pub struct ParallelPerformanceComparison {
     pub sequential_time: std::time::Duration,
@@ -189,6 +246,29 @@ mod tests {
         assert!(processor.should_use_parallel(&multiple_files));
     }
 
+    #[test]
+    fn test_lint_parallel_matches_sequential() {
+        let config = Config::default();
+        let rules = all_rules(&config);
+        let content = "##  Heading with trailing spaces   \n#### Skipped level\n\n\n- item\n";
+        let ctx = LintContext::new(content);
+
+        let parallel = lint_parallel(&ctx, &rules, None).unwrap();
+        let sequential = lint_parallel(&ctx, &rules, Some(1)).unwrap();
+
+        let key = |w: &[LintWarning]| {
+            w.iter()
+                .map(|x| (x.line, x.column, x.rule_name))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(key(&parallel), key(&sequential));
+
+        // Output is non-decreasing by (line, column).
+        for pair in parallel.windows(2) {
+            assert!((pair[0].line, pair[0].column) <= (pair[1].line, pair[1].column));
+        }
+    }
+
     #[test]
     fn test_file_parallel_processing() {
         let config = Config::default();
@@ -0,0 +1,167 @@
+//! Named style presets: curated baselines a project can opt into with a single
+//! `style = "..."` config key (or `--style` on the CLI) instead of re-deriving
+//! the same rule enable/disable and parameter choices from scratch.
+//!
+//! A preset is just a [`SourcedConfigFragment`], tagged [`ConfigSource::Preset`]
+//! so it merges in *beneath* every real config source: [`SourcedConfig::merge`]
+//! only lets a fragment override a field whose current source has equal or
+//! lower precedence, and `Preset` sits just above `Default` in
+//! [`ConfigSource`]'s ranking. See [`SourcedConfig::apply_style`] for where this
+//! gets wired in.
+
+use crate::config::{ConfigError, ConfigSource, SourcedConfigFragment, SourcedRuleConfig, SourcedValue};
+
+/// Resolves a `style` value to a config fragment: first as a built-in preset
+/// name (case-insensitive), then as a path to a preset config file.
+pub fn resolve_preset(name_or_path: &str) -> Result<SourcedConfigFragment, ConfigError> {
+    if let Some(fragment) = builtin_preset(name_or_path) {
+        return Ok(fragment);
+    }
+
+    load_preset_file(name_or_path)
+}
+
+/// Returns the built-in preset with this name, if any.
+fn builtin_preset(name: &str) -> Option<SourcedConfigFragment> {
+    match name.to_ascii_lowercase().as_str() {
+        "relaxed" => Some(relaxed()),
+        "strict" => Some(strict()),
+        _ => None,
+    }
+}
+
+/// Loads a preset from an arbitrary `.rumdl.toml`-style file, re-tagging every
+/// value it sets as [`ConfigSource::Preset`] so it keeps preset (not
+/// `RumdlToml`) precedence once merged into the caller's config.
+fn load_preset_file(path: &str) -> Result<SourcedConfigFragment, ConfigError> {
+    if !std::path::Path::new(path).exists() {
+        return Err(ConfigError::ParseError(format!(
+            "Unknown style preset '{path}': not a built-in preset (try \"relaxed\" or \"strict\") \
+             and no such file exists"
+        )));
+    }
+
+    let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
+        source: e,
+        path: path.to_string(),
+    })?;
+    let fragment = crate::config::parse_rumdl_toml(&content, path)?;
+    Ok(rebrand(fragment, ConfigSource::Preset))
+}
+
+/// Re-tags every value in `fragment` with `source`, discarding the override
+/// history a freshly parsed fragment carries (a preset file's own provenance
+/// isn't meaningful once it's folded into another project's config).
+fn rebrand(fragment: SourcedConfigFragment, source: ConfigSource) -> SourcedConfigFragment {
+    let mut out = SourcedConfigFragment::default();
+
+    out.global.enable = SourcedValue::new(fragment.global.enable.value, source);
+    out.global.disable = SourcedValue::new(fragment.global.disable.value, source);
+    out.global.exclude = SourcedValue::new(fragment.global.exclude.value, source);
+    out.global.include = SourcedValue::new(fragment.global.include.value, source);
+    out.global.respect_gitignore = SourcedValue::new(fragment.global.respect_gitignore.value, source);
+    out.global.line_length = SourcedValue::new(fragment.global.line_length.value, source);
+    out.global.output_format = fragment.global.output_format.map(|v| SourcedValue::new(v.value, source));
+    out.global.fixable = SourcedValue::new(fragment.global.fixable.value, source);
+    out.global.unfixable = SourcedValue::new(fragment.global.unfixable.value, source);
+    out.global.unsafe_fixes = SourcedValue::new(fragment.global.unsafe_fixes.value, source);
+    out.global.style = fragment.global.style.map(|v| SourcedValue::new(v.value, source));
+
+    for (rule_name, rule_cfg) in fragment.rules {
+        let mut values = std::collections::BTreeMap::new();
+        for (key, sv) in rule_cfg.values {
+            values.insert(key, SourcedValue::new(sv.value, source));
+        }
+        out.rules.insert(rule_name, SourcedRuleConfig { values });
+    }
+
+    out
+}
+
+/// mdl-style "relaxed" preset: disables the two checks that fire most often on
+/// prose that wasn't written with a linter in mind (long lines, inline HTML)
+/// and loosens nested-list indentation to the common 4-space convention.
+fn relaxed() -> SourcedConfigFragment {
+    let mut fragment = SourcedConfigFragment::default();
+    let source = ConfigSource::Preset;
+
+    fragment.global.disable = SourcedValue::new(vec!["MD013".to_string(), "MD033".to_string()], source);
+
+    let mut md007_values = std::collections::BTreeMap::new();
+    md007_values.insert("indent".to_string(), SourcedValue::new(toml::Value::Integer(4), source));
+    fragment
+        .rules
+        .insert("MD007".to_string(), SourcedRuleConfig { values: md007_values });
+
+    fragment
+}
+
+/// "strict" preset: keeps every rule enabled and pins the two checks a
+/// relaxed project is most likely to have loosened back to their tightest
+/// common values.
+fn strict() -> SourcedConfigFragment {
+    let mut fragment = SourcedConfigFragment::default();
+    let source = ConfigSource::Preset;
+
+    let mut md007_values = std::collections::BTreeMap::new();
+    md007_values.insert("indent".to_string(), SourcedValue::new(toml::Value::Integer(2), source));
+    fragment
+        .rules
+        .insert("MD007".to_string(), SourcedRuleConfig { values: md007_values });
+
+    let mut md013_values = std::collections::BTreeMap::new();
+    md013_values.insert(
+        "line-length".to_string(),
+        SourcedValue::new(toml::Value::Integer(80), source),
+    );
+    fragment
+        .rules
+        .insert("MD013".to_string(), SourcedRuleConfig { values: md013_values });
+
+    fragment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, SourcedConfig};
+
+    #[test]
+    fn test_relaxed_preset_disables_md013_and_md033() {
+        let fragment = builtin_preset("relaxed").unwrap();
+        assert_eq!(fragment.global.disable.value, vec!["MD013", "MD033"]);
+        assert_eq!(
+            fragment.rules["MD007"].values["indent"].value,
+            toml::Value::Integer(4)
+        );
+    }
+
+    #[test]
+    fn test_unknown_preset_name_that_is_not_a_file_errors() {
+        let result = resolve_preset("not-a-real-preset-or-file");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_preset_is_overridden_by_user_config() {
+        // The user's own `disable` (higher precedence than Preset) must survive
+        // the preset merge, even though the preset also sets `disable`.
+        let mut sourced = SourcedConfig::default();
+        sourced
+            .global
+            .disable
+            .merge_override(vec!["MD001".to_string()], ConfigSource::RumdlToml, None, None);
+
+        sourced.apply_style(Some("relaxed")).unwrap();
+
+        assert_eq!(sourced.global.disable.value, vec!["MD001".to_string()]);
+        // But a field the user never touched (MD007 indent) is filled in by the preset.
+        assert_eq!(
+            sourced.rules["MD007"].values["indent"].value,
+            toml::Value::Integer(4)
+        );
+
+        let config: Config = sourced.into();
+        assert_eq!(config.global.disable, vec!["MD001".to_string()]);
+    }
+}
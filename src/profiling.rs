@@ -1,6 +1,7 @@
 //!
 //! This module provides profiling utilities for measuring and reporting execution times in rumdl.
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 use std::sync::Mutex;
@@ -151,6 +152,182 @@ pub fn reset() {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Hierarchical span profiler
+// ---------------------------------------------------------------------------
+//
+// The flat `Profiler` above aggregates every timer under a single bucket, which
+// loses the call structure: it cannot show that most of a rule's time was spent
+// inside `DocumentStructure::new` versus the rule's own logic. The span
+// profiler records a tree instead. Each `span()` pushes `(label, start)` onto a
+// thread-local stack; on drop it records the elapsed time against its full
+// call path. Reporting aggregates spans by path, computes total and self-time
+// per node, and renders an indented tree with percentage-of-parent.
+
+thread_local! {
+    /// Stack of active spans for the current thread. Each frame carries the
+    /// full call path (ancestor labels plus its own) so recorded durations can
+    /// be aggregated into a tree regardless of which thread produced them.
+    static SPAN_STACK: RefCell<Vec<(Vec<String>, Instant)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Aggregated timing for a single call path.
+#[derive(Debug, Default, Clone)]
+struct SpanAgg {
+    total: Duration,
+    calls: usize,
+}
+
+static SPANS: LazyLock<Mutex<HashMap<Vec<String>, SpanAgg>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// RAII guard for a hierarchical profiling span.
+///
+/// Dropping the guard records the elapsed time against the span's call path.
+pub struct Span {
+    path: Vec<String>,
+    start: Instant,
+    enabled: bool,
+}
+
+impl Span {
+    fn record(path: Vec<String>, elapsed: Duration) {
+        if let Ok(mut spans) = SPANS.lock() {
+            let entry = spans.entry(path).or_default();
+            entry.total += elapsed;
+            entry.calls += 1;
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        let elapsed = self.start.elapsed();
+        SPAN_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+        Span::record(std::mem::take(&mut self.path), elapsed);
+    }
+}
+
+/// Open a hierarchical profiling span nested under any currently-open span.
+///
+/// Returns an RAII guard; the span closes when the guard is dropped. When
+/// profiling is disabled this is a cheap no-op.
+pub fn span(label: &str) -> Span {
+    if !PROFILING_ENABLED {
+        return Span {
+            path: Vec::new(),
+            start: Instant::now(),
+            enabled: false,
+        };
+    }
+    let path = SPAN_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let mut path = stack.last().map(|(p, _)| p.clone()).unwrap_or_default();
+        path.push(label.to_string());
+        stack.push((path.clone(), Instant::now()));
+        path
+    });
+    Span {
+        path,
+        start: Instant::now(),
+        enabled: true,
+    }
+}
+
+/// Render the recorded spans as an indented tree with absolute millis and
+/// percentage-of-parent, collapsing any node contributing less than
+/// `threshold_pct` of its parent into a single `(other)` line.
+pub fn get_span_report(threshold_pct: f64) -> String {
+    if !PROFILING_ENABLED {
+        return "Profiling is disabled.".to_string();
+    }
+    let spans = match SPANS.lock() {
+        Ok(spans) => spans.clone(),
+        Err(_) => return "Span report unavailable (mutex poisoned).".to_string(),
+    };
+    if spans.is_empty() {
+        return "No span measurements recorded.".to_string();
+    }
+
+    // Roots are single-element paths.
+    let mut report = String::from("=== Span Profiling Report ===\n");
+    let mut roots: Vec<&Vec<String>> = spans.keys().filter(|p| p.len() == 1).collect();
+    roots.sort_by(|a, b| spans[*b].total.cmp(&spans[*a].total));
+
+    let root_total: Duration = roots.iter().map(|p| spans[*p].total).sum();
+    for root in roots {
+        render_node(&mut report, &spans, root, root_total, 0, threshold_pct);
+    }
+    report
+}
+
+/// Recursively render one span node and its children.
+fn render_node(
+    report: &mut String,
+    spans: &HashMap<Vec<String>, SpanAgg>,
+    path: &[String],
+    parent_total: Duration,
+    depth: usize,
+    threshold_pct: f64,
+) {
+    let agg = &spans[path];
+    let label = path.last().map(String::as_str).unwrap_or("");
+    let pct = percentage(agg.total, parent_total);
+    let indent = "  ".repeat(depth);
+    report.push_str(&format!(
+        "{indent}{label}: {:.3} ms ({pct:.1}% of parent, {} calls)\n",
+        agg.total.as_secs_f64() * 1000.0,
+        agg.calls,
+    ));
+
+    // Collect direct children (paths one element longer with this prefix).
+    let mut children: Vec<&Vec<String>> = spans
+        .keys()
+        .filter(|p| p.len() == path.len() + 1 && p.starts_with(path))
+        .collect();
+    children.sort_by(|a, b| spans[*b].total.cmp(&spans[*a].total));
+
+    let mut collapsed = Duration::default();
+    let mut collapsed_calls = 0usize;
+    for child in &children {
+        let child_agg = &spans[*child];
+        if percentage(child_agg.total, agg.total) < threshold_pct {
+            collapsed += child_agg.total;
+            collapsed_calls += child_agg.calls;
+            continue;
+        }
+        render_node(report, spans, child, agg.total, depth + 1, threshold_pct);
+    }
+    if collapsed > Duration::default() {
+        let indent = "  ".repeat(depth + 1);
+        report.push_str(&format!(
+            "{indent}(other): {:.3} ms ({:.1}% of parent, {collapsed_calls} calls)\n",
+            collapsed.as_secs_f64() * 1000.0,
+            percentage(collapsed, agg.total),
+        ));
+    }
+}
+
+fn percentage(part: Duration, whole: Duration) -> f64 {
+    if whole.as_secs_f64() == 0.0 {
+        0.0
+    } else {
+        (part.as_secs_f64() / whole.as_secs_f64()) * 100.0
+    }
+}
+
+/// Reset all recorded spans.
+pub fn reset_spans() {
+    if let Ok(mut spans) = SPANS.lock() {
+        spans.clear();
+    }
+    SPAN_STACK.with(|stack| stack.borrow_mut().clear());
+}
+
 /// A utility struct to time a section of code using RAII
 pub struct ScopedTimer {
     section: String,
@@ -403,6 +580,58 @@ mod tests {
         }
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_span_report_tree() {
+        if PROFILING_ENABLED {
+            reset_spans();
+
+            {
+                let _root = span("root");
+                {
+                    let _child = span("child");
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+
+            let report = get_span_report(1.0);
+            assert!(report.contains("Span Profiling Report"));
+            assert!(report.contains("root"));
+            assert!(report.contains("child"));
+            // Child is listed after its parent.
+            let root_pos = report.find("root").unwrap();
+            let child_pos = report.find("child").unwrap();
+            assert!(root_pos < child_pos);
+        } else {
+            assert_eq!(get_span_report(1.0), "Profiling is disabled.");
+        }
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_span_report_collapses_below_threshold() {
+        if PROFILING_ENABLED {
+            reset_spans();
+
+            {
+                let _root = span("root");
+                {
+                    let _hot = span("hot");
+                    thread::sleep(Duration::from_millis(20));
+                }
+                {
+                    let _cold = span("cold");
+                    thread::sleep(Duration::from_millis(1));
+                }
+            }
+
+            // With a high threshold the cold child folds into "(other)".
+            let report = get_span_report(50.0);
+            assert!(report.contains("hot"));
+            assert!(report.contains("(other)"));
+        }
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_concurrent_access() {
@@ -3,7 +3,7 @@
 //! Includes rule categories, dynamic dispatch helpers, and inline comment handling for rule enable/disable.
 
 use dyn_clone::DynClone;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::ops::Range;
 use thiserror::Error;
 
@@ -58,7 +58,46 @@ pub struct Fix {
     pub replacement: String,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+impl Fix {
+    /// Convert this fix's byte-offset `range` into an LSP `TextEdit`, whose
+    /// `Position`s are 0-indexed lines paired with UTF-16 code-unit columns
+    /// (the unit the Language Server Protocol requires regardless of the
+    /// server's own internal encoding).
+    pub fn to_lsp_text_edit(&self, ctx: &LintContext) -> LspTextEdit {
+        LspTextEdit {
+            range: LspRange {
+                start: ctx.offset_to_lsp_position(self.range.start),
+                end: ctx.offset_to_lsp_position(self.range.end),
+            },
+            new_text: self.replacement.clone(),
+        }
+    }
+}
+
+/// A zero-indexed `(line, character)` position, using UTF-16 code units for
+/// `character` per the LSP spec's `Position` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LspPosition {
+    pub line: u32,
+    pub character: u32,
+}
+
+/// An LSP `Range`: a half-open span between two [`LspPosition`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LspRange {
+    pub start: LspPosition,
+    pub end: LspPosition,
+}
+
+/// An LSP `TextEdit`: replace `range` with `new_text`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LspTextEdit {
+    pub range: LspRange,
+    #[serde(rename = "newText")]
+    pub new_text: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum Severity {
     Error,
     Warning,
@@ -81,6 +120,57 @@ pub enum RuleCategory {
     Other,
 }
 
+/// A single document characteristic a rule may depend on.
+///
+/// These mirror the fields of the linter's content pre-scan; a rule that needs
+/// none of the relevant markup present in a document can be skipped entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentFeature {
+    Headings,
+    Lists,
+    Links,
+    Images,
+    Code,
+    Emphasis,
+    Html,
+    Tables,
+    Blockquotes,
+}
+
+/// Declares which content characteristics a rule requires in order to produce
+/// any warnings, so `lint()` can skip rules whose prerequisites are absent.
+///
+/// This replaces the coarse `RuleCategory` → single-boolean mapping: a rule can
+/// require *all* of several features (e.g. tables *and* HTML), *any* of a set,
+/// or declare it must *always* run regardless of content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentRequirement {
+    /// The rule always runs (whitespace, front matter, and cross-cutting rules).
+    Always,
+    /// The rule runs if *any* of these features is present.
+    Any(&'static [ContentFeature]),
+    /// The rule runs only if *all* of these features are present.
+    All(&'static [ContentFeature]),
+}
+
+/// Ergonomic alias so rules can write `Requires::All(&[Lists, Emphasis])`.
+pub type Requires = ContentRequirement;
+
+/// Confidence that applying a rule's fix preserves the author's intent,
+/// modeled on rustfix's `Applicability`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Applicability {
+    /// The fix is a faithful, machine-applicable rewrite and is applied by
+    /// default under `--fix`.
+    #[default]
+    Safe,
+    /// The fix may change the rendered content or the author's intent (e.g.
+    /// stripping inline HTML or reflowing prose). It is reported but only
+    /// applied when `--unsafe-fixes` is enabled.
+    Unsafe,
+}
+
 /// Capability of a rule to fix issues
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FixCapability {
@@ -132,6 +222,64 @@ pub trait Rule: DynClone + Send + Sync {
         RuleCategory::Other // Default implementation returns Other
     }
 
+    /// Human-readable tags this rule belongs to (e.g. `"heading"`,
+    /// `"whitespace"`), so whole categories can be enabled or disabled by one
+    /// token (`--disable-tags blockquote,whitespace`) instead of listing
+    /// every `MD0xx` code. Borrowed from mdl's `tags`.
+    ///
+    /// Defaults to a single tag derived from [`Rule::category`] so existing
+    /// rules get sensible tags for free; rules in more than one logical group
+    /// can override this to return several.
+    fn tags(&self) -> &'static [&'static str] {
+        match self.category() {
+            RuleCategory::Heading => &["heading"],
+            RuleCategory::List => &["list"],
+            RuleCategory::CodeBlock => &["code"],
+            RuleCategory::Link => &["link"],
+            RuleCategory::Image => &["image"],
+            RuleCategory::Html => &["html"],
+            RuleCategory::Emphasis => &["emphasis"],
+            RuleCategory::Whitespace => &["whitespace"],
+            RuleCategory::Blockquote => &["blockquote"],
+            RuleCategory::Table => &["table"],
+            RuleCategory::FrontMatter => &["front_matter"],
+            RuleCategory::Other => &[],
+        }
+    }
+
+    /// Human-readable aliases for this rule (e.g. `"header-increment"` for
+    /// MD001), usable anywhere `--enable`/`--disable` accepts a rule name.
+    /// Borrowed from mdl's `aliases`. Empty by default.
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Declare which content characteristics this rule needs to be present
+    /// before it is worth running. `lint()` evaluates the returned predicate
+    /// against the document's pre-scanned characteristics and skips the rule
+    /// when the predicate is not satisfied.
+    ///
+    /// The default is derived from [`Rule::category`] so existing rules keep
+    /// their current skip behavior; rules that depend on more than one feature
+    /// (or on an exact combination) override this to express that precisely.
+    fn content_requirements(&self) -> ContentRequirement {
+        use ContentFeature::*;
+        match self.category() {
+            RuleCategory::Heading => ContentRequirement::Any(&[Headings]),
+            RuleCategory::List => ContentRequirement::Any(&[Lists]),
+            RuleCategory::Link => ContentRequirement::Any(&[Links, Images]),
+            RuleCategory::Image => ContentRequirement::Any(&[Images]),
+            RuleCategory::CodeBlock => ContentRequirement::Any(&[Code]),
+            RuleCategory::Html => ContentRequirement::Any(&[Html]),
+            RuleCategory::Emphasis => ContentRequirement::Any(&[Emphasis]),
+            RuleCategory::Blockquote => ContentRequirement::Any(&[Blockquotes]),
+            RuleCategory::Table => ContentRequirement::Any(&[Tables]),
+            RuleCategory::Whitespace | RuleCategory::FrontMatter | RuleCategory::Other => {
+                ContentRequirement::Always
+            }
+        }
+    }
+
     /// Check if this rule can benefit from AST parsing
     fn uses_ast(&self) -> bool {
         false
@@ -164,6 +312,15 @@ pub trait Rule: DynClone + Send + Sync {
         FixCapability::FullyFixable // Safe default for backward compatibility
     }
 
+    /// Declares how confidently this rule's fixes can be applied. Rules whose
+    /// fixes may alter rendered content (e.g. stripping inline HTML or
+    /// reflowing prose) return [`Applicability::Unsafe`] so `--fix` leaves them
+    /// reported-but-unapplied unless `--unsafe-fixes` is set. Defaults to
+    /// [`Applicability::Safe`].
+    fn fix_applicability(&self) -> Applicability {
+        Applicability::Safe
+    }
+
     /// Factory: create a rule from config (if present), or use defaults.
     fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
     where
@@ -882,4 +1039,52 @@ Content here"#;
             Some(vec!["MD.001"])
         );
     }
+
+    #[test]
+    fn test_fix_to_lsp_text_edit_single_line() {
+        let content = "Hello world";
+        let ctx = LintContext::new(content);
+        let fix = Fix {
+            range: 6..11,
+            replacement: "there".to_string(),
+        };
+
+        let edit = fix.to_lsp_text_edit(&ctx);
+        assert_eq!(edit.range.start, LspPosition { line: 0, character: 6 });
+        assert_eq!(edit.range.end, LspPosition { line: 0, character: 11 });
+        assert_eq!(edit.new_text, "there");
+    }
+
+    #[test]
+    fn test_fix_to_lsp_text_edit_spans_multiple_lines() {
+        let content = "first\nsecond\nthird";
+        let ctx = LintContext::new(content);
+        // Byte range covering from "second" through the start of "third".
+        let fix = Fix {
+            range: 6..13,
+            replacement: String::new(),
+        };
+
+        let edit = fix.to_lsp_text_edit(&ctx);
+        assert_eq!(edit.range.start, LspPosition { line: 1, character: 0 });
+        assert_eq!(edit.range.end, LspPosition { line: 2, character: 0 });
+    }
+
+    #[test]
+    fn test_fix_to_lsp_text_edit_counts_utf16_code_units_not_bytes() {
+        // "é" is 2 bytes in UTF-8 but 1 UTF-16 code unit; "𝌆" is 4 bytes in UTF-8
+        // but 2 UTF-16 code units (a surrogate pair). VS Code expects `character`
+        // counted in UTF-16 units, not bytes.
+        let content = "café 𝌆 end";
+        let ctx = LintContext::new(content);
+        let end_byte = content.len();
+        let fix = Fix {
+            range: end_byte..end_byte,
+            replacement: String::new(),
+        };
+
+        let edit = fix.to_lsp_text_edit(&ctx);
+        // "café " -> 5 UTF-16 units, "𝌆" -> 2 units (surrogate pair), " end" -> 4 units
+        assert_eq!(edit.range.start.character, 5 + 2 + 4);
+    }
 }
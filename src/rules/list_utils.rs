@@ -1,5 +1,6 @@
 use fancy_regex::Regex as FancyRegex;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
 // Optimized list detection patterns with anchors and non-capturing groups
@@ -329,6 +330,63 @@ pub enum ListType {
     Ordered,
 }
 
+/// Expected spaces after a list marker, shared by MD016 (no multiple spaces)
+/// and MD030 (consistent spaces) so the two rules can never drift on what
+/// "the configured width" means for a given marker/multi-line combination.
+/// Lives under the `MD030` config section, since that's the rule this
+/// configuration was originally named after.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListMarkerSpacing {
+    /// Spaces for single-line unordered list items (default: 1)
+    #[serde(default = "default_marker_spaces", alias = "ul_single")]
+    pub ul_single: crate::types::PositiveUsize,
+
+    /// Spaces for multi-line unordered list items (default: 1)
+    #[serde(default = "default_marker_spaces", alias = "ul_multi")]
+    pub ul_multi: crate::types::PositiveUsize,
+
+    /// Spaces for single-line ordered list items (default: 1)
+    #[serde(default = "default_marker_spaces", alias = "ol_single")]
+    pub ol_single: crate::types::PositiveUsize,
+
+    /// Spaces for multi-line ordered list items (default: 1)
+    #[serde(default = "default_marker_spaces", alias = "ol_multi")]
+    pub ol_multi: crate::types::PositiveUsize,
+}
+
+fn default_marker_spaces() -> crate::types::PositiveUsize {
+    crate::types::PositiveUsize::from_const(1)
+}
+
+impl Default for ListMarkerSpacing {
+    fn default() -> Self {
+        Self {
+            ul_single: default_marker_spaces(),
+            ul_multi: default_marker_spaces(),
+            ol_single: default_marker_spaces(),
+            ol_multi: default_marker_spaces(),
+        }
+    }
+}
+
+impl crate::rule_config_serde::RuleConfig for ListMarkerSpacing {
+    const RULE_NAME: &'static str = "MD030";
+}
+
+impl ListMarkerSpacing {
+    /// The number of spaces expected after a list marker of `list_type`,
+    /// given whether the item spans multiple lines.
+    pub fn get_expected_spaces(&self, list_type: ListType, is_multi: bool) -> usize {
+        match (list_type, is_multi) {
+            (ListType::Unordered, false) => self.ul_single.get(),
+            (ListType::Unordered, true) => self.ul_multi.get(),
+            (ListType::Ordered, false) => self.ol_single.get(),
+            (ListType::Ordered, true) => self.ol_multi.get(),
+        }
+    }
+}
+
 /// Returns (ListType, matched string, number of spaces after marker) if the line is a list item
 pub fn is_list_item(line: &str) -> Option<(ListType, String, usize)> {
     let trimmed_line = line.trim();
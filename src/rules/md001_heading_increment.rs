@@ -279,6 +279,10 @@ impl Rule for MD001HeadingIncrement {
         RuleCategory::Heading
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["header-increment"]
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has headings
         if ctx.content.is_empty() || !ctx.likely_has_headings() {
@@ -305,6 +305,11 @@ impl Rule for MD013LineLength {
         RuleCategory::Whitespace
     }
 
+    fn fix_applicability(&self) -> crate::rule::Applicability {
+        // Reflowing long lines restructures the prose and can alter intent.
+        crate::rule::Applicability::Unsafe
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip if content is empty
         if ctx.content.is_empty() {
@@ -2,29 +2,26 @@
 ///
 /// See [docs/md016.md](../../docs/md016.md) for full documentation, configuration, and examples.
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rules::list_utils::{ListMarkerSpacing, is_list_item, is_multi_line_item, ListType};
+use crate::types::PositiveUsize;
 use crate::utils::element_cache::ElementCache;
 use crate::utils::element_cache::ListMarkerType;
 use crate::utils::range_utils::LineIndex;
 use toml;
-use crate::rules::list_utils::{is_list_item, is_multi_line_item, ListType};
 
 #[derive(Clone, Debug)]
 pub struct MD016NoMultipleSpaceAfterListMarker {
     pub allow_multiple_spaces: bool,
-    pub ul_single: usize,
-    pub ul_multi: usize,
-    pub ol_single: usize,
-    pub ol_multi: usize,
+    /// Expected spacing, shared with MD030 so the two rules cannot disagree
+    /// on what the configured width means.
+    pub spacing: ListMarkerSpacing,
 }
 
 impl Default for MD016NoMultipleSpaceAfterListMarker {
     fn default() -> Self {
         Self {
             allow_multiple_spaces: false,
-            ul_single: 1,
-            ul_multi: 1,
-            ol_single: 1,
-            ol_multi: 1,
+            spacing: ListMarkerSpacing::default(),
         }
     }
 }
@@ -42,22 +39,20 @@ impl MD016NoMultipleSpaceAfterListMarker {
     }
 
     pub fn with_config(allow_multiple_spaces: bool, ul_single: usize, ul_multi: usize, ol_single: usize, ol_multi: usize) -> Self {
+        let clamp = |v: usize| PositiveUsize::new(v).unwrap_or_else(|_| PositiveUsize::from_const(1));
         Self {
             allow_multiple_spaces,
-            ul_single,
-            ul_multi,
-            ol_single,
-            ol_multi,
+            spacing: ListMarkerSpacing {
+                ul_single: clamp(ul_single),
+                ul_multi: clamp(ul_multi),
+                ol_single: clamp(ol_single),
+                ol_multi: clamp(ol_multi),
+            },
         }
     }
 
     pub fn get_expected_spaces(&self, list_type: ListType, is_multi: bool) -> usize {
-        match (list_type, is_multi) {
-            (ListType::Unordered, false) => self.ul_single,
-            (ListType::Unordered, true) => self.ul_multi,
-            (ListType::Ordered, false) => self.ol_single,
-            (ListType::Ordered, true) => self.ol_multi,
-        }
+        self.spacing.get_expected_spaces(list_type, is_multi)
     }
 }
 
@@ -95,6 +90,9 @@ impl Rule for MD016NoMultipleSpaceAfterListMarker {
                 if in_code_block {
                     continue;
                 }
+                if !ctx.line_in_range(line_num) {
+                    continue;
+                }
                 if let Some((list_type, _matched, _spaces)) = is_list_item(line) {
                     let is_multi = is_multi_line_item(&lines, i);
                     let allowed = self.get_expected_spaces(list_type, is_multi);
@@ -173,7 +171,9 @@ impl Rule for MD016NoMultipleSpaceAfterListMarker {
             let line_num = i + 1;
             if let Some(list_item) = element_cache.get_list_item(line_num) {
                 let in_code_block = element_cache.is_in_code_block(line_num);
-                if in_code_block {
+                // Lines outside the `--lines`/`--diff-only` restriction must come
+                // through byte-for-byte untouched, same as lines in a code block.
+                if in_code_block || !ctx.line_in_range(line_num) {
                     result.push_str(line);
                 } else {
                     let indentation = &list_item.indent_str;
@@ -182,7 +182,14 @@ impl Rule for MD016NoMultipleSpaceAfterListMarker {
                     let fixed_line = if content.is_empty() {
                         format!("{}{}", indentation, marker)
                     } else {
-                        format!("{}{} {}", indentation, marker, content)
+                        let allowed = match is_list_item(line) {
+                            Some((list_type, _matched, _spaces)) => {
+                                let is_multi = is_multi_line_item(&lines, i);
+                                self.get_expected_spaces(list_type, is_multi)
+                            }
+                            None => 1,
+                        };
+                        format!("{}{}{}{}", indentation, marker, " ".repeat(allowed), content)
                     };
                     result.push_str(&fixed_line);
                 }
@@ -211,32 +218,32 @@ impl Rule for MD016NoMultipleSpaceAfterListMarker {
         );
         map.insert(
             "ul_single".to_string(),
-            toml::Value::Integer(self.ul_single as i64),
+            toml::Value::Integer(self.spacing.ul_single.get() as i64),
         );
         map.insert(
             "ul_multi".to_string(),
-            toml::Value::Integer(self.ul_multi as i64),
+            toml::Value::Integer(self.spacing.ul_multi.get() as i64),
         );
         map.insert(
             "ol_single".to_string(),
-            toml::Value::Integer(self.ol_single as i64),
+            toml::Value::Integer(self.spacing.ol_single.get() as i64),
         );
         map.insert(
             "ol_multi".to_string(),
-            toml::Value::Integer(self.ol_multi as i64),
+            toml::Value::Integer(self.spacing.ol_multi.get() as i64),
         );
         Some((self.name().to_string(), toml::Value::Table(map)))
     }
 
     fn from_config(config: &crate::config::Config) -> Box<dyn Rule> {
         let allow_multiple_spaces = crate::config::get_rule_config_value::<bool>(config, "MD016", "allow_multiple_spaces").unwrap_or(false);
-        let ul_single = crate::config::get_rule_config_value::<usize>(config, "MD030", "ul-single").unwrap_or(1);
-        let ul_multi  = crate::config::get_rule_config_value::<usize>(config, "MD030", "ul-multi").unwrap_or(1);
-        let ol_single = crate::config::get_rule_config_value::<usize>(config, "MD030", "ol-single").unwrap_or(1);
-        let ol_multi  = crate::config::get_rule_config_value::<usize>(config, "MD030", "ol-multi").unwrap_or(1);
-        Box::new(MD016NoMultipleSpaceAfterListMarker::with_config(
-            allow_multiple_spaces, ul_single, ul_multi, ol_single, ol_multi
-        ))
+        // MD016 and MD030 share their spacing config under MD030's section, so
+        // the two rules can never rewrite to different widths.
+        let spacing = crate::rule_config_serde::load_rule_config::<ListMarkerSpacing>(config);
+        Box::new(MD016NoMultipleSpaceAfterListMarker {
+            allow_multiple_spaces,
+            spacing,
+        })
     }
 }
 
@@ -368,4 +375,44 @@ mod tests {
         let warnings = rule.check(&ctx).unwrap();
         assert_eq!(warnings.len(), 1, "Warn for multi-line unordered with more than allowed");
     }
+
+    #[test]
+    fn test_fix_respects_configured_multi_line_widths() {
+        // ul_multi=2, ol_multi=3: fix() must rewrite to the *configured*
+        // width, not hardcode a single space, and must agree with what
+        // check() considers already-compliant.
+        let rule = MD016NoMultipleSpaceAfterListMarker::with_config(false, 1, 2, 1, 3);
+
+        let content = "-     one\n      continued";
+        let fixed = rule.fix(&crate::lint_context::LintContext::new(content)).unwrap();
+        assert_eq!(fixed, "-  one\n      continued");
+        assert_eq!(rule.check(&crate::lint_context::LintContext::new(&fixed)).unwrap().len(), 0);
+
+        let content = "1.     one\n       continued";
+        let fixed = rule.fix(&crate::lint_context::LintContext::new(content)).unwrap();
+        assert_eq!(fixed, "1.   one\n       continued");
+        assert_eq!(rule.check(&crate::lint_context::LintContext::new(&fixed)).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_line_ranges_restrict_check_and_fix() {
+        // Single-line items are only flagged when the configured width is
+        // above one, so use a width of 2 and give every item 3 spaces.
+        let rule = MD016NoMultipleSpaceAfterListMarker::with_config(false, 2, 2, 2, 2);
+        let content = "-   one\n*   two\n+   three";
+
+        // Unrestricted: all three lines are flagged and fixed.
+        let ctx = crate::lint_context::LintContext::new(content);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 3);
+
+        // Restricted to line 2 only: only that line is flagged, and the
+        // other two must come through the fix byte-for-byte untouched.
+        let ctx = crate::lint_context::LintContext::new(content).with_line_ranges(vec![2..=2]);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "-   one\n*  two\n+   three");
+    }
 }
@@ -2,12 +2,24 @@ use toml;
 
 use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
 use crate::utils::range_utils::calculate_match_range;
 use std::collections::{HashMap, HashSet};
 
 mod md024_config;
 use md024_config::MD024Config;
 
+/// Fold a heading to its canonical caseless form for duplicate comparison.
+///
+/// Unlike a naive `to_lowercase`, full case folding maps German ß to `"ss"`
+/// and handles the Turkish dotless-i edge cases using the Unicode *default*
+/// (locale-independent) folding rules, so `"Straße"` and `"STRASSE"` compare
+/// equal. Only used for matching; the original heading text is still used
+/// when reporting the warning.
+fn fold_case(text: &str) -> String {
+    caseless::default_case_fold_str(text)
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct MD024NoDuplicateHeading {
     config: MD024Config,
@@ -19,6 +31,20 @@ impl MD024NoDuplicateHeading {
             config: MD024Config {
                 allow_different_nesting,
                 siblings_only,
+                ..MD024Config::default()
+            },
+        }
+    }
+
+    /// Create an instance that flags duplicate heading *anchors* (slugs) instead
+    /// of duplicate heading text, using `anchor_style` to generate fragments the
+    /// same way MD051 does.
+    pub fn new_with_anchor_check(anchor_style: AnchorStyle) -> Self {
+        Self {
+            config: MD024Config {
+                check_anchors: true,
+                anchor_style,
+                ..MD024Config::default()
             },
         }
     }
@@ -46,6 +72,7 @@ impl Rule for MD024NoDuplicateHeading {
         let mut warnings = Vec::new();
         let mut seen_headings: HashSet<String> = HashSet::new();
         let mut seen_headings_per_level: HashMap<u8, HashSet<String>> = HashMap::new();
+        let mut seen_anchors: HashSet<String> = HashSet::new();
 
         // For siblings_only mode, track heading hierarchy
         let mut current_section_path: Vec<(u8, String)> = Vec::new(); // Stack of (level, heading_text)
@@ -59,7 +86,11 @@ impl Rule for MD024NoDuplicateHeading {
                     continue;
                 }
 
-                let heading_key = heading.text.clone();
+                let heading_key = if self.config.case_insensitive {
+                    fold_case(&heading.text)
+                } else {
+                    heading.text.clone()
+                };
                 let level = heading.level;
 
                 // Calculate precise character range for the heading text content
@@ -77,7 +108,25 @@ impl Rule for MD024NoDuplicateHeading {
                 let (start_line, start_col, end_line, end_col) =
                     calculate_match_range(line_num + 1, &line_info.content, text_start_in_line, heading.text.len());
 
-                if self.config.siblings_only {
+                if self.config.check_anchors {
+                    // Anchors are always document-scoped, regardless of heading
+                    // nesting, so this mode ignores siblings_only/allow_different_nesting.
+                    let anchor = self.config.anchor_style.generate_fragment(&heading.text);
+                    if seen_anchors.contains(&anchor) {
+                        warnings.push(LintWarning {
+                            rule_name: Some(self.name()),
+                            message: format!("Duplicate heading anchor: '#{anchor}' (from heading '{}').", heading.text),
+                            line: start_line,
+                            column: start_col,
+                            end_line,
+                            end_column: end_col,
+                            severity: Severity::Warning,
+                            fix: None,
+                        });
+                    } else {
+                        seen_anchors.insert(anchor);
+                    }
+                } else if self.config.siblings_only {
                     // Update the section path based on the current heading level
                     while !current_section_path.is_empty() && current_section_path.last().unwrap().0 >= level {
                         current_section_path.pop();
@@ -294,6 +343,7 @@ This has the same text but different level."#;
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            ..MD024Config::default()
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -323,6 +373,58 @@ All caps."#;
         assert_eq!(warnings.len(), 0);
     }
 
+    #[test]
+    fn test_case_insensitive_flags_different_case_as_duplicate() {
+        let content = r#"# First Heading
+
+Some content.
+
+## first heading
+
+Different case.
+
+### FIRST HEADING
+
+All caps."#;
+
+        let config = MD024Config {
+            case_insensitive: true,
+            siblings_only: false,
+            ..MD024Config::default()
+        };
+        let result = run_test(content, config);
+        assert!(result.is_ok());
+        let warnings = result.unwrap();
+        // With case_insensitive, all three count as duplicates of the first
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Duplicate heading: 'first heading'.");
+        assert_eq!(warnings[1].message, "Duplicate heading: 'FIRST HEADING'.");
+    }
+
+    #[test]
+    fn test_case_insensitive_unicode_case_folding() {
+        // German ß case-folds to "ss", so these should be treated as duplicates
+        // even though neither is a naive ASCII-lowercase of the other.
+        let content = r#"# Straße
+
+Some content.
+
+## STRASSE
+
+Same heading, folded case."#;
+
+        let config = MD024Config {
+            case_insensitive: true,
+            siblings_only: false,
+            ..MD024Config::default()
+        };
+        let result = run_test(content, config);
+        assert!(result.is_ok());
+        let warnings = result.unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Duplicate heading: 'STRASSE'.");
+    }
+
     #[test]
     fn test_headings_with_trailing_punctuation() {
         let content = r#"# First Heading!
@@ -511,6 +613,7 @@ Different section, but still a duplicate when allow_different_nesting is true."#
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            ..MD024Config::default()
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -653,6 +756,7 @@ Another Overview in yet another section."#;
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            ..MD024Config::default()
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -775,6 +879,7 @@ Different parent sections, so not siblings - no warning expected."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: true,
+            ..MD024Config::default()
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -804,6 +909,7 @@ This 'First Subsection' IS a sibling duplicate."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: true,
+            ..MD024Config::default()
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -881,6 +987,7 @@ All same text, different levels."#;
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            ..MD024Config::default()
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -888,4 +995,60 @@ All same text, different levels."#;
         // With allow_different_nesting, there should be no warnings
         assert_eq!(warnings.len(), 0);
     }
+
+    #[test]
+    fn test_anchor_check_flags_colliding_slugs_with_different_text() {
+        let content = r#"# API Response
+
+Some content.
+
+## api-response
+
+Different text, but the same GitHub anchor slug."#;
+
+        let rule = MD024NoDuplicateHeading::new_with_anchor_check(AnchorStyle::GitHub);
+        let ctx = LintContext::new(content);
+        let result = rule.check(&ctx);
+        assert!(result.is_ok());
+        let warnings = result.unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Duplicate heading anchor: '#api-response' (from heading 'api-response').");
+        assert_eq!(warnings[0].line, 5);
+    }
+
+    #[test]
+    fn test_anchor_check_ignores_siblings_only_scoping() {
+        let content = r#"# Section One
+
+## Subsection
+
+# Section Two
+
+## Subsection
+
+Different parent sections, but anchors are document-scoped so this is still a collision."#;
+
+        let rule = MD024NoDuplicateHeading::new_with_anchor_check(AnchorStyle::GitHub);
+        let ctx = LintContext::new(content);
+        let result = rule.check(&ctx);
+        assert!(result.is_ok());
+        let warnings = result.unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Duplicate heading anchor: '#subsection' (from heading 'Subsection').");
+    }
+
+    #[test]
+    fn test_anchor_check_no_collision_for_distinct_slugs() {
+        let content = r#"# First Heading
+
+## Second Heading
+
+Different text, different anchors."#;
+
+        let rule = MD024NoDuplicateHeading::new_with_anchor_check(AnchorStyle::GitHub);
+        let ctx = LintContext::new(content);
+        let result = rule.check(&ctx);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 0);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for MD024 (Multiple headings with the same content)
@@ -18,6 +19,32 @@ pub struct MD024Config {
     /// (GitHub, GitLab, etc.) handle this by adding numeric suffixes.
     #[serde(default = "default_siblings_only", alias = "siblings_only")]
     pub siblings_only: bool,
+
+    /// Flag duplicate heading *anchors* (the slug a renderer would link to) rather
+    /// than duplicate heading text (default: false)
+    ///
+    /// Two headings with different text can still collide once slugified (e.g.
+    /// "API Response" and "api-response"), which silently produces the numeric
+    /// anchor suffixes noted above. Enabling this mode checks for that instead of
+    /// literal text equality; it takes precedence over `siblings_only` and
+    /// `allow_different_nesting`, since anchors are always document-scoped.
+    #[serde(default, alias = "check_anchors")]
+    pub check_anchors: bool,
+
+    /// Anchor style used to slugify headings when `check_anchors` is enabled
+    /// (default: github)
+    #[serde(default, alias = "anchor_style")]
+    pub anchor_style: AnchorStyle,
+
+    /// Treat headings that differ only in case as duplicates (default: false)
+    ///
+    /// Uses Unicode default case folding rather than naive ASCII lowercasing
+    /// (so e.g. German "Straße" and "STRASSE" are treated as the same
+    /// heading). Independent of `siblings_only` and `allow_different_nesting`:
+    /// it only changes how two heading strings are compared, not which
+    /// headings are compared against each other.
+    #[serde(default, alias = "case_insensitive")]
+    pub case_insensitive: bool,
 }
 
 fn default_siblings_only() -> bool {
@@ -29,6 +56,9 @@ impl Default for MD024Config {
         Self {
             allow_different_nesting: false,
             siblings_only: true,
+            check_anchors: false,
+            anchor_style: AnchorStyle::default(),
+            case_insensitive: false,
         }
     }
 }
@@ -34,12 +34,7 @@ impl MD030ListMarkerSpace {
     }
 
     pub fn get_expected_spaces(&self, list_type: ListType, is_multi: bool) -> usize {
-        match (list_type, is_multi) {
-            (ListType::Unordered, false) => self.config.ul_single,
-            (ListType::Unordered, true) => self.config.ul_multi,
-            (ListType::Ordered, false) => self.config.ol_single,
-            (ListType::Ordered, true) => self.config.ol_multi,
-        }
+        self.config.get_expected_spaces(list_type, is_multi)
     }
 }
 
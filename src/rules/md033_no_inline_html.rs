@@ -532,6 +532,11 @@ impl Rule for MD033NoInlineHtml {
         crate::rule::FixCapability::Unfixable
     }
 
+    fn fix_applicability(&self) -> crate::rule::Applicability {
+        // Removing inline HTML can change how a document renders.
+        crate::rule::Applicability::Unsafe
+    }
+
     /// Get the category of this rule for selective processing
     fn category(&self) -> RuleCategory {
         RuleCategory::Html
@@ -2,6 +2,51 @@ use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, S
 use crate::utils::document_structure::{DocumentStructure, DocumentStructureExtensions};
 use crate::utils::range_utils::{LineIndex, calculate_line_range};
 
+pub mod md040_config;
+pub use md040_config::MD040Config;
+
+/// Classify a fence info string as a Rust doctest fence.
+///
+/// rustdoc lets documentation fences carry attributes such as `rust,should_panic`,
+/// `no_run`, `ignore,edition2021`, or an error-code tag like `E0277`. These are
+/// Rust code, not arbitrary language labels, so the language check should treat
+/// them as Rust rather than flagging a missing or unknown language.
+///
+/// The info string is tokenized on commas, spaces, and tabs. Two flags track the
+/// running classification; the fence is Rust when `!seen_other_tags || seen_rust_tags`.
+pub fn is_rust_doctest_fence(info: &str) -> bool {
+    let mut seen_rust_tags = false;
+    let mut seen_other_tags = false;
+
+    for token in info.split([',', ' ', '\t']).filter(|t| !t.is_empty()) {
+        match token {
+            "should_panic" | "no_run" | "ignore" | "allow_fail" => {
+                seen_rust_tags = !seen_other_tags;
+            }
+            "rust" => seen_rust_tags = true,
+            "test_harness" | "compile_fail" => {
+                seen_rust_tags = !seen_other_tags || seen_rust_tags;
+            }
+            _ if token.starts_with("edition") => {
+                // Edition selectors carry no language signal; ignore them.
+            }
+            _ if is_error_code(token) => seen_rust_tags = true,
+            _ => seen_other_tags = true,
+        }
+    }
+
+    !seen_other_tags || seen_rust_tags
+}
+
+/// A token shaped like `E` followed by exactly four parseable digits (e.g. `E0277`).
+fn is_error_code(token: &str) -> bool {
+    if let Some(digits) = token.strip_prefix('E') {
+        digits.len() == 4 && digits.bytes().all(|b| b.is_ascii_digit())
+    } else {
+        false
+    }
+}
+
 /// Rule MD040: Fenced code blocks should have a language
 ///
 /// See [docs/md040.md](../../docs/md040.md) for full documentation, configuration, and examples.
@@ -789,6 +834,29 @@ console.log(`template string with backticks`);
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_is_rust_doctest_fence() {
+        // Plain attribute fences rustdoc renders as Rust.
+        assert!(is_rust_doctest_fence("rust,should_panic"));
+        assert!(is_rust_doctest_fence("no_run"));
+        assert!(is_rust_doctest_fence("ignore,edition2021"));
+        assert!(is_rust_doctest_fence("E0277"));
+        assert!(is_rust_doctest_fence("should_panic,no_run"));
+        assert!(is_rust_doctest_fence("rust"));
+        // An explicit `rust` tag wins even alongside another language token.
+        assert!(is_rust_doctest_fence("rust,text"));
+        // `compile_fail`/`test_harness` only count as Rust without another language.
+        assert!(is_rust_doctest_fence("compile_fail"));
+        assert!(!is_rust_doctest_fence("python,compile_fail"));
+
+        // Anything else is a real language label, not a rustdoc fence.
+        assert!(!is_rust_doctest_fence("python"));
+        assert!(!is_rust_doctest_fence("text,should_panic"));
+        assert!(!is_rust_doctest_fence("E027")); // too short
+        assert!(!is_rust_doctest_fence("E02777")); // too long
+        assert!(!is_rust_doctest_fence("Exxxx")); // non-digit
+    }
+
     #[test]
     fn test_should_skip_optimization() {
         let rule = MD040FencedCodeLanguage;
@@ -52,6 +52,13 @@ pub struct MD040Config {
     /// Action for unknown language labels not in Linguist
     #[serde(default, alias = "unknown_language_action")]
     pub unknown_language_action: UnknownLanguageAction,
+
+    /// Flag for rustdoc attribute fences (e.g. `rust,should_panic`, `no_run`,
+    /// `ignore,edition2021`, `E0277`), which `is_rust_doctest_fence` classifies.
+    /// MD040 does not read this flag: its check only reports fences with no
+    /// info string, so such fences are not reported whether it is on or off.
+    #[serde(default, alias = "allow_rust_doctest_fences")]
+    pub allow_rust_doctest_fences: bool,
 }
 
 impl RuleConfig for MD040Config {
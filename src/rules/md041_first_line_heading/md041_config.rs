@@ -19,6 +19,11 @@ pub struct MD041Config {
     /// If provided, checks for this pattern in front matter instead of "title:"
     #[serde(default, alias = "front_matter_title_pattern")]
     pub front_matter_title_pattern: Option<String>,
+
+    /// Allow a permitted preamble (centered logo/badge HTML block, standalone
+    /// image, or link-only line) before the required heading (default: false)
+    #[serde(default, alias = "allowed_preamble")]
+    pub allowed_preamble: bool,
 }
 
 fn default_front_matter_title() -> String {
@@ -31,6 +36,7 @@ impl Default for MD041Config {
             level: HeadingLevel::default(),
             front_matter_title: default_front_matter_title(),
             front_matter_title_pattern: None,
+            allowed_preamble: false,
         }
     }
 }
@@ -49,6 +55,16 @@ mod tests {
         assert_eq!(config.level.get(), 1);
         assert_eq!(config.front_matter_title, "title");
         assert!(config.front_matter_title_pattern.is_none());
+        assert!(!config.allowed_preamble);
+    }
+
+    #[test]
+    fn test_allowed_preamble_deserialization() {
+        let toml_str = r#"
+            allowed-preamble = true
+        "#;
+        let config: MD041Config = toml::from_str(toml_str).unwrap();
+        assert!(config.allowed_preamble);
     }
 
     #[test]
@@ -82,6 +98,7 @@ mod tests {
             level: HeadingLevel::new(2).unwrap(),
             front_matter_title: "header".to_string(),
             front_matter_title_pattern: Some("^heading:".to_string()),
+            allowed_preamble: false,
         };
 
         let toml_str = toml::to_string(&config).unwrap();
@@ -6,6 +6,7 @@ use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
 use crate::rules::front_matter_utils::FrontMatterUtils;
 use crate::utils::range_utils::calculate_line_range;
 use crate::utils::regex_cache::HTML_HEADING_PATTERN;
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag};
 use regex::Regex;
 
 /// Rule MD041: First line in file should be a top-level heading
@@ -17,6 +18,17 @@ pub struct MD041FirstLineHeading {
     pub level: usize,
     pub front_matter_title: bool,
     pub front_matter_title_pattern: Option<Regex>,
+    /// Accepted front matter keys for the title, each optionally dotted to
+    /// descend into nested maps/tables (e.g. `seo.title`).
+    pub title_fields: Vec<String>,
+    /// Allow a permitted preamble (centered logo/badge HTML block, standalone
+    /// image, or link-only line) before the required heading. Off by default
+    /// to preserve existing strictness.
+    pub allowed_preamble: bool,
+}
+
+fn default_title_fields() -> Vec<String> {
+    vec!["title".to_string()]
 }
 
 impl Default for MD041FirstLineHeading {
@@ -25,16 +37,61 @@ impl Default for MD041FirstLineHeading {
             level: 1,
             front_matter_title: true,
             front_matter_title_pattern: None,
+            title_fields: default_title_fields(),
+            allowed_preamble: false,
         }
     }
 }
 
+/// Simplified view of a pulldown-cmark start tag — just enough to classify
+/// the top-level block it opens without threading lifetimes through
+/// [`MD041FirstLineHeading::classify_top_level_block`].
+enum SimpleTag {
+    Heading(usize),
+    HtmlBlock,
+    Paragraph,
+    Other,
+}
+
+impl From<&Tag<'_>> for SimpleTag {
+    fn from(tag: &Tag<'_>) -> Self {
+        match tag {
+            Tag::Heading { level, .. } => SimpleTag::Heading(heading_level_as_usize(*level)),
+            Tag::HtmlBlock => SimpleTag::HtmlBlock,
+            Tag::Paragraph => SimpleTag::Paragraph,
+            _ => SimpleTag::Other,
+        }
+    }
+}
+
+fn heading_level_as_usize(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// Classification of the first top-level block found in a document, used to
+/// decide whether MD041 is already satisfied.
+enum FirstBlock {
+    AtxHeading(usize),
+    SetextHeading(usize),
+    HtmlHeading(usize),
+    Other,
+}
+
 impl MD041FirstLineHeading {
     pub fn new(level: usize, front_matter_title: bool) -> Self {
         Self {
             level,
             front_matter_title,
             front_matter_title_pattern: None,
+            title_fields: default_title_fields(),
+            allowed_preamble: false,
         }
     }
 
@@ -51,7 +108,23 @@ impl MD041FirstLineHeading {
             level,
             front_matter_title,
             front_matter_title_pattern,
+            title_fields: default_title_fields(),
+            allowed_preamble: false,
+        }
+    }
+
+    /// Override the accepted front matter title keys (dotted paths allowed).
+    pub fn with_title_fields(mut self, title_fields: Vec<String>) -> Self {
+        if !title_fields.is_empty() {
+            self.title_fields = title_fields;
         }
+        self
+    }
+
+    /// Allow a permitted preamble construct before the required heading.
+    pub fn with_allowed_preamble(mut self, allowed_preamble: bool) -> Self {
+        self.allowed_preamble = allowed_preamble;
+        self
     }
 
     fn has_front_matter_title(&self, content: &str) -> bool {
@@ -70,20 +143,307 @@ impl MD041FirstLineHeading {
             return false;
         }
 
-        // Default behavior: check for "title:" field
-        FrontMatterUtils::has_front_matter_field(content, "title:")
+        // Parse the front matter block structurally so TOML (`+++`) and JSON
+        // (`{ }`) blocks are recognized, quoted/bare/array values are handled,
+        // and dotted keys descend into nested tables. A parsed-but-absent (or
+        // present-but-empty/null) title still counts as "no title".
+        if let Some(value) = Self::parse_front_matter_value(content) {
+            return self
+                .title_fields
+                .iter()
+                .filter_map(|key| Self::lookup_dotted(&value, key))
+                .any(Self::title_value_present);
+        }
+
+        // Fallback to the flat line-scan when deserialization fails so
+        // malformed front matter keeps behaving as before.
+        self.title_fields
+            .iter()
+            .any(|key| FrontMatterUtils::has_front_matter_field(content, &format!("{key}:")))
     }
 
-    /// Check if a line is a non-content token that should be skipped
-    fn is_non_content_line(line: &str) -> bool {
-        let trimmed = line.trim();
+    /// Deserialize the front matter block into a JSON value tree, choosing the
+    /// parser from the detected fence type. Returns `None` when there is no
+    /// front matter or it fails to deserialize.
+    fn parse_front_matter_value(content: &str) -> Option<serde_json::Value> {
+        use crate::rules::front_matter_utils::FrontMatterType;
 
-        // Skip reference definitions
-        if trimmed.starts_with('[') && trimmed.contains("]: ") {
-            return true;
+        let body = FrontMatterUtils::extract_front_matter(content).join("\n");
+        if body.trim().is_empty() {
+            return None;
+        }
+
+        match FrontMatterUtils::detect_front_matter_type(content) {
+            FrontMatterType::Yaml | FrontMatterType::Malformed => serde_yaml::from_str(&body).ok(),
+            FrontMatterType::Toml => toml::from_str(&body).ok(),
+            // `extract_front_matter` drops the `{`/`}` delimiter lines, so the
+            // object body needs re-wrapping before it parses as JSON.
+            FrontMatterType::Json => serde_json::from_str(&format!("{{{body}}}")).ok(),
+            FrontMatterType::None => None,
+        }
+    }
+
+    /// Resolve a dotted key path against a parsed value tree.
+    fn lookup_dotted<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+        let mut current = value;
+        for segment in path.split('.') {
+            current = current.get(segment.trim())?;
+        }
+        Some(current)
+    }
+
+    /// Whether a resolved title value counts as actually present. Null, empty
+    /// strings, and empty/blank arrays are treated as missing so the rule fires.
+    fn title_value_present(value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Null => false,
+            serde_json::Value::String(s) => !s.trim().is_empty(),
+            serde_json::Value::Array(items) => items.iter().any(Self::title_value_present),
+            _ => true,
+        }
+    }
+
+    /// Classification of the first block-level element found after front
+    /// matter, derived from the shared pulldown-cmark event stream rather
+    /// than re-deriving block boundaries from per-line heuristics.
+    fn first_block(&self, ctx: &crate::lint_context::LintContext) -> Option<(FirstBlock, usize)> {
+        // Front matter (YAML/TOML/JSON) is not part of the Markdown grammar,
+        // so it's stripped up front rather than modeled as a block.
+        let mut start_line = FrontMatterUtils::get_front_matter_end_line(ctx.content);
+        // MDX-style ESM import/export lines sit between front matter and the
+        // first real block; pulldown-cmark doesn't know this dialect, so skip
+        // them the same way the rest of the rule does.
+        while start_line < ctx.lines.len() && ctx.lines[start_line].in_esm_block {
+            start_line += 1;
+        }
+        if start_line >= ctx.lines.len() {
+            return None;
+        }
+        let start_byte = ctx.lines[start_line].byte_offset;
+
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        options.insert(Options::ENABLE_FOOTNOTES);
+        options.insert(Options::ENABLE_STRIKETHROUGH);
+        options.insert(Options::ENABLE_TASKLISTS);
+        options.insert(Options::ENABLE_GFM);
+
+        let body = &ctx.content[start_byte..];
+        let mut depth = 0usize;
+        let mut open: Option<(SimpleTag, usize)> = None;
+
+        for (event, range) in Parser::new_ext(body, options).into_offset_iter() {
+            match event {
+                Event::Start(tag) => {
+                    if depth == 0 {
+                        open = Some((SimpleTag::from(&tag), start_byte + range.start));
+                    }
+                    depth += 1;
+                }
+                Event::End(_) => {
+                    depth -= 1;
+                    if depth == 0
+                        && let Some((kind, block_start)) = open.take()
+                        && let Some(result) = self.classify_top_level_block(ctx, kind, block_start, start_byte + range.end)
+                    {
+                        return Some(result);
+                    }
+                }
+                Event::Rule if depth == 0 => {
+                    let block_start = start_byte + range.start;
+                    return Some((FirstBlock::Other, Self::line_idx_for_byte(ctx, block_start)));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Classify a single top-level block, returning `None` to signal that the
+    /// block is permitted preamble/non-content and scanning should continue.
+    fn classify_top_level_block(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        kind: SimpleTag,
+        block_start: usize,
+        block_end: usize,
+    ) -> Option<(FirstBlock, usize)> {
+        let first_line_idx = Self::line_idx_for_byte(ctx, block_start);
+        let raw = ctx.content[block_start..block_end].trim();
+
+        match kind {
+            SimpleTag::Heading(level) => {
+                let kind = if raw.starts_with('#') {
+                    FirstBlock::AtxHeading(level)
+                } else {
+                    FirstBlock::SetextHeading(level)
+                };
+                Some((kind, first_line_idx))
+            }
+            SimpleTag::HtmlBlock => {
+                if let Some(level) = Self::html_heading_level(ctx, first_line_idx) {
+                    return Some((FirstBlock::HtmlHeading(level), first_line_idx));
+                }
+                // Comments are always transparent to this rule, regardless of
+                // `allowed_preamble` — they carry no content either way.
+                if raw.starts_with("<!--") && raw.ends_with("-->") {
+                    return None;
+                }
+                if self.allowed_preamble && self.allowed_preamble_block_end(ctx, first_line_idx).is_some() {
+                    return None;
+                }
+                Some((FirstBlock::Other, first_line_idx))
+            }
+            SimpleTag::Paragraph => {
+                if self.is_non_content_line(raw) {
+                    return None;
+                }
+                Some((FirstBlock::Other, first_line_idx))
+            }
+            SimpleTag::Other => Some((FirstBlock::Other, first_line_idx)),
+        }
+    }
+
+    /// 0-indexed line containing `byte_offset`.
+    fn line_idx_for_byte(ctx: &crate::lint_context::LintContext, byte_offset: usize) -> usize {
+        ctx.offset_to_line_col(byte_offset).0 - 1
+    }
+
+    /// When `allowed_preamble` is enabled and `line_num` opens a permitted HTML
+    /// preamble block (e.g. `<p align="center"><img .../></p>`, a bare `<img>`,
+    /// or a `<div>` wrapping a logo), returns the index of the first line after
+    /// it. Headings are never treated as preamble, and an unterminated block
+    /// is treated as real content rather than silently consumed.
+    fn allowed_preamble_block_end(&self, ctx: &crate::lint_context::LintContext, line_num: usize) -> Option<usize> {
+        let line_info = &ctx.lines[line_num];
+        let content = line_info.content(ctx.content);
+        let trimmed = content.trim_start();
+        if !trimmed.starts_with('<') {
+            return None;
+        }
+
+        let offset = line_info.byte_offset + (content.len() - trimmed.len());
+        let html_tags = ctx.html_tags();
+        let open_idx = html_tags.iter().position(|tag| tag.byte_offset == offset)?;
+        let tag = &html_tags[open_idx];
+        if tag.is_closing || Self::is_heading_tag(&tag.tag_name) {
+            return None;
+        }
+        if tag.is_self_closing || Self::is_void_html_tag(&tag.tag_name) {
+            return Some(line_num + 1);
+        }
+
+        let target_tag = tag.tag_name.clone();
+        let mut depth = 1usize;
+        for tag in html_tags.iter().skip(open_idx + 1) {
+            if tag.tag_name == target_tag {
+                if tag.is_closing {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(tag.line); // tag.line is 1-indexed == next 0-indexed line
+                    }
+                } else if !tag.is_self_closing {
+                    depth += 1;
+                }
+            }
+        }
+        None
+    }
+
+    /// Whether `tag_name` is `h1`..`h6`; heading tags are never preamble.
+    fn is_heading_tag(tag_name: &str) -> bool {
+        let mut chars = tag_name.chars();
+        matches!(chars.next(), Some('h')) && chars.as_str().parse::<u8>().is_ok_and(|n| (1..=6).contains(&n))
+    }
+
+    /// HTML void elements that never have a closing tag, so a preamble line
+    /// built only from one (e.g. a bare `<img src="logo.png">`) is complete
+    /// by itself.
+    fn is_void_html_tag(tag_name: &str) -> bool {
+        matches!(
+            tag_name,
+            "img" | "br" | "hr" | "input" | "meta" | "link" | "area" | "base" | "col" | "embed" | "source" | "track" | "wbr"
+        )
+    }
+
+    /// Whether `block` counts as the document's required top-level heading.
+    fn block_satisfies_level(block: &FirstBlock, level: usize) -> bool {
+        matches!(
+            block,
+            FirstBlock::AtxHeading(l) | FirstBlock::SetextHeading(l) | FirstBlock::HtmlHeading(l) if *l == level
+        )
+    }
+
+    /// Build the heading text to insert when the document is missing its
+    /// top-level heading. Prefer an explicit front matter title; otherwise
+    /// derive a Title-Cased name from the source filename; fall back to the
+    /// literal placeholder when neither source is available.
+    fn derived_title(&self, content: &str, file_path: Option<&str>) -> String {
+        if let Some(value) = Self::parse_front_matter_value(content) {
+            for key in &self.title_fields {
+                if let Some(text) = Self::lookup_dotted(&value, key).and_then(|v| v.as_str()) {
+                    let text = text.trim();
+                    if !text.is_empty() {
+                        return text.to_string();
+                    }
+                }
+            }
+        }
+
+        if let Some(title) = file_path.and_then(Self::title_from_filename) {
+            return title;
+        }
+
+        "Title".to_string()
+    }
+
+    /// Derive a Title-Cased heading from a file name: strip the extension,
+    /// split on `-`/`_`/space, and capitalize each word.
+    fn title_from_filename(path: &str) -> Option<String> {
+        let stem = std::path::Path::new(path).file_stem()?.to_str()?;
+        let words: Vec<String> = stem
+            .split(['-', '_', ' '])
+            .filter(|w| !w.is_empty())
+            .map(Self::title_case_word)
+            .collect();
+        if words.is_empty() {
+            return None;
+        }
+        Some(words.join(" "))
+    }
+
+    fn title_case_word(word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+            None => String::new(),
+        }
+    }
+
+    /// The `(byte offset, replacement)` insertion that adds the missing
+    /// top-level heading, or `None` when the document already has one or the
+    /// rule is skipped.
+    fn missing_heading_insertion(&self, ctx: &crate::lint_context::LintContext) -> Option<(usize, String)> {
+        if self.should_skip(ctx) {
+            return None;
         }
+        let (block, first_line_idx) = self.first_block(ctx)?;
+        if Self::block_satisfies_level(&block, self.level) {
+            return None;
+        }
+        let title = self.derived_title(ctx.content, ctx.file_path());
+        let heading = format!("{} {title}\n\n", "#".repeat(self.level));
+        Some((ctx.lines[first_line_idx].byte_offset, heading))
+    }
 
-        // Skip abbreviation definitions
+    /// Check if a (trimmed, whole-block) paragraph is a non-content token
+    /// that should be skipped. When `allowed_preamble` is enabled, also
+    /// treats a standalone link line (e.g. `[View Demo](url)`, with no other
+    /// prose) as skippable.
+    fn is_non_content_line(&self, trimmed: &str) -> bool {
+        // Skip abbreviation definitions (markdown-extra `*[HTML]: ...` style;
+        // CommonMark reference definitions never reach here as they're
+        // consumed by the parser before a Paragraph event is emitted).
         if trimmed.starts_with('*') && trimmed.contains("]: ") {
             return true;
         }
@@ -94,9 +454,28 @@ impl MD041FirstLineHeading {
             return true;
         }
 
+        if self.allowed_preamble && Self::is_link_only_line(trimmed) {
+            return true;
+        }
+
         false
     }
 
+    /// Check if a line consists only of a single markdown link and nothing
+    /// else, e.g. `[View Demo](https://example.com)`.
+    fn is_link_only_line(line: &str) -> bool {
+        if !line.starts_with('[') {
+            return false;
+        }
+        let Some(text_end) = line.find("](") else {
+            return false;
+        };
+        let Some(url_end) = line[text_end + 2..].find(')') else {
+            return false;
+        };
+        text_end + 2 + url_end + 1 == line.len()
+    }
+
     /// Check if a line consists only of badge/shield images
     /// Common patterns:
     /// - `![badge](url)`
@@ -175,46 +554,58 @@ impl MD041FirstLineHeading {
         Some(link_start + link_end + 1)
     }
 
-    /// Check if a line is an HTML heading using the centralized HTML parser
-    fn is_html_heading(ctx: &crate::lint_context::LintContext, first_line_idx: usize, level: usize) -> bool {
+    /// The level of the HTML heading that the line at `first_line_idx` opens,
+    /// if any.
+    fn html_heading_level(ctx: &crate::lint_context::LintContext, first_line_idx: usize) -> Option<usize> {
         // Check for single-line HTML heading using regex (fast path)
         let first_line_content = ctx.lines[first_line_idx].content(ctx.content);
         if let Ok(Some(captures)) = HTML_HEADING_PATTERN.captures(first_line_content.trim())
             && let Some(h_level) = captures.get(1)
-            && h_level.as_str().parse::<usize>().unwrap_or(0) == level
+            && let Ok(level) = h_level.as_str().parse::<usize>()
         {
-            return true;
+            return Some(level);
         }
 
-        // Use centralized HTML parser for multi-line headings
+        // General path: tunnel through any leading block-level wrapper tags
+        // (<div>, <header>, ...) to find a heading's opening tag, then walk
+        // forward tracking nesting depth until its matching close tag. Tag
+        // names are already lower-cased by the HTML parser, so uppercase tags
+        // are handled for free. Bails as soon as real (non-whitespace,
+        // non-wrapper) content appears before a heading is found.
         let html_tags = ctx.html_tags();
-        let target_tag = format!("h{level}");
+        let mut cursor = ctx.lines[first_line_idx].byte_offset;
+        let mut open: Option<(usize, usize)> = None; // (tag index, level)
 
-        // Find opening tag on first line
-        let opening_index = html_tags.iter().position(|tag| {
-            tag.line == first_line_idx + 1 // HtmlTag uses 1-indexed lines
-                && tag.tag_name == target_tag
-                && !tag.is_closing
-        });
+        for (idx, tag) in html_tags.iter().enumerate() {
+            if tag.byte_offset < cursor {
+                continue;
+            }
+            if !ctx.content[cursor..tag.byte_offset].trim().is_empty() {
+                break;
+            }
+            if !tag.is_closing && let Some(level) = Self::heading_tag_level(&tag.tag_name) {
+                open = Some((idx, level));
+                break;
+            }
+            if Self::is_block_wrapper_tag(&tag.tag_name) {
+                cursor = tag.byte_end;
+                continue;
+            }
+            break;
+        }
 
-        let Some(open_idx) = opening_index else {
-            return false;
-        };
+        let (open_idx, level) = open?;
 
         // Walk HTML tags to find the corresponding closing tag, allowing arbitrary nesting depth.
         // This avoids brittle line-count heuristics and handles long headings with nested content.
+        let target_tag = format!("h{level}");
         let mut depth = 1usize;
         for tag in html_tags.iter().skip(open_idx + 1) {
-            // Ignore tags that appear before the first heading line (possible when multiple tags share a line)
-            if tag.line <= first_line_idx + 1 {
-                continue;
-            }
-
             if tag.tag_name == target_tag {
                 if tag.is_closing {
                     depth -= 1;
                     if depth == 0 {
-                        return true;
+                        return Some(level);
                     }
                 } else if !tag.is_self_closing {
                     depth += 1;
@@ -222,7 +613,19 @@ impl MD041FirstLineHeading {
             }
         }
 
-        false
+        None
+    }
+
+    /// `Some(n)` when `tag_name` is `h1`..`h6`.
+    fn heading_tag_level(tag_name: &str) -> Option<usize> {
+        Self::is_heading_tag(tag_name).then(|| tag_name[1..].parse::<usize>().ok()).flatten()
+    }
+
+    /// Block-level container tags that may wrap a heading at the top of a
+    /// document (e.g. `<header><h1>Title</h1></header>`) without themselves
+    /// counting as the document's required heading content.
+    fn is_block_wrapper_tag(tag_name: &str) -> bool {
+        matches!(tag_name, "div" | "header" | "section" | "article" | "main" | "body")
     }
 }
 
@@ -243,59 +646,29 @@ impl Rule for MD041FirstLineHeading {
             return Ok(warnings);
         }
 
-        // Find the first non-blank line after front matter using cached info
-        let mut first_content_line_num = None;
-        let mut skip_lines = 0;
-
-        // Check for front matter
-        if ctx.lines.first().map(|l| l.content(ctx.content).trim()) == Some("---") {
-            // Skip front matter
-            for (idx, line_info) in ctx.lines.iter().enumerate().skip(1) {
-                if line_info.content(ctx.content).trim() == "---" {
-                    skip_lines = idx + 1;
-                    break;
-                }
-            }
-        }
-
-        for (line_num, line_info) in ctx.lines.iter().enumerate().skip(skip_lines) {
-            let line_content = line_info.content(ctx.content).trim();
-            // Skip ESM blocks in MDX files (import/export statements)
-            if line_info.in_esm_block {
-                continue;
-            }
-            // Skip HTML comments - they are non-visible and should not affect MD041
-            if line_info.in_html_comment {
-                continue;
-            }
-            if !line_content.is_empty() && !Self::is_non_content_line(line_info.content(ctx.content)) {
-                first_content_line_num = Some(line_num);
-                break;
-            }
-        }
-
-        if first_content_line_num.is_none() {
-            // No non-blank lines after front matter
+        // Find the first top-level block after front matter.
+        let Some((block, first_line_idx)) = self.first_block(ctx) else {
+            // No content blocks after front matter
             return Ok(warnings);
-        }
-
-        let first_line_idx = first_content_line_num.unwrap();
-
-        // Check if the first non-blank line is a heading of the required level
-        let first_line_info = &ctx.lines[first_line_idx];
-        let is_correct_heading = if let Some(heading) = &first_line_info.heading {
-            heading.level as usize == self.level
-        } else {
-            // Check for HTML heading (both single-line and multi-line)
-            Self::is_html_heading(ctx, first_line_idx, self.level)
         };
 
-        if !is_correct_heading {
+        // Check if that block is a heading of the required level.
+        if !Self::block_satisfies_level(&block, self.level) {
+            let first_line_info = &ctx.lines[first_line_idx];
             // Calculate precise character range for the entire first line
             let first_line = first_line_idx + 1; // Convert to 1-indexed
             let first_line_content = first_line_info.content(ctx.content);
             let (start_line, start_col, end_line, end_col) = calculate_line_range(first_line, first_line_content);
 
+            // Suggest inserting a heading whose text is derived from the front
+            // matter title or the source filename rather than a placeholder.
+            let fix = self
+                .missing_heading_insertion(ctx)
+                .map(|(offset, replacement)| crate::rule::Fix {
+                    range: offset..offset,
+                    replacement,
+                });
+
             warnings.push(LintWarning {
                 rule_name: Some(self.name().to_string()),
                 line: start_line,
@@ -304,16 +677,25 @@ impl Rule for MD041FirstLineHeading {
                 end_column: end_col,
                 message: format!("First line in file should be a level {} heading", self.level),
                 severity: Severity::Warning,
-                fix: None, // MD041 no longer provides auto-fix suggestions
+                fix,
             });
         }
         Ok(warnings)
     }
 
     fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
-        // MD041 should not auto-fix - adding content/titles is a decision that should be made by the document author
-        // This rule now only detects and warns about missing titles, but does not automatically add them
-        Ok(ctx.content.to_string())
+        // Insert a meaningful top-level heading when one is missing, taking the
+        // text from the front matter title or the source filename.
+        match self.missing_heading_insertion(ctx) {
+            Some((offset, heading)) => {
+                let mut fixed = String::with_capacity(ctx.content.len() + heading.len());
+                fixed.push_str(&ctx.content[..offset]);
+                fixed.push_str(&heading);
+                fixed.push_str(&ctx.content[offset..]);
+                Ok(fixed)
+            }
+            None => Ok(ctx.content.to_string()),
+        }
     }
 
     /// Check if this rule should be skipped
@@ -349,11 +731,24 @@ impl Rule for MD041FirstLineHeading {
 
         let use_front_matter = !md041_config.front_matter_title.is_empty();
 
-        Box::new(MD041FirstLineHeading::with_pattern(
-            md041_config.level.as_usize(),
-            use_front_matter,
-            md041_config.front_matter_title_pattern,
-        ))
+        // A single configured value may list several acceptable keys,
+        // comma-separated, each of which may be a dotted path (e.g. `seo.title`).
+        let title_fields: Vec<String> = md041_config
+            .front_matter_title
+            .split(',')
+            .map(|k| k.trim().to_string())
+            .filter(|k| !k.is_empty())
+            .collect();
+
+        Box::new(
+            MD041FirstLineHeading::with_pattern(
+                md041_config.level.as_usize(),
+                use_front_matter,
+                md041_config.front_matter_title_pattern,
+            )
+            .with_title_fields(title_fields)
+            .with_allowed_preamble(md041_config.allowed_preamble),
+        )
     }
 
     fn default_config_section(&self) -> Option<(String, toml::Value)> {
@@ -363,6 +758,7 @@ impl Rule for MD041FirstLineHeading {
                 level = 1
                 front-matter-title = "title"
                 front-matter-title-pattern = ""
+                allowed-preamble = false
             }
             .into(),
         ))
@@ -792,15 +1188,41 @@ mod tests {
     }
 
     #[test]
-    fn test_no_fix_suggestion() {
+    fn test_fix_inserts_placeholder_without_title_source() {
         let rule = MD041FirstLineHeading::default();
 
-        // Check that NO fix suggestion is provided (MD041 is now detection-only)
+        // With neither front matter nor a filename the heading falls back to
+        // the literal placeholder.
         let content = "Not a heading\n\nContent.";
         let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
         let result = rule.check(&ctx).unwrap();
         assert_eq!(result.len(), 1);
-        assert!(result[0].fix.is_none(), "MD041 should not provide fix suggestions");
+        let fix = result[0].fix.as_ref().expect("fix suggestion");
+        assert_eq!(fix.replacement, "# Title\n\n");
+        assert_eq!(rule.fix(&ctx).unwrap(), "# Title\n\nNot a heading\n\nContent.");
+    }
+
+    #[test]
+    fn test_fix_derives_heading_from_filename() {
+        let rule = MD041FirstLineHeading::default();
+
+        // With no front matter the heading is derived from the source filename.
+        let content = "Not a heading\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, Some("my-great-doc.md"));
+        let fixed = rule.fix(&ctx).unwrap();
+        assert!(fixed.starts_with("# My Great Doc\n\n"), "got: {fixed:?}");
+    }
+
+    #[test]
+    fn test_title_from_filename_title_cases_words() {
+        assert_eq!(
+            MD041FirstLineHeading::title_from_filename("docs/getting_started.md").as_deref(),
+            Some("Getting Started")
+        );
+        assert_eq!(
+            MD041FirstLineHeading::title_from_filename("my-great-doc.markdown").as_deref(),
+            Some("My Great Doc")
+        );
     }
 
     #[test]
@@ -999,6 +1421,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_html_heading_wrapped_in_header_tag() {
+        let rule = MD041FirstLineHeading::default();
+
+        let content = "<header>\n<h1>\nTitle\n</h1>\n</header>\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "HTML heading wrapped in a <header> should be recognized");
+    }
+
+    #[test]
+    fn test_html_heading_wrapped_in_nested_div_uppercase() {
+        let rule = MD041FirstLineHeading::default();
+
+        let content = "<DIV><SECTION><H1>Title</H1></SECTION></DIV>\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(
+            result.is_empty(),
+            "Uppercase heading nested in uppercase wrapper tags should be recognized"
+        );
+    }
+
+    #[test]
+    fn test_html_heading_after_self_closing_wrapper() {
+        let rule = MD041FirstLineHeading::default();
+
+        let content = "<div/>\n<h1>Title</h1>\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(
+            result.is_empty(),
+            "A self-closing wrapper tag before the heading should not block recognition"
+        );
+    }
+
+    #[test]
+    fn test_wrapper_tag_does_not_hide_missing_heading() {
+        let rule = MD041FirstLineHeading::default();
+
+        let content = "<div>\nJust some text, no heading here.\n</div>";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1, "Plain content inside a wrapper is not a heading");
+    }
+
     #[test]
     fn test_badge_images_before_heading() {
         let rule = MD041FirstLineHeading::default();
@@ -1055,6 +1523,80 @@ mod tests {
         assert_eq!(result.len(), 1, "Mixed content line should not be skipped");
     }
 
+    #[test]
+    fn test_allowed_preamble_disabled_by_default() {
+        let rule = MD041FirstLineHeading::default();
+
+        let content = "<p align=\"center\"><img src=\"logo.png\"></p>\n\n# My Project";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1, "allowed_preamble defaults to off, so the HTML block still warns");
+    }
+
+    #[test]
+    fn test_allowed_preamble_single_line_html_block() {
+        let rule = MD041FirstLineHeading::default().with_allowed_preamble(true);
+
+        let content = "<p align=\"center\"><img src=\"logo.png\"></p>\n\n# My Project";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "Centered logo block should be permitted as preamble");
+    }
+
+    #[test]
+    fn test_allowed_preamble_multi_line_html_block() {
+        let rule = MD041FirstLineHeading::default().with_allowed_preamble(true);
+
+        let content = "<div align=\"center\">\n  <img src=\"logo.png\">\n</div>\n\n# My Project";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "Multi-line wrapper block should be permitted as preamble");
+    }
+
+    #[test]
+    fn test_allowed_preamble_bare_void_element() {
+        let rule = MD041FirstLineHeading::default().with_allowed_preamble(true);
+
+        let content = "<img src=\"logo.png\">\n\n# My Project";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "A bare void element line should be permitted as preamble");
+    }
+
+    #[test]
+    fn test_allowed_preamble_link_only_line() {
+        let rule = MD041FirstLineHeading::default().with_allowed_preamble(true);
+
+        let content = "[View Demo](https://example.com)\n\n# My Project";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "A standalone link line should be permitted as preamble");
+    }
+
+    #[test]
+    fn test_allowed_preamble_does_not_swallow_heading_tag() {
+        let rule = MD041FirstLineHeading::default().with_allowed_preamble(true);
+
+        // Wrong-level HTML heading should still be reported, not treated as preamble
+        let content = "<h2>Title</h2>\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1, "An HTML heading tag must never be treated as preamble");
+    }
+
+    #[test]
+    fn test_allowed_preamble_fix_inserts_after_preamble() {
+        let rule = MD041FirstLineHeading::default().with_allowed_preamble(true);
+
+        let content = "<p align=\"center\"><img src=\"logo.png\"></p>\n\nContent without a heading.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "<p align=\"center\"><img src=\"logo.png\"></p>\n\n# Title\n\nContent without a heading."
+        );
+    }
+
     #[test]
     fn test_is_badge_image_line_unit() {
         // Unit tests for is_badge_image_line
@@ -1069,4 +1611,132 @@ mod tests {
         assert!(!MD041FirstLineHeading::is_badge_image_line("![badge](url) text"));
         assert!(!MD041FirstLineHeading::is_badge_image_line("# Heading"));
     }
+
+    #[test]
+    fn test_toml_front_matter_title_recognized() {
+        let rule = MD041FirstLineHeading::default();
+        let content = "+++\ntitle = \"My Doc\"\n+++\n\nSome content.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "TOML front matter title should suppress MD041");
+    }
+
+    #[test]
+    fn test_json_front_matter_title_recognized() {
+        let rule = MD041FirstLineHeading::default();
+        let content = "{\n\"title\": \"My Doc\"\n}\n\nSome content.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "JSON front matter title should suppress MD041");
+    }
+
+    #[test]
+    fn test_empty_title_still_warns() {
+        let rule = MD041FirstLineHeading::default();
+        // Present-but-empty title does not count as a title.
+        let content = "---\ntitle: \"\"\n---\n\nSome content.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1, "Empty title should not suppress MD041");
+    }
+
+    #[test]
+    fn test_dotted_nested_title_key() {
+        let rule = MD041FirstLineHeading::default().with_title_fields(vec!["seo.title".to_string()]);
+        let content = "---\nseo:\n  title: My Doc\n---\n\nSome content.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "Dotted nested title key should suppress MD041");
+    }
+
+    #[test]
+    fn test_multiple_accepted_title_keys() {
+        let rule = MD041FirstLineHeading::default().with_title_fields(vec![
+            "title".to_string(),
+            "headline".to_string(),
+        ]);
+        let content = "---\nheadline: My Doc\n---\n\nSome content.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty(), "Any accepted title key should suppress MD041");
+    }
+
+    #[test]
+    fn test_indented_code_block_that_looks_like_front_matter() {
+        let rule = MD041FirstLineHeading::default();
+
+        // Indented content that merely resembles a front matter fence must not
+        // be mistaken for one: it's a code block, and the required heading is
+        // still missing.
+        let content = "    ---\n    title: not front matter\n    ---\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(
+            result.len(),
+            1,
+            "An indented block that looks like front matter is actually a code block"
+        );
+        assert_eq!(result[0].line, 1);
+    }
+
+    #[test]
+    fn test_comment_block_spanning_many_lines_before_heading() {
+        let rule = MD041FirstLineHeading::default();
+
+        // A long multi-line HTML comment is a single block-level element, so
+        // it's skipped as a whole regardless of how many lines it spans.
+        let content = "<!--\nline one\nline two\nline three\nline four\n-->\n# My Document\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(
+            result.is_empty(),
+            "A comment block of any length should be transparent to MD041"
+        );
+    }
+
+    #[test]
+    fn test_reference_definition_first_document() {
+        let rule = MD041FirstLineHeading::default();
+
+        // A document that opens with link reference definitions (no block-level
+        // event is emitted for these) followed by a valid heading.
+        let content = "[ref1]: https://example.com/one\n[ref2]: https://example.com/two \"Title\"\n\n# My Document\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(
+            result.is_empty(),
+            "Reference definitions carry no block content and should be skipped"
+        );
+    }
+
+    #[test]
+    fn test_reference_definition_immediately_followed_by_setext_heading() {
+        let rule = MD041FirstLineHeading::default();
+
+        // No blank line between the reference definition and the setext
+        // heading's underline; the definition is still consumed by the parser
+        // before any paragraph/heading event is produced.
+        let content = "[ref]: https://example.com\nMy Document\n===========\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(
+            result.is_empty(),
+            "A reference definition directly preceding a setext heading should not block recognition"
+        );
+    }
+
+    #[test]
+    fn test_reference_definition_followed_by_non_heading_still_warns() {
+        let rule = MD041FirstLineHeading::default();
+
+        let content = "[ref]: https://example.com\nJust a paragraph, not a heading.\n\nContent.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(
+            result.len(),
+            1,
+            "Reference definitions should not mask a genuinely missing heading"
+        );
+        assert_eq!(result[0].line, 2, "Warning should land on the first real content line");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{ContentFeature, ContentRequirement, Fix, LintError, LintResult, LintWarning, Rule, Severity};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -89,6 +89,11 @@ impl Rule for MD045NoAltText {
         "Images should have alternate text (alt text)"
     }
 
+    fn content_requirements(&self) -> ContentRequirement {
+        // Only images can be missing alt text, so skip documents with none.
+        ContentRequirement::Any(&[ContentFeature::Images])
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
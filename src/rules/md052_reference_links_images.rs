@@ -1,4 +1,4 @@
-use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule::{ContentFeature, ContentRequirement, LintError, LintResult, LintWarning, Rule, Severity};
 use crate::utils::mkdocs_patterns::is_mkdocs_auto_reference;
 use crate::utils::range_utils::calculate_match_range;
 use crate::utils::regex_cache::{HTML_COMMENT_PATTERN, SHORTCUT_REF_REGEX};
@@ -769,6 +769,11 @@ impl Rule for MD052ReferenceLinkImages {
         "Reference links and images should use a reference that exists"
     }
 
+    fn content_requirements(&self) -> ContentRequirement {
+        // Reference definitions are only relevant when links or images exist.
+        ContentRequirement::Any(&[ContentFeature::Links, ContentFeature::Images])
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let content = ctx.content;
         let mut warnings = Vec::new();
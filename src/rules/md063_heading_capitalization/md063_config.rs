@@ -1,8 +1,8 @@
 use crate::rule_config_serde::RuleConfig;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Capitalization style for headings
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum HeadingCapStyle {
     /// Title Case - capitalize major words (default)
@@ -14,6 +14,34 @@ pub enum HeadingCapStyle {
     AllCaps,
 }
 
+/// Tolerant deserialization for the `style` key: users write the value with
+/// varied casing and separators (`"TitleCase"`, `"Title Case"`, `"title-case"`,
+/// `"SENTENCE"`), so we normalize before matching rather than requiring the
+/// strict snake_case spelling.
+impl<'de> Deserialize<'de> for HeadingCapStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        // Strip spaces/hyphens/underscores and fold case to a canonical form.
+        let normalized: String = raw
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .flat_map(|c| c.to_lowercase())
+            .collect();
+
+        match normalized.as_str() {
+            "titlecase" | "title" => Ok(HeadingCapStyle::TitleCase),
+            "sentencecase" | "sentence" => Ok(HeadingCapStyle::SentenceCase),
+            "allcaps" | "caps" | "uppercase" => Ok(HeadingCapStyle::AllCaps),
+            _ => Err(serde::de::Error::custom(format!(
+                "invalid heading capitalization style {raw:?}; expected one of \"title_case\", \"sentence_case\", \"all_caps\""
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MD063Config {
     /// Whether this rule is enabled (default: false - opt-in rule)
@@ -45,6 +73,24 @@ pub struct MD063Config {
     )]
     pub preserve_cased_words: bool,
 
+    /// Capitalize the first word after a colon/subtitle boundary in title case
+    /// (e.g. `Rust: The Complete Guide`)
+    #[serde(
+        default = "default_true",
+        rename = "capitalize-after-colon",
+        alias = "capitalize_after_colon"
+    )]
+    pub capitalize_after_colon: bool,
+
+    /// Always capitalize the first and last word in title case, even when they
+    /// are in `lowercase_words` (e.g. `Something to Look Up`)
+    #[serde(
+        default = "default_true",
+        rename = "always-capitalize-bounds",
+        alias = "always_capitalize_bounds"
+    )]
+    pub always_capitalize_bounds: bool,
+
     /// Minimum heading level to check (1-6)
     #[serde(default = "default_min_level", rename = "min-level", alias = "min_level")]
     pub min_level: u8,
@@ -69,6 +115,10 @@ fn default_preserve_cased_words() -> bool {
     true
 }
 
+fn default_true() -> bool {
+    true
+}
+
 fn default_min_level() -> u8 {
     1
 }
@@ -85,6 +135,8 @@ impl Default for MD063Config {
             lowercase_words: default_lowercase_words(),
             ignore_words: Vec::new(),
             preserve_cased_words: default_preserve_cased_words(),
+            capitalize_after_colon: true,
+            always_capitalize_bounds: true,
             min_level: default_min_level(),
             max_level: default_max_level(),
         }
@@ -159,6 +211,33 @@ mod tests {
         assert_eq!(config.style, HeadingCapStyle::AllCaps);
     }
 
+    #[test]
+    fn test_style_tolerant_parsing() {
+        // Varied casing and separators all normalize to the same variant.
+        for spelling in ["TitleCase", "Title Case", "title-case", "TITLE_CASE", "title"] {
+            let config: MD063Config = toml::from_str(&format!("style = \"{spelling}\"")).unwrap();
+            assert_eq!(config.style, HeadingCapStyle::TitleCase, "spelling: {spelling}");
+        }
+        for spelling in ["SentenceCase", "sentence case", "SENTENCE", "sentence_case"] {
+            let config: MD063Config = toml::from_str(&format!("style = \"{spelling}\"")).unwrap();
+            assert_eq!(config.style, HeadingCapStyle::SentenceCase, "spelling: {spelling}");
+        }
+        for spelling in ["AllCaps", "all caps", "ALL-CAPS", "caps"] {
+            let config: MD063Config = toml::from_str(&format!("style = \"{spelling}\"")).unwrap();
+            assert_eq!(config.style, HeadingCapStyle::AllCaps, "spelling: {spelling}");
+        }
+    }
+
+    #[test]
+    fn test_style_invalid_error_message() {
+        let err = toml::from_str::<MD063Config>("style = \"bogus\"").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("invalid heading capitalization style"), "msg: {msg}");
+        assert!(msg.contains("title_case"), "msg: {msg}");
+        assert!(msg.contains("sentence_case"), "msg: {msg}");
+        assert!(msg.contains("all_caps"), "msg: {msg}");
+    }
+
     #[test]
     fn test_style_serialization() {
         assert_eq!(
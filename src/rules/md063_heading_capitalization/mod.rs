@@ -21,6 +21,17 @@ use std::sync::LazyLock;
 mod md063_config;
 pub use md063_config::{HeadingCapStyle, MD063Config};
 
+/// Fold a word to its canonical caseless form for comparison.
+///
+/// Unlike a naive `to_lowercase`, full case folding maps German ß to `"ss"`,
+/// normalizes the Greek final sigma, and handles the Turkish dotless-i edge
+/// cases, so `"STRASSE"`, `"straße"`, and `"Straße"` all compare equal. Only
+/// used for *matching* (stop words, "already lowercase?"); rewrites use the
+/// Unicode `char::to_uppercase`/`to_lowercase` mappings to keep the real text.
+fn fold_case(word: &str) -> String {
+    caseless::default_case_fold_str(word)
+}
+
 // Regex to match inline code spans (backticks)
 static INLINE_CODE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`+[^`]+`+").unwrap());
 
@@ -51,6 +62,7 @@ enum HeadingSegment {
 pub struct MD063HeadingCapitalization {
     config: MD063Config,
     lowercase_set: HashSet<String>,
+    ignore_set: HashSet<String>,
 }
 
 impl Default for MD063HeadingCapitalization {
@@ -61,14 +73,17 @@ impl Default for MD063HeadingCapitalization {
 
 impl MD063HeadingCapitalization {
     pub fn new() -> Self {
-        let config = MD063Config::default();
-        let lowercase_set = config.lowercase_words.iter().cloned().collect();
-        Self { config, lowercase_set }
+        Self::from_config_struct(MD063Config::default())
     }
 
     pub fn from_config_struct(config: MD063Config) -> Self {
-        let lowercase_set = config.lowercase_words.iter().cloned().collect();
-        Self { config, lowercase_set }
+        let lowercase_set = config.lowercase_words.iter().map(|w| fold_case(w)).collect();
+        let ignore_set = config.ignore_words.iter().map(|w| fold_case(w)).collect();
+        Self {
+            config,
+            lowercase_set,
+            ignore_set,
+        }
     }
 
     /// Check if a word has internal capitals (like "iPhone", "macOS", "GitHub")
@@ -117,8 +132,8 @@ impl MD063HeadingCapitalization {
 
     /// Check if a word should be preserved as-is
     fn should_preserve_word(&self, word: &str) -> bool {
-        // Check ignore_words list (case-sensitive exact match)
-        if self.config.ignore_words.iter().any(|w| w == word) {
+        // Check ignore_words list (case-insensitive via folded lookup)
+        if self.ignore_set.contains(&fold_case(word)) {
             return true;
         }
 
@@ -137,11 +152,13 @@ impl MD063HeadingCapitalization {
 
     /// Check if a word is a "lowercase word" (articles, prepositions, etc.)
     fn is_lowercase_word(&self, word: &str) -> bool {
-        self.lowercase_set.contains(&word.to_lowercase())
+        self.lowercase_set.contains(&fold_case(word))
     }
 
-    /// Apply title case to a single word
-    fn title_case_word(&self, word: &str, is_first: bool, is_last: bool) -> String {
+    /// Apply title case to a single word. `force_capitalize` capitalizes the
+    /// word even when it is a stop word — used for the first/last word of a
+    /// heading and the first word after a colon (per the Chicago Manual rules).
+    fn title_case_word(&self, word: &str, force_capitalize: bool) -> String {
         if word.is_empty() {
             return word.to_string();
         }
@@ -151,8 +168,8 @@ impl MD063HeadingCapitalization {
             return word.to_string();
         }
 
-        // First and last words are always capitalized
-        if is_first || is_last {
+        // Forced positions (bounds / after a colon) are always capitalized
+        if force_capitalize {
             return self.capitalize_first(word);
         }
 
@@ -192,8 +209,9 @@ impl MD063HeadingCapitalization {
             .iter()
             .enumerate()
             .map(|(i, word)| {
-                let is_first = i == 0;
-                let is_last = i == total_words - 1;
+                let bounds = self.config.always_capitalize_bounds;
+                let force_first = bounds && i == 0;
+                let force_last = bounds && i == total_words - 1;
 
                 // Check if the ORIGINAL word should be preserved (for acronyms like "API")
                 if let Some(original_word) = original_words.get(i)
@@ -206,20 +224,23 @@ impl MD063HeadingCapitalization {
                 if word.contains('-') {
                     // Also check original for hyphenated preservation
                     if let Some(original_word) = original_words.get(i) {
-                        return self.handle_hyphenated_word_with_original(word, original_word, is_first, is_last);
+                        return self.handle_hyphenated_word_with_original(word, original_word, force_first, force_last);
                     }
-                    return self.handle_hyphenated_word(word, is_first, is_last);
+                    return self.handle_hyphenated_word(word, force_first, force_last);
                 }
 
-                self.title_case_word(word, is_first, is_last)
+                self.title_case_word(word, force_first || force_last)
             })
             .collect();
 
         result_words.join(" ")
     }
 
-    /// Handle hyphenated words like "self-documenting"
-    fn handle_hyphenated_word(&self, word: &str, is_first: bool, is_last: bool) -> String {
+    /// Handle hyphenated words like "self-documenting". Each component is
+    /// title-cased independently so interior stop words (e.g. `State-of-the-Art`)
+    /// stay lowercase; `force_first`/`force_last` capitalize the leading/trailing
+    /// component when the whole word sits in a forced position.
+    fn handle_hyphenated_word(&self, word: &str, force_first: bool, force_last: bool) -> String {
         let parts: Vec<&str> = word.split('-').collect();
         let total_parts = parts.len();
 
@@ -227,10 +248,8 @@ impl MD063HeadingCapitalization {
             .iter()
             .enumerate()
             .map(|(i, part)| {
-                // First part of first word and last part of last word get special treatment
-                let part_is_first = is_first && i == 0;
-                let part_is_last = is_last && i == total_parts - 1;
-                self.title_case_word(part, part_is_first, part_is_last)
+                let part_force = (force_first && i == 0) || (force_last && i == total_parts - 1);
+                self.title_case_word(part, part_force)
             })
             .collect();
 
@@ -242,8 +261,8 @@ impl MD063HeadingCapitalization {
         &self,
         word: &str,
         original: &str,
-        is_first: bool,
-        is_last: bool,
+        force_first: bool,
+        force_last: bool,
     ) -> String {
         let parts: Vec<&str> = word.split('-').collect();
         let original_parts: Vec<&str> = original.split('-').collect();
@@ -260,10 +279,8 @@ impl MD063HeadingCapitalization {
                     return (*original_part).to_string();
                 }
 
-                // First part of first word and last part of last word get special treatment
-                let part_is_first = is_first && i == 0;
-                let part_is_last = is_last && i == total_parts - 1;
-                self.title_case_word(part, part_is_first, part_is_last)
+                let part_force = (force_first && i == 0) || (force_last && i == total_parts - 1);
+                self.title_case_word(part, part_force)
             })
             .collect();
 
@@ -518,21 +535,27 @@ impl MD063HeadingCapitalization {
             return text.to_string();
         }
 
-        let result_words: Vec<String> = words
-            .iter()
-            .enumerate()
-            .map(|(i, word)| {
-                let is_first = is_first_segment && i == 0;
-                let is_last = is_last_segment && i == total_words - 1;
+        let bounds = self.config.always_capitalize_bounds;
+        let mut after_colon = false;
+        let mut result_words: Vec<String> = Vec::with_capacity(total_words);
+        for (i, word) in words.iter().enumerate() {
+            let is_first = is_first_segment && i == 0;
+            let is_last = is_last_segment && i == total_words - 1;
 
-                // Handle hyphenated words
-                if word.contains('-') {
-                    return self.handle_hyphenated_word(word, is_first, is_last);
-                }
+            // First/last words plus the first word after a colon are forced capitals.
+            let force_first = (bounds && is_first) || (self.config.capitalize_after_colon && after_colon);
+            let force_last = bounds && is_last;
 
-                self.title_case_word(word, is_first, is_last)
-            })
-            .collect();
+            // Track whether the next word opens a subtitle (previous word ended with ':').
+            after_colon = word.ends_with(':');
+
+            // Handle hyphenated words
+            if word.contains('-') {
+                result_words.push(self.handle_hyphenated_word(word, force_first, force_last));
+            } else {
+                result_words.push(self.title_case_word(word, force_first || force_last));
+            }
+        }
 
         // Preserve original spacing
         let mut result = String::new();
@@ -1091,6 +1114,99 @@ mod tests {
         assert_eq!(fixed, "# CVE Security and RNN Models\n");
     }
 
+    // Unicode case-folding tests
+    #[test]
+    fn test_unicode_title_case_rewrite() {
+        let rule = create_rule();
+        let ctx = |c| LintContext::new(c, crate::config::MarkdownFlavor::Standard, None);
+
+        // German ß: capitalize the first grapheme, leave the rest intact.
+        let fixed = rule.fix(&ctx("# straße markt\n")).unwrap();
+        assert_eq!(fixed, "# Straße Markt\n");
+
+        // An all-caps word (precomposed Î included) counts as an acronym and is
+        // kept as written; the other words are title-cased.
+        let fixed = rule.fix(&ctx("# ÎLE de france\n")).unwrap();
+        assert_eq!(fixed, "# ÎLE De France\n");
+    }
+
+    #[test]
+    fn test_case_folding_matches_stop_words() {
+        // A stop word spelled with ß still matches its "ss" spelling in a
+        // heading, because both fold to the same canonical form.
+        let config = MD063Config {
+            enabled: true,
+            lowercase_words: vec!["straße".to_string()],
+            ..Default::default()
+        };
+        let rule = MD063HeadingCapitalization::from_config_struct(config);
+        assert!(rule.is_lowercase_word("STRASSE"));
+        assert!(rule.is_lowercase_word("Straße"));
+    }
+
+    #[test]
+    fn test_bounds_and_after_colon_capitalization() {
+        let rule = create_rule();
+        let ctx = |c| LintContext::new(c, crate::config::MarkdownFlavor::Standard, None);
+
+        // Trailing stop word ("Up") is forced to a capital as the last word.
+        let fixed = rule.fix(&ctx("# something to look up\n")).unwrap();
+        assert_eq!(fixed, "# Something to Look Up\n");
+
+        // The first word after a colon opens a subtitle and is capitalized even
+        // though "the" is a stop word.
+        let fixed = rule.fix(&ctx("# rust: the complete guide\n")).unwrap();
+        assert_eq!(fixed, "# Rust: The Complete Guide\n");
+
+        // Hyphenated compounds title-case each component, keeping interior stop
+        // words lowercase.
+        let fixed = rule.fix(&ctx("# state-of-the-art tooling\n")).unwrap();
+        assert_eq!(fixed, "# State-of-the-Art Tooling\n");
+    }
+
+    #[test]
+    fn test_capitalization_bounds_flags_disabled() {
+        let ctx = |c| LintContext::new(c, crate::config::MarkdownFlavor::Standard, None);
+
+        let config = MD063Config {
+            enabled: true,
+            always_capitalize_bounds: false,
+            capitalize_after_colon: false,
+            ..Default::default()
+        };
+        let rule = MD063HeadingCapitalization::from_config_struct(config);
+
+        // With the bounds flag off, a trailing stop word stays lowercase and the
+        // word after a colon is not forced.
+        let fixed = rule.fix(&ctx("# something to look up\n")).unwrap();
+        assert_eq!(fixed, "# Something to Look up\n");
+
+        let fixed = rule.fix(&ctx("# rust: the complete guide\n")).unwrap();
+        assert_eq!(fixed, "# Rust: the Complete Guide\n");
+    }
+
+    #[test]
+    fn test_word_lists_are_case_insensitive() {
+        // A stop word listed with different casing still matches in a heading.
+        let config = MD063Config {
+            enabled: true,
+            lowercase_words: vec!["The".to_string()],
+            ..Default::default()
+        };
+        let rule = MD063HeadingCapitalization::from_config_struct(config);
+        assert!(rule.is_lowercase_word("the"));
+        assert!(rule.is_lowercase_word("THE"));
+
+        // ignore_words matching folds case too.
+        let config = MD063Config {
+            enabled: true,
+            ignore_words: vec!["IPHONE".to_string()],
+            ..Default::default()
+        };
+        let rule = MD063HeadingCapitalization::from_config_struct(config);
+        assert!(rule.should_preserve_word("iPhone"));
+    }
+
     #[test]
     fn test_is_all_caps_acronym() {
         let rule = create_rule();
@@ -1,7 +1,9 @@
 use crate::rule::{LintError, LintResult, LintWarning, Rule, Severity};
+use crate::rule_config_serde::RuleConfig;
 use fancy_regex::Regex as FancyRegex;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::LazyLock;
 
 /// Pattern to match footnote definitions: [^id]: content
@@ -75,11 +77,188 @@ pub fn strip_blockquote_prefix(line: &str) -> &str {
 /// [^unused]: This footnote is defined but never referenced.
 /// ```
 #[derive(Debug, Clone, Default)]
-pub struct MD066FootnoteValidation;
+pub struct MD066FootnoteValidation {
+    config: MD066Config,
+}
+
+/// Footnote dialect to validate against.
+///
+/// GitHub (GFM) and Pandoc disagree on what a valid footnote is: GFM labels may
+/// not contain whitespace and there are no inline footnotes, while Pandoc allows
+/// multi-word labels and inline `^[...]` notes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FootnoteStyle {
+    /// GitHub Flavored Markdown: whitespace labels are invalid, no inline notes.
+    #[default]
+    Gfm,
+    /// Pandoc: multi-word labels and inline `^[...]` footnotes are permitted.
+    Pandoc,
+    /// Parse under both dialects and warn only where they diverge.
+    Auto,
+}
+
+/// Configuration for MD066 (Footnote validation)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD066Config {
+    /// Footnote dialect to validate against (default: GFM).
+    #[serde(default)]
+    pub style: FootnoteStyle,
+    /// Delete footnote definitions that are never referenced
+    #[serde(default = "default_true")]
+    pub remove_unused: bool,
+    /// Append placeholder `[^id]: TODO` definitions for orphaned references
+    #[serde(default)]
+    pub stub_undefined: bool,
+    /// Permit footnote labels that contain internal whitespace (e.g.
+    /// `[^my note]`). When false (the default), such labels are reported as
+    /// invalid and not also reported as references with no definition;
+    /// duplicate definitions of such a label are still reported.
+    #[serde(default)]
+    pub allow_spaces: bool,
+    /// Renumber numeric footnotes (`[^1]`, `[^2]`, …) to match first-reference
+    /// order, rewriting every reference and the matching definition. Non-numeric
+    /// labels are left untouched.
+    #[serde(default)]
+    pub renumber: bool,
+    /// When renumbering, gather the numeric definitions into a single block at
+    /// the end of the document, ordered by their new number.
+    #[serde(default)]
+    pub collect_at_end: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for MD066Config {
+    fn default() -> Self {
+        Self {
+            style: FootnoteStyle::Gfm,
+            remove_unused: true,
+            stub_undefined: false,
+            allow_spaces: false,
+            renumber: false,
+            collect_at_end: false,
+        }
+    }
+}
+
+impl RuleConfig for MD066Config {
+    const RULE_NAME: &'static str = "MD066";
+}
 
 impl MD066FootnoteValidation {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD066Config) -> Self {
+        Self { config }
+    }
+
+    /// Renumber numeric footnotes to first-reference order, rewriting every
+    /// reference and its matching definition. Non-numeric labels, references
+    /// inside code spans/blocks/HTML (already excluded by the index), and
+    /// orphaned numeric definitions are left untouched.
+    fn renumber(ctx: &crate::lint_context::LintContext, collect_at_end: bool) -> String {
+        use std::collections::{HashMap, HashSet};
+
+        let index = ctx.footnote_index();
+
+        // Assign new numbers to numeric ids in order of first reference.
+        let mut refs_flat: Vec<(usize, usize, String)> = Vec::new();
+        for (id, occ) in &index.references {
+            if id.parse::<u64>().is_ok() {
+                for &(line, off) in occ {
+                    refs_flat.push((line, off, id.clone()));
+                }
+            }
+        }
+        refs_flat.sort();
+
+        let mut map: HashMap<String, u64> = HashMap::new();
+        let mut next = 1u64;
+        for (_, _, id) in &refs_flat {
+            map.entry(id.clone()).or_insert_with(|| {
+                let n = next;
+                next += 1;
+                n
+            });
+        }
+        if map.is_empty() {
+            return ctx.content.to_string();
+        }
+
+        let mut lines: Vec<String> = ctx.content.split('\n').map(str::to_string).collect();
+
+        // (a) Rewrite references, right-to-left per line so columns stay valid.
+        let mut by_line: HashMap<usize, Vec<(usize, String, u64)>> = HashMap::new();
+        for (line, off, id) in refs_flat {
+            if let Some(&n) = map.get(&id) {
+                let col = off - ctx.lines[line - 1].byte_offset;
+                by_line.entry(line - 1).or_default().push((col, id, n));
+            }
+        }
+        for (li, mut edits) in by_line {
+            edits.sort_by(|a, b| b.0.cmp(&a.0));
+            for (col, id, n) in edits {
+                let old = format!("[^{id}]");
+                lines[li].replace_range(col..col + old.len(), &format!("[^{n}]"));
+            }
+        }
+
+        // (b) Rewrite the matching definition label in place.
+        for (id, defs) in &index.definitions {
+            if let Some(&n) = map.get(id) {
+                for def in defs {
+                    let li = def.start_line - 1;
+                    let old = format!("[^{id}]");
+                    if let Some(pos) = lines[li].find(&old) {
+                        lines[li].replace_range(pos..pos + old.len(), &format!("[^{n}]"));
+                    }
+                }
+            }
+        }
+
+        // (c) Optionally gather the numeric definitions into one ordered block.
+        if collect_at_end {
+            let mut blocks: Vec<(u64, Vec<String>)> = Vec::new();
+            let mut remove: HashSet<usize> = HashSet::new();
+            for (id, defs) in &index.definitions {
+                if let Some(&n) = map.get(id) {
+                    for def in defs {
+                        let mut body = Vec::new();
+                        for li in (def.start_line - 1)..=(def.end_line - 1) {
+                            remove.insert(li);
+                            body.push(lines[li].clone());
+                        }
+                        blocks.push((n, body));
+                    }
+                }
+            }
+            blocks.sort_by_key(|(n, _)| *n);
+
+            let mut kept: Vec<String> = lines
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !remove.contains(i))
+                .map(|(_, l)| l.clone())
+                .collect();
+            while kept.last().is_some_and(|l| l.trim().is_empty()) {
+                kept.pop();
+            }
+            if !kept.is_empty() {
+                kept.push(String::new());
+            }
+            for (_, body) in blocks {
+                kept.extend(body);
+            }
+            lines = kept;
+        }
+
+        lines.join("\n")
     }
 }
 
@@ -96,115 +275,97 @@ impl Rule for MD066FootnoteValidation {
         let mut warnings = Vec::new();
 
         // Early exit if no footnotes at all
-        if ctx.footnote_refs.is_empty() && !ctx.content.contains("[^") {
+        if !ctx.content.contains("[^") {
             return Ok(warnings);
         }
 
-        // Collect all footnote references (id is WITHOUT the ^ prefix)
-        // Map from id -> list of (line, byte_offset) for each reference
-        // Note: pulldown-cmark only finds references when definitions exist,
-        // so we need to parse references directly to find orphaned ones
-        let mut references: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
-
-        // First, use pulldown-cmark's detected references (when definitions exist)
-        for footnote_ref in &ctx.footnote_refs {
-            // Skip if in code block, frontmatter, HTML comment, or HTML block
-            if ctx.line_info(footnote_ref.line).is_some_and(|info| {
-                info.in_code_block || info.in_front_matter || info.in_html_comment || info.in_html_block
-            }) {
-                continue;
-            }
-            references
-                .entry(footnote_ref.id.to_lowercase())
-                .or_default()
-                .push((footnote_ref.line, footnote_ref.byte_offset));
-        }
-
-        // Also parse references directly to find orphaned ones (without definitions)
-        let code_spans = ctx.code_spans();
-        for (line_idx, line_info) in ctx.lines.iter().enumerate() {
-            // Skip if in code block, frontmatter, HTML comment, or HTML block
-            if line_info.in_code_block
-                || line_info.in_front_matter
-                || line_info.in_html_comment
-                || line_info.in_html_block
-            {
-                continue;
-            }
-
-            let line = line_info.content(ctx.content);
-            let line_num = line_idx + 1; // 1-indexed
-
-            for caps in FOOTNOTE_REF_PATTERN.captures_iter(line).flatten() {
-                if let Some(id_match) = caps.get(1) {
-                    let id = id_match.as_str().to_lowercase();
-
-                    // Check if this match is inside a code span
-                    let match_start = caps.get(0).unwrap().start();
-                    let byte_offset = line_info.byte_offset + match_start;
-
-                    let in_code_span = code_spans
-                        .iter()
-                        .any(|span| byte_offset >= span.byte_offset && byte_offset < span.byte_end);
-
-                    if !in_code_span {
-                        // Only add if not already found (avoid duplicates with pulldown-cmark)
-                        references.entry(id).or_default().push((line_num, byte_offset));
+        // Consume the shared footnote index (id keys are lowercased).
+        let index = ctx.footnote_index();
+        let references = &index.references;
+        let definitions = &index.definitions;
+
+        // Whitespace-bearing labels are valid under Pandoc but not GFM. Under
+        // `auto` we only flag labels that actually diverge — i.e. that resolve
+        // to a definition under Pandoc but would be literal text on GitHub.
+        // Flagged ids are kept out of the orphan checks so they don't also
+        // surface as "no corresponding definition".
+        let pandoc_allows_spaces = self.config.allow_spaces || self.config.style == FootnoteStyle::Pandoc;
+        let mut invalid_ids: HashSet<String> = HashSet::new();
+        if !pandoc_allows_spaces {
+            for (label, line, _byte_offset) in &index.invalid_labels {
+                let id = label.to_lowercase();
+                let message = match self.config.style {
+                    FootnoteStyle::Auto => {
+                        // Only a divergence if the label resolves under Pandoc.
+                        if !definitions.contains_key(&id) {
+                            continue;
+                        }
+                        format!(
+                            "Footnote label '[^{label}]' resolves under Pandoc but is invalid on GitHub (GFM)"
+                        )
                     }
-                }
+                    _ => format!("Invalid footnote label '[^{label}]' (labels may not contain whitespace)"),
+                };
+                invalid_ids.insert(id);
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: *line,
+                    column: 1,
+                    end_line: *line,
+                    end_column: 1,
+                    message,
+                    severity: Severity::Error,
+                    fix: None,
+                });
             }
         }
 
-        // Deduplicate references (pulldown-cmark and regex might find the same ones)
-        for occurrences in references.values_mut() {
-            occurrences.sort();
-            occurrences.dedup();
-        }
-
-        // Collect footnote definitions by parsing directly from content
-        // Footnote definitions: [^id]: content (NOT in reference_defs which expects URLs)
-        // Map from id (lowercase) -> list of (line, byte_offset) for duplicate detection
-        let mut definitions: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
-        for (line_idx, line_info) in ctx.lines.iter().enumerate() {
-            // Skip if in code block, frontmatter, HTML comment, or HTML block
-            if line_info.in_code_block
-                || line_info.in_front_matter
-                || line_info.in_html_comment
-                || line_info.in_html_block
-            {
-                continue;
-            }
-
-            let line = line_info.content(ctx.content);
-            // Strip blockquote prefixes to handle definitions inside blockquotes
-            let line_stripped = strip_blockquote_prefix(line);
-
-            if let Some(caps) = FOOTNOTE_DEF_PATTERN.captures(line_stripped)
-                && let Some(id_match) = caps.get(1)
-            {
-                let id = id_match.as_str().to_lowercase();
-                let line_num = line_idx + 1; // 1-indexed
-                definitions
-                    .entry(id)
-                    .or_default()
-                    .push((line_num, line_info.byte_offset));
+        // Check for duplicate definitions
+        for def_id in &index.duplicate_ids {
+            let occurrences = &definitions[def_id];
+            // Report all duplicate definitions after the first one, spanning the
+            // full body of each repeated definition.
+            for def in &occurrences[1..] {
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: def.start_line,
+                    column: 1,
+                    end_line: def.end_line,
+                    end_column: 1,
+                    message: format!(
+                        "Duplicate footnote definition '[^{def_id}]' (first defined on line {})",
+                        occurrences[0].start_line
+                    ),
+                    severity: Severity::Error,
+                    fix: None,
+                });
             }
         }
 
-        // Check for duplicate definitions
-        for (def_id, occurrences) in &definitions {
-            if occurrences.len() > 1 {
-                // Report all duplicate definitions after the first one
-                for (line, _byte_offset) in &occurrences[1..] {
+        // Warn when the line directly below a definition opener is indented by
+        // one to three spaces: too little to be a continuation, so it silently
+        // breaks the footnote body apart.
+        for defs in definitions.values() {
+            for def in defs {
+                let next_idx = def.start_line; // 0-indexed line after the opener
+                let Some(next) = ctx.line_info(next_idx + 1) else {
+                    continue;
+                };
+                let stripped = strip_blockquote_prefix(next.content(ctx.content));
+                if stripped.trim().is_empty() || stripped.starts_with('\t') {
+                    continue;
+                }
+                let leading = stripped.len() - stripped.trim_start_matches(' ').len();
+                if (1..=3).contains(&leading) {
                     warnings.push(LintWarning {
                         rule_name: Some(self.name().to_string()),
-                        line: *line,
+                        line: next_idx + 1,
                         column: 1,
-                        end_line: *line,
+                        end_line: next_idx + 1,
                         end_column: 1,
                         message: format!(
-                            "Duplicate footnote definition '[^{def_id}]' (first defined on line {})",
-                            occurrences[0].0
+                            "Footnote definition '[^{}]' continuation is under-indented (needs at least 4 spaces)",
+                            def.id
                         ),
                         severity: Severity::Error,
                         fix: None,
@@ -214,41 +375,39 @@ impl Rule for MD066FootnoteValidation {
         }
 
         // Check for orphaned references (references without definitions)
-        let defined_ids: HashSet<&String> = definitions.keys().collect();
-        for (ref_id, occurrences) in &references {
-            if !defined_ids.contains(ref_id) {
-                // Report the first occurrence of each undefined reference
-                let (line, _byte_offset) = occurrences[0];
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name().to_string()),
-                    line,
-                    column: 1,
-                    end_line: line,
-                    end_column: 1,
-                    message: format!("Footnote reference '[^{ref_id}]' has no corresponding definition"),
-                    severity: Severity::Error,
-                    fix: None,
-                });
+        for ref_id in &index.orphan_refs {
+            // Invalid-labelled references are reported separately above.
+            if invalid_ids.contains(ref_id) {
+                continue;
             }
+            // Report the first occurrence of each undefined reference
+            let (line, _byte_offset) = references[ref_id][0];
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line,
+                column: 1,
+                end_line: line,
+                end_column: 1,
+                message: format!("Footnote reference '[^{ref_id}]' has no corresponding definition"),
+                severity: Severity::Error,
+                fix: None,
+            });
         }
 
         // Check for orphaned definitions (definitions without references)
-        let referenced_ids: HashSet<&String> = references.keys().collect();
-        for (def_id, occurrences) in &definitions {
-            if !referenced_ids.contains(def_id) {
-                // Report the first definition location
-                let (line, _byte_offset) = occurrences[0];
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name().to_string()),
-                    line,
-                    column: 1,
-                    end_line: line,
-                    end_column: 1,
-                    message: format!("Footnote definition '[^{def_id}]' is never referenced"),
-                    severity: Severity::Error,
-                    fix: None,
-                });
-            }
+        for def_id in &index.orphan_defs {
+            // Report the first definition location, spanning its full body.
+            let def = &definitions[def_id][0];
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: def.start_line,
+                column: 1,
+                end_line: def.end_line,
+                end_column: 1,
+                message: format!("Footnote definition '[^{def_id}]' is never referenced"),
+                severity: Severity::Error,
+                fix: None,
+            });
         }
 
         // Sort warnings by line number for consistent output
@@ -258,19 +417,100 @@ impl Rule for MD066FootnoteValidation {
     }
 
     fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
-        // No automatic fix - user must decide what to do with orphaned footnotes
-        Ok(ctx.content.to_string())
+        // Renumbering rewrites references and definitions wholesale, so it runs
+        // as a standalone cleanup rather than combining with removal/stubbing.
+        if self.config.renumber {
+            return Ok(Self::renumber(ctx, self.config.collect_at_end));
+        }
+
+        if !self.config.remove_unused && !self.config.stub_undefined {
+            return Ok(ctx.content.to_string());
+        }
+
+        let index = ctx.footnote_index();
+        let references = &index.references;
+        let defined_ids: HashSet<&String> = index.definitions.keys().collect();
+
+        // (a) Delete orphaned definition lines, including their continuations.
+        let mut lines_to_remove: HashSet<usize> = HashSet::new();
+        if self.config.remove_unused {
+            for def_id in &index.orphan_defs {
+                for def in &index.definitions[def_id] {
+                    // Delete the entire definition body (opener + continuations).
+                    for idx in (def.start_line - 1)..=(def.end_line - 1) {
+                        lines_to_remove.insert(idx);
+                    }
+                }
+            }
+        }
+
+        let source_lines: Vec<&str> = ctx.content.split('\n').collect();
+        let mut kept: Vec<String> = source_lines
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !lines_to_remove.contains(idx))
+            .map(|(_, line)| (*line).to_string())
+            .collect();
+
+        // (b) Append placeholder definitions for orphaned references.
+        if self.config.stub_undefined {
+            let mut undefined: Vec<(&String, bool)> = references
+                .iter()
+                .filter(|(id, _)| !defined_ids.contains(*id))
+                .map(|(id, occurrences)| {
+                    // Mirror the blockquote context of the first reference.
+                    let first_line = occurrences[0].0;
+                    let in_blockquote = ctx
+                        .line_info(first_line)
+                        .map(|info| {
+                            let content = info.content(ctx.content);
+                            strip_blockquote_prefix(content).len() != content.len()
+                        })
+                        .unwrap_or(false);
+                    (id, in_blockquote)
+                })
+                .collect();
+            undefined.sort();
+
+            if !undefined.is_empty() {
+                // Separate the stubs from preceding content with a blank line.
+                if kept.last().is_some_and(|l| !l.trim().is_empty()) {
+                    kept.push(String::new());
+                }
+                for (id, in_blockquote) in undefined {
+                    let prefix = if in_blockquote { "> " } else { "" };
+                    kept.push(format!("{prefix}[^{id}]: TODO"));
+                }
+            }
+        }
+
+        Ok(kept.join("\n"))
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
-    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        // Both removing and stubbing footnotes change document content in ways
+        // that may not match the author's intent.
+        crate::rule::FixCapability::ConditionallyFixable
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
     where
         Self: Sized,
     {
-        Box::new(MD066FootnoteValidation)
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD066Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
     }
 }
 
@@ -284,6 +524,11 @@ mod tests {
         MD066FootnoteValidation::new().check(&ctx).unwrap()
     }
 
+    fn fix_md066(content: &str, config: MD066Config) -> String {
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        MD066FootnoteValidation::from_config_struct(config).fix(&ctx).unwrap()
+    }
+
     // ==================== Valid cases ====================
 
     #[test]
@@ -746,6 +991,47 @@ Text with real footnote[^1].
         assert!(warnings[0].message.contains("Duplicate"));
     }
 
+    // ==================== Autofix ====================
+
+    #[test]
+    fn test_fix_removes_orphaned_definition() {
+        let content = "Used[^used].\n\n[^used]: Kept.\n[^unused]: Removed.\n";
+        let fixed = fix_md066(content, MD066Config::default());
+        assert!(fixed.contains("[^used]: Kept."));
+        assert!(!fixed.contains("[^unused]"));
+    }
+
+    #[test]
+    fn test_fix_removes_multiline_definition() {
+        let content = "Text.\n\n[^orphan]: First line\n    continuation line\n";
+        let fixed = fix_md066(content, MD066Config::default());
+        assert!(!fixed.contains("continuation line"));
+        assert!(!fixed.contains("[^orphan]"));
+    }
+
+    #[test]
+    fn test_fix_stubs_undefined_reference() {
+        let content = "A reference[^missing].\n";
+        let config = MD066Config {
+            remove_unused: false,
+            stub_undefined: true,
+            ..MD066Config::default()
+        };
+        let fixed = fix_md066(content, config);
+        assert!(fixed.contains("[^missing]: TODO"));
+    }
+
+    #[test]
+    fn test_fix_noop_when_both_disabled() {
+        let content = "Text[^missing].\n\n[^unused]: Orphan.\n";
+        let config = MD066Config {
+            remove_unused: false,
+            stub_undefined: false,
+            ..MD066Config::default()
+        };
+        assert_eq!(fix_md066(content, config), content);
+    }
+
     #[test]
     fn test_all_enhancement_features_together() {
         let content = r#"<!-- Comment with [^comment] -->
@@ -780,4 +1066,121 @@ Regular text[^valid] and[^missing].
             "Should find orphaned def"
         );
     }
+
+    #[test]
+    fn test_invalid_label_with_whitespace() {
+        let content = "A labelled note[^bad label] here.";
+        let warnings = check_md066(content);
+        // The malformed label is flagged once, with no spurious orphan warning.
+        assert_eq!(warnings.len(), 1, "Should flag only the invalid label: {warnings:?}");
+        assert!(
+            warnings[0].message.contains("Invalid footnote label"),
+            "Expected invalid-label message: {}",
+            warnings[0].message
+        );
+    }
+
+    fn check_with_style(content: &str, style: FootnoteStyle) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let config = MD066Config {
+            style,
+            ..MD066Config::default()
+        };
+        MD066FootnoteValidation::from_config_struct(config).check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_style_pandoc_permits_multiword_labels() {
+        let content = "A note[^my note].\n\n[^my note]: content.";
+        let warnings = check_with_style(content, FootnoteStyle::Pandoc);
+        assert!(warnings.is_empty(), "Pandoc permits multi-word labels: {warnings:?}");
+    }
+
+    #[test]
+    fn test_style_auto_flags_divergence() {
+        // Resolves under Pandoc (has a definition) but breaks on GitHub.
+        let content = "A note[^my note].\n\n[^my note]: content.";
+        let warnings = check_with_style(content, FootnoteStyle::Auto);
+        assert!(
+            warnings.iter().any(|w| w.message.contains("resolves under Pandoc")),
+            "auto should flag the divergence: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn test_style_auto_ignores_non_divergent_labels() {
+        // Orphan under both dialects (no definition) -> no divergence warning.
+        let content = "A note[^my note] with no definition.";
+        let warnings = check_with_style(content, FootnoteStyle::Auto);
+        assert!(
+            !warnings.iter().any(|w| w.message.contains("resolves under Pandoc")),
+            "auto should not warn when both dialects agree: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn test_under_indented_continuation_warns() {
+        let content = "[^a]: First line.\n  under-indented line.\n\nUsed[^a].";
+        let warnings = check_md066(content);
+        assert!(
+            warnings.iter().any(|w| w.message.contains("under-indented")),
+            "Expected an under-indentation warning: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn test_fix_removes_full_definition_block() {
+        let content = "Used[^x].\n\n[^x]: defined\n\n[^unused]: orphan first\n    orphan continuation\n";
+        let fixed = fix_md066(content, MD066Config::default());
+        assert!(
+            !fixed.contains("orphan"),
+            "Multi-line orphaned definition should be removed entirely: {fixed:?}"
+        );
+        assert!(fixed.contains("[^x]: defined"), "Referenced definition should remain");
+    }
+
+    #[test]
+    fn test_renumber_follows_first_reference_order() {
+        let content = "First[^2] and second[^1] and named[^note].\n\n[^2]: two\n[^1]: one\n[^note]: keep\n";
+        let config = MD066Config {
+            renumber: true,
+            ..MD066Config::default()
+        };
+        let fixed = fix_md066(content, config);
+        assert!(fixed.contains("First[^1] and second[^2] and named[^note]."), "refs: {fixed:?}");
+        assert!(fixed.contains("[^1]: two"), "def 2->1: {fixed:?}");
+        assert!(fixed.contains("[^2]: one"), "def 1->2: {fixed:?}");
+        assert!(fixed.contains("[^note]: keep"), "named label untouched: {fixed:?}");
+    }
+
+    #[test]
+    fn test_renumber_collect_at_end() {
+        let content = "Alpha[^1].\n\n[^1]: first def\n\nBeta[^2].\n\n[^2]: second def\n";
+        let config = MD066Config {
+            renumber: true,
+            collect_at_end: true,
+            ..MD066Config::default()
+        };
+        let fixed = fix_md066(content, config);
+        let idx1 = fixed.find("[^1]: first def").expect("def1 present");
+        let idx2 = fixed.find("[^2]: second def").expect("def2 present");
+        assert!(idx1 < idx2, "definitions collected in order: {fixed:?}");
+        // Definitions are moved below the prose referencing them.
+        assert!(fixed.find("Beta[^2].").unwrap() < idx1, "defs moved to end: {fixed:?}");
+    }
+
+    #[test]
+    fn test_allow_spaces_permits_whitespace_labels() {
+        let content = "A labelled note[^bad label] here.\n\n[^bad label]: content.";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let config = MD066Config {
+            allow_spaces: true,
+            ..MD066Config::default()
+        };
+        let warnings = MD066FootnoteValidation::from_config_struct(config).check(&ctx).unwrap();
+        assert!(
+            warnings.is_empty(),
+            "allow_spaces should permit whitespace labels: {warnings:?}"
+        );
+    }
 }
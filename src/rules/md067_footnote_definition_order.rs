@@ -33,6 +33,111 @@ impl MD067FootnoteDefinitionOrder {
     pub fn new() -> Self {
         Self
     }
+
+    /// Collect footnote reference ids in order of first appearance, skipping
+    /// code blocks/spans, front matter, HTML comments, and HTML blocks.
+    fn reference_order(ctx: &crate::lint_context::LintContext) -> Vec<String> {
+        let mut order: Vec<String> = Vec::new();
+        let mut seen: HashMap<String, ()> = HashMap::new();
+        let code_spans = ctx.code_spans();
+
+        for line_info in &ctx.lines {
+            if line_info.in_code_block
+                || line_info.in_front_matter
+                || line_info.in_html_comment
+                || line_info.in_html_block
+            {
+                continue;
+            }
+            let line = line_info.content(ctx.content);
+            for caps in FOOTNOTE_REF_PATTERN.captures_iter(line).flatten() {
+                if let Some(id_match) = caps.get(1) {
+                    let id = id_match.as_str().to_lowercase();
+                    let byte_offset = line_info.byte_offset + caps.get(0).unwrap().start();
+                    let in_code_span = code_spans
+                        .iter()
+                        .any(|span| byte_offset >= span.byte_offset && byte_offset < span.byte_end);
+                    if !in_code_span && seen.insert(id.clone(), ()).is_none() {
+                        order.push(id);
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    /// Map each purely numeric footnote id to its desired sequential number
+    /// (1-based rank among numeric ids in `reference_order`).
+    fn numeric_renumbering(reference_order: &[String]) -> Vec<(String, usize)> {
+        let mut mapping = Vec::new();
+        let mut next = 1;
+        for id in reference_order {
+            if id.parse::<usize>().is_ok() {
+                mapping.push((id.clone(), next));
+                next += 1;
+            }
+        }
+        mapping
+    }
+
+    /// Collect the absolute byte range of every `[^id]` token (references and
+    /// definitions) whose id is numeric and being renumbered, together with the
+    /// replacement number. Tokens inside code spans are skipped.
+    fn numeric_tokens(
+        ctx: &crate::lint_context::LintContext,
+        mapping: &HashMap<String, usize>,
+    ) -> Vec<(usize, usize, usize)> {
+        let mut edits = Vec::new();
+        let code_spans = ctx.code_spans();
+
+        for line_info in &ctx.lines {
+            if line_info.in_code_block
+                || line_info.in_front_matter
+                || line_info.in_html_comment
+                || line_info.in_html_block
+            {
+                continue;
+            }
+            let line = line_info.content(ctx.content);
+
+            // References (`[^id]` not followed by `:`).
+            for caps in FOOTNOTE_REF_PATTERN.captures_iter(line).flatten() {
+                if let Some(id_match) = caps.get(1) {
+                    let id = id_match.as_str().to_lowercase();
+                    if let Some(&new) = mapping.get(&id) {
+                        let token = caps.get(0).unwrap();
+                        let byte_offset = line_info.byte_offset + token.start();
+                        let in_code_span = code_spans
+                            .iter()
+                            .any(|span| byte_offset >= span.byte_offset && byte_offset < span.byte_end);
+                        if !in_code_span {
+                            edits.push((byte_offset, token.as_str().len(), new));
+                        }
+                    }
+                }
+            }
+
+            // Definition (`[^id]:`), after stripping any blockquote prefix.
+            let stripped = strip_blockquote_prefix(line);
+            if let Some(caps) = FOOTNOTE_DEF_PATTERN.captures(stripped)
+                && let Some(id_match) = caps.get(1)
+            {
+                let id = id_match.as_str().to_lowercase();
+                if let Some(&new) = mapping.get(&id)
+                    && let Some(rel) = stripped.find("[^")
+                {
+                    let prefix_len = line.len() - stripped.len();
+                    let byte_offset = line_info.byte_offset + prefix_len + rel;
+                    let token_len = id.len() + 3; // [^ + id + ]
+                    // Avoid double-counting a token already captured as a reference.
+                    if !edits.iter().any(|(s, _, _)| *s == byte_offset) {
+                        edits.push((byte_offset, token_len, new));
+                    }
+                }
+            }
+        }
+        edits
+    }
 }
 
 impl Rule for MD067FootnoteDefinitionOrder {
@@ -142,13 +247,61 @@ impl Rule for MD067FootnoteDefinitionOrder {
             // Definitions without references are handled by MD066, skip them here
         }
 
+        // Sequential numbering: purely numeric footnotes should be labelled
+        // 1, 2, 3, … in order of first reference.
+        let def_lines: HashMap<String, usize> =
+            definition_order.iter().map(|(id, line, _)| (id.clone(), *line)).collect();
+        for (id, expected) in Self::numeric_renumbering(&reference_order) {
+            if id == expected.to_string() {
+                continue;
+            }
+            let line = def_lines
+                .get(&id)
+                .copied()
+                .unwrap_or_else(|| definition_order.first().map(|d| d.1).unwrap_or(1));
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line,
+                column: 1,
+                end_line: line,
+                end_column: 1,
+                message: format!(
+                    "Numeric footnote '[^{id}]' should be renumbered to '[^{expected}]' (sequential by first reference)"
+                ),
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        warnings.sort_by_key(|w| w.line);
         Ok(warnings)
     }
 
     fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
-        // Auto-fix would require reordering definitions which is complex
-        // and could break multi-paragraph footnotes
-        Ok(ctx.content.to_string())
+        // Reordering definitions could split multi-paragraph footnotes, so the
+        // fix is limited to renumbering purely numeric footnotes in place.
+        let reference_order = Self::reference_order(ctx);
+        let mapping = Self::numeric_renumbering(&reference_order);
+        if mapping.iter().all(|(id, n)| id == &n.to_string()) {
+            return Ok(ctx.content.to_string());
+        }
+        let mapping: HashMap<String, usize> = mapping.into_iter().collect();
+
+        // Collect every `[^id]` token (references and definitions) for numeric
+        // ids as absolute byte ranges, then rewrite right-to-left.
+        let mut edits = Self::numeric_tokens(ctx, &mapping);
+        edits.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let mut content = ctx.content.to_string();
+        for (start, len, new_label) in edits {
+            content.replace_range(start..start + len, &format!("[^{new_label}]"));
+        }
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        // Only numeric footnotes are renumbered; reordering is left to the author.
+        crate::rule::FixCapability::ConditionallyFixable
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -173,6 +326,11 @@ mod tests {
         MD067FootnoteDefinitionOrder::new().check(&ctx).unwrap()
     }
 
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        MD067FootnoteDefinitionOrder::new().fix(&ctx).unwrap()
+    }
+
     #[test]
     fn test_correct_order() {
         let content = r#"Text with [^1] and [^2].
@@ -282,6 +440,31 @@ mod tests {
         assert!(!warnings.is_empty());
     }
 
+    #[test]
+    fn test_numeric_out_of_order_warns() {
+        let content = "Text with [^2] and [^1].\n\n[^2]: Two\n[^1]: One\n";
+        let warnings = check(content);
+        assert!(
+            warnings.iter().any(|w| w.message.contains("renumbered")),
+            "Expected a renumbering warning: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn test_numeric_renumber_fix() {
+        let content = "Text with [^2] and [^1].\n\n[^2]: Two\n[^1]: One\n";
+        let fixed = fix(content);
+        // First-referenced footnote becomes [^1]; the second becomes [^2].
+        assert_eq!(fixed, "Text with [^1] and [^2].\n\n[^1]: Two\n[^2]: One\n");
+    }
+
+    #[test]
+    fn test_named_footnotes_not_renumbered() {
+        let content = "See [^beta] and [^alpha].\n\n[^beta]: B\n[^alpha]: A\n";
+        let fixed = fix(content);
+        assert_eq!(fixed, content, "Named footnotes must not be renumbered");
+    }
+
     #[test]
     fn test_blockquote_definitions() {
         let content = r#"Text with [^1] and [^2].
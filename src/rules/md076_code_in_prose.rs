@@ -0,0 +1,255 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD076 (Code-like identifiers in prose should be wrapped in backticks)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD076Config {
+    /// Identifiers that look code-like but should be left alone (e.g. brand names)
+    #[serde(default)]
+    pub allowed: Vec<String>,
+}
+
+impl Default for MD076Config {
+    fn default() -> Self {
+        Self { allowed: Vec::new() }
+    }
+}
+
+impl RuleConfig for MD076Config {
+    const RULE_NAME: &'static str = "MD076";
+}
+
+/// Rule MD076: Code-like identifiers in prose should be wrapped in backticks
+///
+/// See [docs/md076.md](../../docs/md076.md) for full documentation, configuration, and examples.
+///
+/// Modelled on Clippy's `doc_markdown` lint: an identifier in running prose that carries
+/// obvious code syntax — a path separator (`std::fmt`), an internal underscore
+/// (`max_width`), a camel-case hump (`LintContext`), or a trailing call (`collect()`) —
+/// almost always refers to code and reads better as an inline code span.
+#[derive(Clone)]
+pub struct MD076CodeInProse {
+    config: MD076Config,
+}
+
+impl MD076CodeInProse {
+    pub fn new(allowed: Vec<String>) -> Self {
+        Self {
+            config: MD076Config { allowed },
+        }
+    }
+
+    pub fn from_config_struct(config: MD076Config) -> Self {
+        Self { config }
+    }
+
+    /// Return true if `word` carries obvious code syntax.
+    fn looks_code_like(word: &str) -> bool {
+        if word.contains("::") {
+            return true;
+        }
+        if word.ends_with("()") {
+            return true;
+        }
+        // Internal underscore flanked by alphanumerics (snake_case).
+        let bytes = word.as_bytes();
+        for (i, &b) in bytes.iter().enumerate() {
+            if b == b'_' && i > 0 && i + 1 < bytes.len() {
+                let prev = bytes[i - 1];
+                let next = bytes[i + 1];
+                if prev.is_ascii_alphanumeric() && next.is_ascii_alphanumeric() {
+                    return true;
+                }
+            }
+        }
+        // camelCase / PascalCase hump: a lowercase letter immediately followed by an uppercase.
+        let mut chars = word.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c.is_ascii_lowercase()
+                && chars.peek().is_some_and(|n| n.is_ascii_uppercase())
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Strip surrounding punctuation so `collect(),` matches `collect()`.
+    fn trim_word(word: &str) -> &str {
+        word.trim_matches(|c: char| !c.is_alphanumeric() && c != '_' && c != ':' && c != '(' && c != ')')
+    }
+}
+
+impl Default for MD076CodeInProse {
+    fn default() -> Self {
+        Self::from_config_struct(MD076Config::default())
+    }
+}
+
+impl Rule for MD076CodeInProse {
+    fn name(&self) -> &'static str {
+        "MD076"
+    }
+
+    fn description(&self) -> &'static str {
+        "Code-like identifiers in prose should be wrapped in backticks"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        crate::rule::FixCapability::FullyFixable
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD076Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        for line_info in ctx.lines.iter() {
+            if line_info.in_code_block {
+                continue;
+            }
+            let line = &line_info.content;
+            let line_start = line_info.byte_offset;
+
+            let mut offset = 0;
+            for word in line.split(' ') {
+                let word_start = offset;
+                offset += word.len() + 1; // account for the space separator
+
+                let trimmed = Self::trim_word(word);
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if self.config.allowed.iter().any(|a| a == trimmed) {
+                    continue;
+                }
+                if !Self::looks_code_like(trimmed) {
+                    continue;
+                }
+
+                // Compute the byte span of the trimmed word inside the document.
+                let rel = word_start + (trimmed.as_ptr() as usize - word.as_ptr() as usize);
+                let byte_pos = line_start + rel;
+                if ctx.is_in_code_block_or_span(byte_pos) {
+                    continue;
+                }
+
+                let (line_num, col) = ctx.offset_to_line_col(byte_pos);
+                warnings.push(LintWarning {
+                    line: line_num,
+                    column: col,
+                    end_line: line_num,
+                    end_column: col + trimmed.chars().count(),
+                    message: format!("'{trimmed}' looks like code and should be wrapped in backticks"),
+                    severity: Severity::Warning,
+                    fix: Some(crate::rule::Fix {
+                        range: byte_pos..byte_pos + trimmed.len(),
+                        replacement: format!("`{trimmed}`"),
+                    }),
+                    rule_name: Some(self.name().to_string()),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+
+        // Apply fixes right-to-left so earlier byte offsets stay valid.
+        let mut content = ctx.content.to_string();
+        let mut fixes: Vec<_> = warnings.into_iter().filter_map(|w| w.fix).collect();
+        fixes.sort_by(|a, b| b.range.start.cmp(&a.range.start));
+        for fix in fixes {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn warnings(content: &str) -> Vec<LintWarning> {
+        let rule = MD076CodeInProse::default();
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_flags_snake_case() {
+        let result = warnings("Set the max_width option.");
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("max_width"));
+    }
+
+    #[test]
+    fn test_flags_path_and_camel_case() {
+        assert_eq!(warnings("Use std::fmt here.").len(), 1);
+        assert_eq!(warnings("The LintContext struct.").len(), 1);
+        assert_eq!(warnings("Call collect() on it.").len(), 1);
+    }
+
+    #[test]
+    fn test_ignores_plain_prose() {
+        assert!(warnings("This is a perfectly ordinary sentence.").is_empty());
+    }
+
+    #[test]
+    fn test_ignores_existing_code_span() {
+        assert!(warnings("Set the `max_width` option.").is_empty());
+    }
+
+    #[test]
+    fn test_ignores_code_block() {
+        let content = "```rust\nlet max_width = 80;\n```\n";
+        assert!(warnings(content).is_empty());
+    }
+
+    #[test]
+    fn test_allowed_list() {
+        let rule = MD076CodeInProse::new(vec!["PyPI".to_string()]);
+        let ctx = LintContext::new("Publish to PyPI today.", MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_fix_wraps_identifier() {
+        let rule = MD076CodeInProse::default();
+        let ctx = LintContext::new("Set the max_width option.", MarkdownFlavor::Standard, None);
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "Set the `max_width` option.");
+    }
+}
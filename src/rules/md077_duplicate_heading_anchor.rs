@@ -0,0 +1,166 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
+use crate::utils::range_utils::calculate_match_range;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for MD077 (Heading anchors should be unique)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD077Config {
+    /// Slug generation style used to derive anchors
+    #[serde(default)]
+    pub style: AnchorStyle,
+}
+
+impl RuleConfig for MD077Config {
+    const RULE_NAME: &'static str = "MD077";
+}
+
+/// Rule MD077: Heading anchors should be unique
+///
+/// See [docs/md077.md](../../docs/md077.md) for full documentation, configuration, and examples.
+///
+/// Two headings can share text after slugification even when the text differs (for example
+/// `Set up` and `Set Up`), producing duplicate anchors. Renderers such as GitHub and mdBook
+/// silently disambiguate these with a numeric suffix, which breaks any `#set-up` link that was
+/// meant for the first heading. This rule flags every heading whose generated anchor collides
+/// with an earlier one under the configured slug style.
+#[derive(Clone, Default)]
+pub struct MD077DuplicateHeadingAnchor {
+    config: MD077Config,
+}
+
+impl MD077DuplicateHeadingAnchor {
+    pub fn from_config_struct(config: MD077Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD077DuplicateHeadingAnchor {
+    fn name(&self) -> &'static str {
+        "MD077"
+    }
+
+    fn description(&self) -> &'static str {
+        "Heading anchors should be unique"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        crate::rule::FixCapability::Unfixable
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD077Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        // Map of anchor slug -> number of times already seen.
+        let mut seen: HashMap<String, usize> = HashMap::new();
+
+        for (line_num, line_info) in ctx.lines.iter().enumerate() {
+            let Some(heading) = &line_info.heading else {
+                continue;
+            };
+            if heading.text.is_empty() {
+                continue;
+            }
+
+            // An explicit custom id wins over the derived slug.
+            let anchor = match &heading.custom_id {
+                Some(id) => id.clone(),
+                None => self.config.style.generate_fragment(&heading.text),
+            };
+            if anchor.is_empty() {
+                continue;
+            }
+
+            let count = seen.entry(anchor.clone()).or_insert(0);
+            if *count > 0 {
+                let text_start = line_info.content.find(&heading.text).unwrap_or(0);
+                let (start_line, start_col, end_line, end_col) =
+                    calculate_match_range(line_num + 1, &line_info.content, text_start, heading.text.len());
+                warnings.push(LintWarning {
+                    line: start_line,
+                    column: start_col,
+                    end_line,
+                    end_column: end_col,
+                    message: format!("Duplicate heading anchor '{anchor}' (collides with an earlier heading)"),
+                    severity: Severity::Warning,
+                    fix: None,
+                    rule_name: Some(self.name().to_string()),
+                });
+            }
+            *count += 1;
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Resolving an anchor collision requires renaming a heading or adding a
+        // custom id, both of which need human judgement.
+        Ok(ctx.content.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn warnings(content: &str) -> Vec<LintWarning> {
+        let rule = MD077DuplicateHeadingAnchor::default();
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_unique_headings_ok() {
+        assert!(warnings("# Setup\n\n# Usage\n").is_empty());
+    }
+
+    #[test]
+    fn test_case_only_difference_collides() {
+        // "Set up" and "Set Up" both slugify to "set-up" on GitHub.
+        let result = warnings("# Set up\n\n## Set Up\n");
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("set-up"));
+    }
+
+    #[test]
+    fn test_exact_duplicate_collides() {
+        let result = warnings("# Notes\n\n## Notes\n");
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn test_custom_id_avoids_collision() {
+        let result = warnings("# Notes\n\n## Notes {#other-notes}\n");
+        assert!(result.is_empty());
+    }
+}
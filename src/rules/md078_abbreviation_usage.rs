@@ -0,0 +1,309 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for MD078 (Abbreviation definitions should be used and unique)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD078Config {
+    /// Match abbreviation labels without regard to case
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// Paths to external glossary files holding shared `*[LABEL]: expansion` definitions
+    #[serde(default)]
+    pub glossaries: Vec<String>,
+}
+
+impl RuleConfig for MD078Config {
+    const RULE_NAME: &'static str = "MD078";
+}
+
+/// A parsed abbreviation definition (`*[ABBR]: expansion`).
+struct AbbrDef {
+    label: String,
+    expansion: String,
+    line: usize,
+    /// Column (1-based) of the `*` marker.
+    column: usize,
+}
+
+/// Rule MD078: Abbreviation definitions should be used and unique
+///
+/// See [docs/md078.md](../../docs/md078.md) for full documentation, configuration, and examples.
+///
+/// PHP Markdown Extra abbreviation definitions take the form `*[HTML]: HyperText Markup Language`
+/// and attach a tooltip to every later occurrence of the label. This rule flags three mistakes in
+/// that workflow: an abbreviation that is defined but never referenced in the prose, a definition
+/// with an empty expansion, and the same label defined more than once.
+#[derive(Clone, Default)]
+pub struct MD078AbbreviationUsage {
+    config: MD078Config,
+}
+
+impl MD078AbbreviationUsage {
+    pub fn from_config_struct(config: MD078Config) -> Self {
+        Self { config }
+    }
+
+    /// Parse an `*[LABEL]: expansion` definition from a line, if present.
+    fn parse_definition(line: &str) -> Option<(String, String)> {
+        let rest = line.trim_start().strip_prefix("*[")?;
+        let close = rest.find(']')?;
+        let label = rest[..close].trim().to_string();
+        let after = rest[close + 1..].trim_start();
+        let expansion = after.strip_prefix(':')?.trim().to_string();
+        if label.is_empty() {
+            return None;
+        }
+        Some((label, expansion))
+    }
+
+    /// Count whole-word occurrences of `label` in `haystack`. When
+    /// `case_insensitive` is set, both sides are lowercased first.
+    fn count_occurrences(haystack: &str, label: &str, case_insensitive: bool) -> usize {
+        if label.is_empty() {
+            return 0;
+        }
+        if case_insensitive {
+            return Self::count_occurrences(&haystack.to_lowercase(), &label.to_lowercase(), false);
+        }
+        let mut count = 0;
+        let bytes = haystack.as_bytes();
+        let mut search_from = 0;
+        while let Some(pos) = haystack[search_from..].find(label) {
+            let start = search_from + pos;
+            let end = start + label.len();
+            let before_ok = start == 0 || !bytes[start - 1].is_ascii_alphanumeric();
+            let after_ok = end >= bytes.len() || !bytes[end].is_ascii_alphanumeric();
+            if before_ok && after_ok {
+                count += 1;
+            }
+            search_from = start + label.len();
+        }
+        count
+    }
+
+    /// Key a label for the duplicate/usage maps, folding case when configured.
+    fn key(&self, label: &str) -> String {
+        if self.config.case_insensitive {
+            label.to_lowercase()
+        } else {
+            label.to_string()
+        }
+    }
+
+    /// Read `*[LABEL]: expansion` definitions out of the configured glossary files.
+    /// Labels from glossaries count as defined so that in-document usage of a shared
+    /// abbreviation is not reported as undefined.
+    fn glossary_labels(&self) -> Vec<String> {
+        let mut labels = Vec::new();
+        for path in &self.config.glossaries {
+            let Ok(text) = std::fs::read_to_string(path) else {
+                continue;
+            };
+            for line in text.lines() {
+                if let Some((label, _)) = Self::parse_definition(line) {
+                    labels.push(label);
+                }
+            }
+        }
+        labels
+    }
+}
+
+impl Rule for MD078AbbreviationUsage {
+    fn name(&self) -> &'static str {
+        "MD078"
+    }
+
+    fn description(&self) -> &'static str {
+        "Abbreviation definitions should be used and unique"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        crate::rule::FixCapability::Unfixable
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD078Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        // Collect all definitions (skipping code blocks).
+        let mut defs: Vec<AbbrDef> = Vec::new();
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block || line_info.in_front_matter {
+                continue;
+            }
+            let line_num = idx + 1;
+            if let Some((label, expansion)) = Self::parse_definition(&line_info.content) {
+                let column = line_info.content.find("*[").map(|c| c + 1).unwrap_or(1);
+                defs.push(AbbrDef {
+                    label,
+                    expansion,
+                    line: line_num,
+                    column,
+                });
+            }
+        }
+
+        if defs.is_empty() {
+            return Ok(warnings);
+        }
+
+        // Body text = all non-definition, non-code lines concatenated.
+        let mut body = String::new();
+        for line_info in ctx.lines.iter() {
+            if line_info.in_code_block || line_info.in_front_matter {
+                continue;
+            }
+            if Self::parse_definition(&line_info.content).is_some() {
+                continue;
+            }
+            body.push_str(&line_info.content);
+            body.push('\n');
+        }
+
+        // Seed the seen-count with glossary labels so that a local definition of a
+        // label already present in a glossary is reported as a duplicate.
+        let mut reported_duplicates: HashMap<String, usize> = HashMap::new();
+        for label in self.glossary_labels() {
+            *reported_duplicates.entry(self.key(&label)).or_insert(0) += 1;
+        }
+
+        for def in &defs {
+            // Duplicate definition: report every definition after the first.
+            let seen = reported_duplicates.entry(self.key(&def.label)).or_insert(0);
+            *seen += 1;
+            if *seen > 1 {
+                warnings.push(LintWarning {
+                    line: def.line,
+                    column: def.column,
+                    end_line: def.line,
+                    end_column: def.column + def.label.len() + 3,
+                    message: format!("Abbreviation '{}' is defined more than once", def.label),
+                    severity: Severity::Warning,
+                    fix: None,
+                    rule_name: Some(self.name().to_string()),
+                });
+                continue;
+            }
+
+            if def.expansion.is_empty() {
+                warnings.push(LintWarning {
+                    line: def.line,
+                    column: def.column,
+                    end_line: def.line,
+                    end_column: def.column + def.label.len() + 3,
+                    message: format!("Abbreviation '{}' has an empty expansion", def.label),
+                    severity: Severity::Warning,
+                    fix: None,
+                    rule_name: Some(self.name().to_string()),
+                });
+            }
+
+            if Self::count_occurrences(&body, &def.label, self.config.case_insensitive) == 0 {
+                warnings.push(LintWarning {
+                    line: def.line,
+                    column: def.column,
+                    end_line: def.line,
+                    end_column: def.column + def.label.len() + 3,
+                    message: format!("Abbreviation '{}' is defined but never used", def.label),
+                    severity: Severity::Warning,
+                    fix: None,
+                    rule_name: Some(self.name().to_string()),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn warnings(content: &str) -> Vec<LintWarning> {
+        let rule = MD078AbbreviationUsage::default();
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_used_definition_ok() {
+        assert!(warnings("The HTML spec is long.\n\n*[HTML]: HyperText Markup Language\n").is_empty());
+    }
+
+    #[test]
+    fn test_unused_definition() {
+        let result = warnings("Plain prose here.\n\n*[HTML]: HyperText Markup Language\n");
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("never used"));
+    }
+
+    #[test]
+    fn test_empty_expansion() {
+        let result = warnings("Uses HTML.\n\n*[HTML]:\n");
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("empty expansion"));
+    }
+
+    #[test]
+    fn test_duplicate_definition() {
+        let result = warnings("Uses HTML.\n\n*[HTML]: One\n*[HTML]: Two\n");
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("more than once"));
+    }
+
+    #[test]
+    fn test_no_definitions() {
+        assert!(warnings("Just some prose about HTML.\n").is_empty());
+    }
+
+    #[test]
+    fn test_case_insensitive_usage() {
+        // "html" in prose matches the "HTML" definition only when case-insensitive.
+        let content = "We love html.\n\n*[HTML]: HyperText Markup Language\n";
+
+        let sensitive = MD078AbbreviationUsage::default();
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(sensitive.check(&ctx).unwrap().len(), 1);
+
+        let insensitive = MD078AbbreviationUsage::from_config_struct(MD078Config {
+            case_insensitive: true,
+            glossaries: Vec::new(),
+        });
+        assert!(insensitive.check(&ctx).unwrap().is_empty());
+    }
+}
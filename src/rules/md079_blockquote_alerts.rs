@@ -0,0 +1,290 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+lazy_static! {
+    /// A callout marker occupying a blockquote line on its own: `[!NOTE]`.
+    static ref ALERT_MARKER: Regex = Regex::new(r"^\[!([A-Za-z]+)\]\s*$").unwrap();
+}
+
+fn default_types() -> Vec<String> {
+    ["NOTE", "TIP", "IMPORTANT", "WARNING", "CAUTION"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Configuration for MD079 (Blockquote alert callouts)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD079Config {
+    /// Accepted callout keywords (compared case-insensitively; the configured
+    /// spelling is the canonical casing the fix normalizes to).
+    #[serde(default = "default_types")]
+    pub types: Vec<String>,
+}
+
+impl Default for MD079Config {
+    fn default() -> Self {
+        Self { types: default_types() }
+    }
+}
+
+impl RuleConfig for MD079Config {
+    const RULE_NAME: &'static str = "MD079";
+}
+
+/// Rule MD079: GitHub/Obsidian-style alert callouts in blockquotes
+///
+/// See [docs/md079.md](../../docs/md079.md) for full documentation, configuration, and examples.
+///
+/// Validates blockquote callouts whose first line is a bare marker such as
+/// `> [!NOTE]`. It flags unknown keywords, wrong casing, a marker that is not on
+/// the first line of the blockquote, and an empty callout body. The keyword set
+/// is configurable so teams can adopt their own vocabulary.
+#[derive(Clone)]
+pub struct MD079BlockquoteAlerts {
+    config: MD079Config,
+}
+
+impl MD079BlockquoteAlerts {
+    pub fn from_config_struct(config: MD079Config) -> Self {
+        Self { config }
+    }
+
+    /// Return the canonical spelling for `keyword` if it is accepted.
+    fn canonical<'a>(&'a self, keyword: &str) -> Option<&'a str> {
+        self.config
+            .types
+            .iter()
+            .find(|t| t.eq_ignore_ascii_case(keyword))
+            .map(String::as_str)
+    }
+
+    fn check_group(&self, ctx: &LintContext, start: usize, end: usize, warnings: &mut Vec<LintWarning>) {
+        let first = &ctx.lines[start];
+        let Some(bq) = &first.blockquote else { return };
+        let content = bq.content.trim();
+
+        let Some(caps) = ALERT_MARKER.captures(content) else {
+            // The first line is not a marker: flag a marker hiding further down,
+            // which GitHub will not render as a callout.
+            for idx in (start + 1)..=end {
+                if let Some(info) = &ctx.lines[idx].blockquote
+                    && ALERT_MARKER.is_match(info.content.trim())
+                {
+                    warnings.push(LintWarning {
+                        line: idx + 1,
+                        column: 1,
+                        end_line: idx + 1,
+                        end_column: 1,
+                        message: "Alert callout marker must be on the first line of the blockquote".to_string(),
+                        severity: Severity::Warning,
+                        fix: None,
+                        rule_name: Some("MD079".to_string()),
+                    });
+                    break;
+                }
+            }
+            return;
+        };
+
+        let keyword = caps.get(1).unwrap().as_str();
+        let (line_num, _) = ctx.offset_to_line_col(first.byte_offset);
+
+        match self.canonical(keyword) {
+            None => {
+                warnings.push(LintWarning {
+                    line: line_num,
+                    column: 1,
+                    end_line: line_num,
+                    end_column: 1,
+                    message: format!("Unknown alert callout type '[!{keyword}]'"),
+                    severity: Severity::Warning,
+                    fix: None,
+                    rule_name: Some("MD079".to_string()),
+                });
+            }
+            Some(canonical) if canonical != keyword => {
+                // Normalize the keyword casing.
+                let marker_pos = first.content.find("[!").unwrap_or(0);
+                let byte_start = first.byte_offset + marker_pos;
+                let old = format!("[!{keyword}]");
+                warnings.push(LintWarning {
+                    line: line_num,
+                    column: marker_pos + 1,
+                    end_line: line_num,
+                    end_column: marker_pos + 1 + old.chars().count(),
+                    message: format!("Alert callout '[!{keyword}]' should be '[!{canonical}]'"),
+                    severity: Severity::Warning,
+                    fix: Some(crate::rule::Fix {
+                        range: byte_start..byte_start + old.len(),
+                        replacement: format!("[!{canonical}]"),
+                    }),
+                    rule_name: Some("MD079".to_string()),
+                });
+            }
+            Some(_) => {}
+        }
+
+        // An accepted callout with no body below the marker renders empty.
+        let has_body = (start + 1..=end).any(|idx| {
+            ctx.lines[idx]
+                .blockquote
+                .as_ref()
+                .is_some_and(|info| !info.content.trim().is_empty())
+        });
+        if self.canonical(keyword).is_some() && !has_body {
+            warnings.push(LintWarning {
+                line: line_num,
+                column: 1,
+                end_line: line_num,
+                end_column: 1,
+                message: format!("Alert callout '[!{keyword}]' has an empty body"),
+                severity: Severity::Warning,
+                fix: None,
+                rule_name: Some("MD079".to_string()),
+            });
+        }
+    }
+}
+
+impl Default for MD079BlockquoteAlerts {
+    fn default() -> Self {
+        Self::from_config_struct(MD079Config::default())
+    }
+}
+
+impl Rule for MD079BlockquoteAlerts {
+    fn name(&self) -> &'static str {
+        "MD079"
+    }
+
+    fn description(&self) -> &'static str {
+        "Blockquote alert callouts should use a known keyword and non-empty body"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Blockquote
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        crate::rule::FixCapability::FullyFixable
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD079Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        let mut i = 0;
+        while i < ctx.lines.len() {
+            if ctx.lines[i].blockquote.is_none() {
+                i += 1;
+                continue;
+            }
+            // Extend over the consecutive lines of this blockquote.
+            let start = i;
+            let mut end = i;
+            while end + 1 < ctx.lines.len() && ctx.lines[end + 1].blockquote.is_some() {
+                end += 1;
+            }
+            self.check_group(ctx, start, end, &mut warnings);
+            i = end + 1;
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        let mut fixes: Vec<_> = warnings.into_iter().filter_map(|w| w.fix).collect();
+        if fixes.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+
+        // Apply fixes right-to-left so earlier byte offsets stay valid.
+        let mut content = ctx.content.to_string();
+        fixes.sort_by(|a, b| b.range.start.cmp(&a.range.start));
+        for fix in fixes {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn warnings(content: &str) -> Vec<LintWarning> {
+        let rule = MD079BlockquoteAlerts::default();
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_valid_callout() {
+        assert!(warnings("> [!NOTE]\n> Helpful information.\n").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_keyword() {
+        let result = warnings("> [!INFO]\n> Body.\n");
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("Unknown alert callout type"));
+    }
+
+    #[test]
+    fn test_wrong_casing_is_fixed() {
+        let rule = MD079BlockquoteAlerts::default();
+        let ctx = LintContext::new("> [!note]\n> Body.\n", MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(rule.fix(&ctx).unwrap(), "> [!NOTE]\n> Body.\n");
+    }
+
+    #[test]
+    fn test_marker_not_on_first_line() {
+        let result = warnings("> Some intro.\n> [!WARNING]\n> Body.\n");
+        assert!(result.iter().any(|w| w.message.contains("first line")));
+    }
+
+    #[test]
+    fn test_empty_body() {
+        let result = warnings("> [!TIP]\n");
+        assert!(result.iter().any(|w| w.message.contains("empty body")));
+    }
+
+    #[test]
+    fn test_custom_vocabulary() {
+        let rule = MD079BlockquoteAlerts::from_config_struct(MD079Config {
+            types: vec!["INFO".to_string()],
+        });
+        let ctx = LintContext::new("> [!INFO]\n> Body.\n", MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::line_ending::{LineEnding, detect_line_ending, get_line_ending_str, normalize_line_ending};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// The line ending style MD080 should enforce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndingTarget {
+    /// Adopt whichever ending is already dominant in the file.
+    #[default]
+    Auto,
+    Lf,
+    Crlf,
+    Cr,
+}
+
+/// Configuration for MD080 (Consistent line endings)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD080Config {
+    /// The line ending style to enforce (default: "auto")
+    #[serde(default)]
+    pub target: LineEndingTarget,
+}
+
+impl RuleConfig for MD080Config {
+    const RULE_NAME: &'static str = "MD080";
+}
+
+/// Rule MD080: Consistent line endings
+///
+/// See [docs/md080.md](../../docs/md080.md) for full documentation, configuration, and examples.
+///
+/// File content is normalized to LF before any rule runs (see
+/// [`crate::utils::line_ending`]), so by the time [`LintContext`] is built the original `\r\n`/`\r`
+/// terminators are already gone. This rule recovers them by re-reading the file straight off disk
+/// via [`LintContext::file_path`], the same approach [`crate::rules::MD078AbbreviationUsage`] uses
+/// to pull in external glossary files. Content passed in without a backing file path (e.g. piped
+/// through stdin) has no raw terminators left to inspect, so the rule has nothing to check.
+#[derive(Clone, Default)]
+pub struct MD080ConsistentLineEndings {
+    config: MD080Config,
+}
+
+impl MD080ConsistentLineEndings {
+    pub fn from_config_struct(config: MD080Config) -> Self {
+        Self { config }
+    }
+
+    /// Locate every line terminator in `raw`, returning its byte range and style.
+    fn raw_line_endings(raw: &str) -> Vec<(Range<usize>, LineEnding)> {
+        let bytes = raw.as_bytes();
+        let mut endings = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                    endings.push((i..i + 2, LineEnding::Crlf));
+                    i += 2;
+                }
+                b'\r' => {
+                    endings.push((i..i + 1, LineEnding::Cr));
+                    i += 1;
+                }
+                b'\n' => {
+                    endings.push((i..i + 1, LineEnding::Lf));
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+        endings
+    }
+
+    /// The dominant ending in `raw`, reusing the same count-based tie-break as
+    /// [`detect_line_ending`].
+    fn dominant_ending(raw: &str) -> LineEnding {
+        match detect_line_ending(raw) {
+            "\r\n" => LineEnding::Crlf,
+            "\r" => LineEnding::Cr,
+            _ => LineEnding::Lf,
+        }
+    }
+
+    fn resolved_target(&self, raw: &str) -> LineEnding {
+        match self.config.target {
+            LineEndingTarget::Auto => Self::dominant_ending(raw),
+            LineEndingTarget::Lf => LineEnding::Lf,
+            LineEndingTarget::Crlf => LineEnding::Crlf,
+            LineEndingTarget::Cr => LineEnding::Cr,
+        }
+    }
+
+    fn ending_name(ending: LineEnding) -> &'static str {
+        match ending {
+            LineEnding::Lf => "LF",
+            LineEnding::Crlf => "CRLF",
+            LineEnding::Cr => "CR",
+            LineEnding::Mixed => "mixed",
+        }
+    }
+}
+
+impl Rule for MD080ConsistentLineEndings {
+    fn name(&self) -> &'static str {
+        "MD080"
+    }
+
+    fn description(&self) -> &'static str {
+        "Line endings should be consistent"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        crate::rule::FixCapability::ConditionallyFixable
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD080Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        let Some(path) = ctx.file_path() else {
+            return Ok(warnings);
+        };
+        let Ok(raw) = std::fs::read_to_string(path) else {
+            return Ok(warnings);
+        };
+
+        let endings = Self::raw_line_endings(&raw);
+        if endings.is_empty() {
+            return Ok(warnings);
+        }
+
+        let target = self.resolved_target(&raw);
+        let replacement = get_line_ending_str(target).to_string();
+
+        let mut line_start = 0;
+        for (idx, (range, ending)) in endings.iter().enumerate() {
+            if *ending != target {
+                let line = idx + 1;
+                let column = range.start - line_start + 1;
+                let end_column = range.end - line_start + 1;
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    message: format!(
+                        "Line ending is {}, expected {}",
+                        Self::ending_name(*ending),
+                        Self::ending_name(target)
+                    ),
+                    line,
+                    column,
+                    end_line: line,
+                    end_column,
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: range.clone(),
+                        replacement: replacement.clone(),
+                    }),
+                });
+            }
+            line_start = range.end;
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let Some(path) = ctx.file_path() else {
+            return Ok(ctx.content.to_string());
+        };
+        let Ok(raw) = std::fs::read_to_string(path) else {
+            return Ok(ctx.content.to_string());
+        };
+
+        let target = self.resolved_target(&raw);
+        Ok(normalize_line_ending(&raw, target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use std::io::Write;
+
+    fn ctx_for_file(content: &str) -> (tempfile::NamedTempFile, String) {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        (file, path)
+    }
+
+    #[test]
+    fn test_no_file_path_is_a_no_op() {
+        let rule = MD080ConsistentLineEndings::default();
+        let ctx = LintContext::new("Line 1\nLine 2\n", MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_auto_target_flags_minority_endings() {
+        let (_file, path) = ctx_for_file("a\r\nb\r\nc\n");
+        let rule = MD080ConsistentLineEndings::default();
+        let ctx = LintContext::new("a\nb\nc\n", MarkdownFlavor::Standard, Some(path.as_str()));
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1, "Only the lone LF line should be flagged against the dominant CRLF");
+        assert_eq!(result[0].line, 3);
+    }
+
+    #[test]
+    fn test_explicit_lf_target_flags_crlf_lines() {
+        let (_file, path) = ctx_for_file("a\r\nb\n");
+        let rule = MD080ConsistentLineEndings::from_config_struct(MD080Config {
+            target: LineEndingTarget::Lf,
+        });
+        let ctx = LintContext::new("a\nb\n", MarkdownFlavor::Standard, Some(path.as_str()));
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 1);
+    }
+
+    #[test]
+    fn test_consistent_file_has_no_warnings() {
+        let (_file, path) = ctx_for_file("a\nb\nc\n");
+        let rule = MD080ConsistentLineEndings::default();
+        let ctx = LintContext::new("a\nb\nc\n", MarkdownFlavor::Standard, Some(path.as_str()));
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_fix_rewrites_to_explicit_target() {
+        let (_file, path) = ctx_for_file("a\r\nb\nc\r\n");
+        let rule = MD080ConsistentLineEndings::from_config_struct(MD080Config {
+            target: LineEndingTarget::Crlf,
+        });
+        let ctx = LintContext::new("a\nb\nc\n", MarkdownFlavor::Standard, Some(path.as_str()));
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "a\r\nb\r\nc\r\n");
+    }
+}
@@ -0,0 +1,244 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+
+/// A zero-width character: invisible, and never meaningful in prose, so every
+/// occurrence is reported regardless of context.
+const ZERO_WIDTH_CHARS: [(char, &str); 4] = [
+    ('\u{200B}', "ZERO WIDTH SPACE"),
+    ('\u{200C}', "ZERO WIDTH NON-JOINER"),
+    ('\u{200D}', "ZERO WIDTH JOINER"),
+    ('\u{2060}', "WORD JOINER"),
+];
+
+/// U+FEFF is legitimate as a byte-order mark at the very start of a file, but
+/// invisible and suspicious anywhere else.
+const ZERO_WIDTH_NO_BREAK_SPACE: char = '\u{FEFF}';
+
+/// Bidi override characters force the rendering direction of everything up to
+/// the next pop, with no requirement that a pop ever follows. This is the
+/// classic Trojan Source (CVE-2021-42574) vector, so every occurrence is
+/// reported unconditionally rather than only unterminated ones.
+const BIDI_OVERRIDE_CHARS: [(char, &str); 5] = [
+    ('\u{202A}', "LEFT-TO-RIGHT EMBEDDING"),
+    ('\u{202B}', "RIGHT-TO-LEFT EMBEDDING"),
+    ('\u{202C}', "POP DIRECTIONAL FORMATTING"),
+    ('\u{202D}', "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202E}', "RIGHT-TO-LEFT OVERRIDE"),
+];
+
+/// Bidi isolate characters are legitimate when balanced (e.g. wrapping a
+/// user-supplied RTL name inside LTR prose), so only unterminated opens and
+/// stray closers are reported.
+const BIDI_ISOLATE_OPENERS: [(char, &str); 3] = [
+    ('\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', "FIRST STRONG ISOLATE"),
+];
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+fn isolate_opener_name(ch: char) -> Option<&'static str> {
+    BIDI_ISOLATE_OPENERS.iter().find(|(c, _)| *c == ch).map(|(_, name)| *name)
+}
+
+/// Rule MD081: Invisible and bidirectional Unicode control characters
+///
+/// See [docs/md081.md](../../docs/md081.md) for full documentation, configuration, and examples.
+///
+/// Detects zero-width characters and bidirectional formatting control
+/// characters that can be used to hide content or make source text render
+/// differently than its logical order implies (the "Trojan Source" class of
+/// attack, CVE-2021-42574). Zero-width characters and bidi overrides are
+/// flagged on every occurrence; bidi isolates are only flagged when
+/// unterminated or stray, since properly paired isolates are legitimate in
+/// mixed-direction prose.
+#[derive(Clone, Default)]
+pub struct MD081UnicodeControlChars;
+
+impl MD081UnicodeControlChars {
+    fn warning(&self, line: usize, column: usize, codepoint: char, name: &str, byte_offset: usize) -> LintWarning {
+        LintWarning {
+            rule_name: Some(self.name()),
+            message: format!("Invisible or bidirectional control character U+{:04X} ({name})", codepoint as u32),
+            line,
+            column,
+            end_line: line,
+            end_column: column + 1,
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: byte_offset..byte_offset + codepoint.len_utf8(),
+                replacement: String::new(),
+            }),
+        }
+    }
+}
+
+impl Rule for MD081UnicodeControlChars {
+    fn name(&self) -> &'static str {
+        "MD081"
+    }
+
+    fn description(&self) -> &'static str {
+        "Invisible and bidirectional Unicode control characters should not appear in the document"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(Self)
+    }
+
+    fn fix_applicability(&self) -> crate::rule::Applicability {
+        crate::rule::Applicability::Safe
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        let mut line = 1usize;
+        let mut column = 1usize;
+        // Tracks unmatched isolate openers as (name, line, column, byte_offset),
+        // so an isolate with no matching PDI by end-of-document can still be
+        // reported once the whole content has been scanned.
+        let mut open_isolates: Vec<(&'static str, usize, usize, usize)> = Vec::new();
+
+        for (byte_offset, ch) in ctx.content.char_indices() {
+            if let Some((_, name)) = ZERO_WIDTH_CHARS.iter().find(|(c, _)| *c == ch) {
+                warnings.push(self.warning(line, column, ch, name, byte_offset));
+            } else if ch == ZERO_WIDTH_NO_BREAK_SPACE && byte_offset != 0 {
+                warnings.push(self.warning(line, column, ch, "ZERO WIDTH NO-BREAK SPACE", byte_offset));
+            } else if let Some((_, name)) = BIDI_OVERRIDE_CHARS.iter().find(|(c, _)| *c == ch) {
+                warnings.push(self.warning(line, column, ch, name, byte_offset));
+            } else if let Some(name) = isolate_opener_name(ch) {
+                open_isolates.push((name, line, column, byte_offset));
+            } else if ch == POP_DIRECTIONAL_ISOLATE {
+                if open_isolates.pop().is_none() {
+                    warnings.push(self.warning(line, column, ch, "POP DIRECTIONAL ISOLATE (stray, no matching opener)", byte_offset));
+                }
+            }
+
+            if ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+
+        for (name, line, column, byte_offset) in open_isolates {
+            let ch = match name {
+                "LEFT-TO-RIGHT ISOLATE" => '\u{2066}',
+                "RIGHT-TO-LEFT ISOLATE" => '\u{2067}',
+                _ => '\u{2068}',
+            };
+            warnings.push(self.warning(line, column, ch, &format!("{name} (unterminated, no matching pop)"), byte_offset));
+        }
+
+        warnings.sort_by(|a, b| (a.line, a.column).cmp(&(b.line, b.column)));
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        let mut ranges: Vec<_> = warnings.into_iter().filter_map(|w| w.fix).collect();
+        ranges.sort_by(|a, b| b.range.start.cmp(&a.range.start));
+
+        let mut content = ctx.content.to_string();
+        for fix in ranges {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_content_has_no_warnings() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("Just plain ASCII prose.\n");
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_zero_width_space_is_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("hello\u{200B}world\n");
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 1);
+        assert!(result[0].message.contains("ZERO WIDTH SPACE"));
+    }
+
+    #[test]
+    fn test_leading_bom_is_not_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("\u{FEFF}# Title\n");
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_non_leading_bom_is_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("a\u{FEFF}b\n");
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn test_bidi_override_is_always_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("user\u{202E}name\n");
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("RIGHT-TO-LEFT OVERRIDE"));
+    }
+
+    #[test]
+    fn test_balanced_isolate_is_not_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("Hello \u{2066}שלום\u{2069} world\n");
+        let result = rule.check(&ctx).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_unterminated_isolate_is_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("Hello \u{2066}שלום world\n");
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("unterminated"));
+    }
+
+    #[test]
+    fn test_stray_pop_directional_isolate_is_flagged() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("Hello\u{2069} world\n");
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("stray"));
+    }
+
+    #[test]
+    fn test_fix_strips_all_flagged_characters() {
+        let rule = MD081UnicodeControlChars;
+        let ctx = LintContext::new("a\u{200B}b\u{202E}c\n");
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "abc\n");
+    }
+}
@@ -0,0 +1,235 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::element_cache::ElementCache;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD082 (Un-tracked TODO/FIXME/XXX markers)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD082Config {
+    /// The issue-marker keywords to scan for (case-sensitive).
+    #[serde(default = "default_tags")]
+    pub tags: Vec<String>,
+    /// When true, only markers with no `(#123)` or URL reference are flagged;
+    /// when false (the default), every marker occurrence is flagged.
+    #[serde(default)]
+    pub require_issue_number: bool,
+}
+
+fn default_tags() -> Vec<String> {
+    ["TODO", "FIXME", "HACK", "XXX"].iter().map(|s| s.to_string()).collect()
+}
+
+impl Default for MD082Config {
+    fn default() -> Self {
+        Self {
+            tags: default_tags(),
+            require_issue_number: false,
+        }
+    }
+}
+
+impl RuleConfig for MD082Config {
+    const RULE_NAME: &'static str = "MD082";
+}
+
+/// Rule MD082: Un-tracked TODO/FIXME/XXX markers
+///
+/// See [docs/md082.md](../../docs/md082.md) for full documentation, configuration, and examples.
+///
+/// Scans prose (skipping fenced and indented code blocks) for configurable
+/// issue-marker keywords. In the default mode every marker is reported so
+/// they stay visible; enabling `require_issue_number` instead only reports
+/// markers with no `(#123)`-style or URL issue reference immediately after.
+#[derive(Clone, Default)]
+pub struct MD082TodoIssueReference {
+    config: MD082Config,
+}
+
+impl MD082TodoIssueReference {
+    pub fn from_config_struct(config: MD082Config) -> Self {
+        Self { config }
+    }
+
+    fn is_word_boundary(ch: Option<char>) -> bool {
+        !ch.is_some_and(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// Whether an issue reference (`#123`, `(#123)` or a URL) starts `rest`,
+    /// the text right after a marker, skipping over `:` and whitespace first.
+    fn has_issue_reference(rest: &str) -> bool {
+        let trimmed = rest.trim_start_matches([':', ' ', '\t']);
+        if let Some(hash_rest) = trimmed.strip_prefix('#') {
+            return hash_rest.chars().next().is_some_and(|c| c.is_ascii_digit());
+        }
+        if let Some(paren_rest) = trimmed.strip_prefix("(#") {
+            return paren_rest.chars().next().is_some_and(|c| c.is_ascii_digit());
+        }
+        trimmed.starts_with("http://") || trimmed.starts_with("https://")
+    }
+
+    /// Find every case-sensitive, word-bounded occurrence of `tag` in `line`,
+    /// yielding its 0-indexed byte offset.
+    fn find_markers<'a>(line: &'a str, tag: &'a str) -> impl Iterator<Item = usize> + 'a {
+        line.match_indices(tag).filter_map(move |(idx, _)| {
+            let before = line[..idx].chars().next_back();
+            let after = line[idx + tag.len()..].chars().next();
+            (Self::is_word_boundary(before) && Self::is_word_boundary(after)).then_some(idx)
+        })
+    }
+}
+
+impl Rule for MD082TodoIssueReference {
+    fn name(&self) -> &'static str {
+        "MD082"
+    }
+
+    fn description(&self) -> &'static str {
+        "Un-tracked TODO/FIXME/XXX markers should reference an issue"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn fix_capability(&self) -> crate::rule::FixCapability {
+        crate::rule::FixCapability::Unfixable
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD082Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.config.tags.is_empty() {
+            return Ok(warnings);
+        }
+
+        let element_cache = ElementCache::new(ctx.content);
+        for (i, line) in ctx.content.lines().enumerate() {
+            let line_num = i + 1;
+            if element_cache.is_in_code_block(line_num) {
+                continue;
+            }
+
+            for tag in &self.config.tags {
+                for offset in Self::find_markers(line, tag) {
+                    let referenced = Self::has_issue_reference(&line[offset + tag.len()..]);
+                    if self.config.require_issue_number && referenced {
+                        continue;
+                    }
+
+                    let message = if self.config.require_issue_number {
+                        format!("{tag} marker has no issue reference (e.g. `(#123)` or a URL)")
+                    } else {
+                        format!("Found un-tracked `{tag}` marker")
+                    };
+
+                    warnings.push(LintWarning {
+                        rule_name: Some(self.name()),
+                        message,
+                        line: line_num,
+                        column: offset + 1,
+                        end_line: line_num,
+                        end_column: offset + tag.len() + 1,
+                        severity: Severity::Warning,
+                        fix: None,
+                    });
+                }
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Whether a TODO needs an issue reference is a judgement call for a
+        // human, not something that can be rewritten automatically.
+        Ok(ctx.content.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_mode_flags_every_marker() {
+        let rule = MD082TodoIssueReference::default();
+        let ctx = LintContext::new("TODO: clean this up\nFIXME later\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].line, 1);
+        assert_eq!(warnings[1].line, 2);
+    }
+
+    #[test]
+    fn test_marker_inside_word_is_not_flagged() {
+        let rule = MD082TodoIssueReference::default();
+        let ctx = LintContext::new("TODOLIST and HACKATHON are not markers\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_marker_in_code_block_is_ignored() {
+        let rule = MD082TodoIssueReference::default();
+        let ctx = LintContext::new("```\nTODO: ignored in code\n```\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_require_issue_number_allows_hash_reference() {
+        let rule = MD082TodoIssueReference::from_config_struct(MD082Config {
+            require_issue_number: true,
+            ..Default::default()
+        });
+        let ctx = LintContext::new("TODO(#123): fix this\nFIXME: no reference here\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+    }
+
+    #[test]
+    fn test_require_issue_number_allows_url_reference() {
+        let rule = MD082TodoIssueReference::from_config_struct(MD082Config {
+            require_issue_number: true,
+            ..Default::default()
+        });
+        let ctx = LintContext::new("TODO: https://example.com/issues/42 fix this\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_custom_tags_are_respected() {
+        let rule = MD082TodoIssueReference::from_config_struct(MD082Config {
+            tags: vec!["REVISIT".to_string()],
+            require_issue_number: false,
+        });
+        let ctx = LintContext::new("TODO: not scanned\nREVISIT: this later\n");
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+    }
+}
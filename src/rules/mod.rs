@@ -55,8 +55,22 @@ mod md054_link_image_style;
 mod md055_table_pipe_style;
 mod md056_table_column_count;
 mod md058_blanks_around_tables;
+pub mod md076_code_in_prose;
+pub mod md077_duplicate_heading_anchor;
+pub mod md078_abbreviation_usage;
+pub mod md079_blockquote_alerts;
+pub mod md080_consistent_line_endings;
+pub mod md081_unicode_control_chars;
+pub mod md082_todo_issue_reference;
 
 pub use md001_heading_increment::MD001HeadingIncrement;
+pub use md076_code_in_prose::MD076CodeInProse;
+pub use md077_duplicate_heading_anchor::MD077DuplicateHeadingAnchor;
+pub use md078_abbreviation_usage::MD078AbbreviationUsage;
+pub use md079_blockquote_alerts::MD079BlockquoteAlerts;
+pub use md080_consistent_line_endings::MD080ConsistentLineEndings;
+pub use md081_unicode_control_chars::MD081UnicodeControlChars;
+pub use md082_todo_issue_reference::MD082TodoIssueReference;
 pub use md002_first_heading_h1::MD002FirstHeadingH1;
 pub use md003_heading_style::MD003HeadingStyle;
 pub use md004_unordered_list_style::MD004UnorderedListStyle;
@@ -209,9 +223,34 @@ pub fn all_rules(config: &crate::config::Config) -> Vec<Box<dyn Rule>> {
         rule!(MD056TableColumnCount),
         rule!(MD057ExistingRelativeLinks),
         rule!(MD058BlanksAroundTables),
+        rule!(MD076CodeInProse),
+        rule!(MD077DuplicateHeadingAnchor),
+        rule!(MD078AbbreviationUsage),
+        rule!(MD079BlockquoteAlerts),
+        rule!(MD080ConsistentLineEndings),
+        rule!(MD081UnicodeControlChars),
+        rule!(MD082TodoIssueReference),
     ]
 }
 
+/// Look up the [`Applicability`](crate::rule::Applicability) a rule declares
+/// for its fixes, by rule name (e.g. `"MD033"`). Unknown names are treated as
+/// [`Applicability::Safe`]. Backed by the default rule set so applicability
+/// stays defined in one place — each rule's `fix_applicability` impl.
+pub fn rule_applicability(name: &str) -> crate::rule::Applicability {
+    use std::collections::HashMap;
+    use std::sync::OnceLock;
+
+    static MAP: OnceLock<HashMap<&'static str, crate::rule::Applicability>> = OnceLock::new();
+    let map = MAP.get_or_init(|| {
+        all_rules(&crate::config::Config::default())
+            .iter()
+            .map(|r| (r.name(), r.fix_applicability()))
+            .collect()
+    });
+    map.get(name).copied().unwrap_or_default()
+}
+
 // Filter rules based on config (moved from main.rs)
 // Note: This needs access to GlobalConfig from the config module.
 use crate::config::GlobalConfig;
@@ -156,7 +156,7 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
     // For formats that need collection
     if matches!(
         output_format,
-        OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit
+        OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit | OutputFormat::Checkstyle
     ) {
         let file_warnings = vec![(display_filename.to_string(), all_warnings)];
         let output = match output_format {
@@ -164,6 +164,9 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
             OutputFormat::GitLab => rumdl_lib::output::formatters::gitlab::format_gitlab_report(&file_warnings),
             OutputFormat::Sarif => rumdl_lib::output::formatters::sarif::format_sarif_report(&file_warnings),
             OutputFormat::Junit => rumdl_lib::output::formatters::junit::format_junit_report(&file_warnings, 0),
+            OutputFormat::Checkstyle => {
+                rumdl_lib::output::formatters::checkstyle::format_checkstyle_report(&file_warnings)
+            }
             _ => unreachable!(),
         };
 
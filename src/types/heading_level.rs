@@ -31,6 +31,26 @@ impl HeadingLevel {
     pub fn as_usize(self) -> usize {
         self.0 as usize
     }
+
+    /// Parse a heading level out of a string representation: a bare number
+    /// (`"2"`), an `h`/`H`-prefixed form (`"h2"`, `"H2"`), or a literal hash
+    /// prefix (`"##"`). Does not itself validate the 1..=6 range; callers
+    /// should feed the result through [`HeadingLevel::new`].
+    fn parse_str(s: &str) -> Result<u8, String> {
+        let trimmed = s.trim();
+        let digits = trimmed.strip_prefix(['h', 'H']).unwrap_or(trimmed);
+
+        if !digits.is_empty() && digits.chars().all(|c| c == '#') {
+            return Ok(digits.len() as u8);
+        }
+
+        digits.parse::<u8>().map_err(|_| {
+            format!(
+                "invalid heading level {trimmed:?}: expected an integer, an \"h2\"-style string, \
+                 or a literal \"##\"-style hash prefix"
+            )
+        })
+    }
 }
 
 /// Error type for invalid heading levels.
@@ -55,8 +75,46 @@ impl<'de> Deserialize<'de> for HeadingLevel {
     where
         D: Deserializer<'de>,
     {
-        let level = u8::deserialize(deserializer)?;
-        HeadingLevel::new(level).map_err(serde::de::Error::custom)
+        deserializer.deserialize_any(HeadingLevelVisitor)
+    }
+}
+
+/// Accepts a `HeadingLevel` from multiple input representations (integer,
+/// `"h2"`-style string, or `"##"`-style hash prefix), mirroring the approach
+/// the `log` crate uses to deserialize its `Level` type from either an
+/// integer or a name.
+struct HeadingLevelVisitor;
+
+impl serde::de::Visitor<'_> for HeadingLevelVisitor {
+    type Value = HeadingLevel;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a heading level as an integer (1-6), a string like \"h2\", or a hash prefix like \"##\"")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let level = u8::try_from(value).map_err(|_| E::custom(HeadingLevelError(u8::MAX).to_string()))?;
+        HeadingLevel::new(level).map_err(E::custom)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let level =
+            u8::try_from(value).map_err(|_| E::custom(format!("Heading level must be between 1 and 6, got {value}.")))?;
+        HeadingLevel::new(level).map_err(E::custom)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let level = HeadingLevel::parse_str(value).map_err(E::custom)?;
+        HeadingLevel::new(level).map_err(E::custom)
     }
 }
 
@@ -116,6 +174,95 @@ mod tests {
         assert_eq!(deserialized.level.get(), 3);
     }
 
+    #[test]
+    fn test_deserialize_h_prefixed_string() {
+        #[derive(Debug, serde::Deserialize)]
+        struct TestConfig {
+            level: HeadingLevel,
+        }
+
+        let config: TestConfig = toml::from_str("level = \"h2\"").unwrap();
+        assert_eq!(config.level.get(), 2);
+
+        let config: TestConfig = toml::from_str("level = \"H4\"").unwrap();
+        assert_eq!(config.level.get(), 4);
+    }
+
+    #[test]
+    fn test_deserialize_hash_prefix_string() {
+        #[derive(Debug, serde::Deserialize)]
+        struct TestConfig {
+            level: HeadingLevel,
+        }
+
+        let config: TestConfig = toml::from_str("level = \"###\"").unwrap();
+        assert_eq!(config.level.get(), 3);
+    }
+
+    #[test]
+    fn test_deserialize_bare_numeric_string() {
+        #[derive(Debug, serde::Deserialize)]
+        struct TestConfig {
+            level: HeadingLevel,
+        }
+
+        let config: TestConfig = toml::from_str("level = \"5\"").unwrap();
+        assert_eq!(config.level.get(), 5);
+    }
+
+    #[test]
+    fn test_deserialize_string_out_of_range() {
+        #[derive(Debug, serde::Deserialize)]
+        struct TestConfig {
+            level: HeadingLevel,
+        }
+
+        let result: Result<TestConfig, _> = toml::from_str("level = \"h9\"");
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("must be between 1 and 6") || err.contains("got 9"));
+
+        // A valid string form still deserializes correctly alongside the error case.
+        let config: TestConfig = toml::from_str("level = \"h6\"").unwrap();
+        assert_eq!(config.level.get(), 6);
+    }
+
+    #[test]
+    fn test_deserialize_unparseable_string() {
+        #[derive(Debug, serde::Deserialize)]
+        struct TestConfig {
+            level: HeadingLevel,
+        }
+
+        let result: Result<TestConfig, _> = toml::from_str("level = \"heading\"");
+        assert!(result.is_err());
+
+        // A valid string form still deserializes correctly alongside the error case.
+        let config: TestConfig = toml::from_str("level = \"h1\"").unwrap();
+        assert_eq!(config.level.get(), 1);
+    }
+
+    #[test]
+    fn test_ron_roundtrip() {
+        // RON presents integers and strings to serde differently than TOML does
+        // (e.g. via `visit_i64` rather than `visit_u64`); make sure both forms
+        // still round-trip correctly.
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct TestConfig {
+            level: HeadingLevel,
+        }
+
+        let config = TestConfig {
+            level: HeadingLevel::new(4).unwrap(),
+        };
+        let serialized = ron::ser::to_string(&config).unwrap();
+        let deserialized: TestConfig = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.level.get(), 4);
+
+        let config: TestConfig = ron::from_str("(level: \"h3\")").unwrap();
+        assert_eq!(config.level.get(), 3);
+    }
+
     #[test]
     fn test_validation_error() {
         #[derive(Debug, serde::Deserialize)]
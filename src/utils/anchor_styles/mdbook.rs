@@ -0,0 +1,58 @@
+//! mdBook-style anchor generation.
+//!
+//! mdBook derives heading ids with pulldown-cmark's id normalization: the text
+//! is lowercased, runs of whitespace collapse to a single `-`, and every
+//! character that is not an ASCII alphanumeric, `-`, or `_` is dropped. Unlike
+//! GitHub, mdBook keeps underscores and does not strip leading digits.
+
+/// Generate an mdBook-style anchor fragment for a single heading.
+pub fn heading_to_fragment(heading: &str) -> String {
+    let mut fragment = String::with_capacity(heading.len());
+    let mut prev_dash = false;
+
+    for c in heading.chars() {
+        if c.is_whitespace() {
+            if !fragment.is_empty() && !prev_dash {
+                fragment.push('-');
+                prev_dash = true;
+            }
+        } else if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            fragment.extend(c.to_lowercase());
+            prev_dash = false;
+        }
+        // Any other character is simply dropped.
+    }
+
+    // Never emit a trailing dash introduced by trailing whitespace.
+    if fragment.ends_with('-') {
+        fragment.pop();
+    }
+
+    fragment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(heading_to_fragment("Hello World"), "hello-world");
+    }
+
+    #[test]
+    fn test_keeps_underscores_and_digits() {
+        assert_eq!(heading_to_fragment("max_width 2"), "max_width-2");
+        assert_eq!(heading_to_fragment("3 Little Pigs"), "3-little-pigs");
+    }
+
+    #[test]
+    fn test_drops_punctuation() {
+        assert_eq!(heading_to_fragment("What's new?!"), "whats-new");
+    }
+
+    #[test]
+    fn test_collapses_whitespace() {
+        assert_eq!(heading_to_fragment("a   b\tc"), "a-b-c");
+    }
+}
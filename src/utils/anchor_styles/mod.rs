@@ -13,6 +13,7 @@
 pub mod github;
 pub mod kramdown;
 pub mod kramdown_gfm; // Renamed from jekyll for clarity
+pub mod mdbook;
 
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +33,9 @@ pub enum AnchorStyle {
     /// Pure kramdown style: removes underscores and punctuation
     #[serde(rename = "kramdown")]
     Kramdown,
+    /// mdBook style: lowercases, keeps underscores, drops other punctuation
+    #[serde(rename = "mdbook")]
+    MdBook,
 }
 
 impl AnchorStyle {
@@ -41,6 +45,7 @@ impl AnchorStyle {
             AnchorStyle::GitHub => github::heading_to_fragment(heading),
             AnchorStyle::KramdownGfm => kramdown_gfm::heading_to_fragment(heading),
             AnchorStyle::Kramdown => kramdown::heading_to_fragment(heading),
+            AnchorStyle::MdBook => mdbook::heading_to_fragment(heading),
         }
     }
 }
@@ -6,15 +6,75 @@
 
 use crate::rule::MarkdownAst;
 use lazy_static::lazy_static;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::panic;
 use std::sync::{Arc, Mutex};
 
+/// Which optional Markdown constructs [`parse_markdown_ast`] should enable, mirroring
+/// the subset of the `markdown` crate's `Constructs` that rules actually need to vary.
+/// Two profiles that differ in any field are treated as distinct cache entries (see
+/// [`AstCache::get_or_parse_with`]), so an AST parsed with footnotes off is never
+/// handed back to a caller that asked for footnotes on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseProfile {
+    pub gfm_tables: bool,
+    pub footnotes: bool,
+    pub task_lists: bool,
+    pub strikethrough: bool,
+    pub frontmatter: bool,
+    pub math: bool,
+}
+
+impl Default for ParseProfile {
+    /// Matches the options `parse_markdown_ast` has always used: GFM tables,
+    /// footnotes, task lists, and strikethrough enabled, frontmatter enabled, math off.
+    fn default() -> Self {
+        Self {
+            gfm_tables: true,
+            footnotes: true,
+            task_lists: true,
+            strikethrough: true,
+            frontmatter: true,
+            math: false,
+        }
+    }
+}
+
+impl ParseProfile {
+    fn to_parse_options(self) -> markdown::ParseOptions {
+        let mut options = markdown::ParseOptions::gfm();
+        options.constructs.gfm_table = self.gfm_tables;
+        options.constructs.gfm_footnote_definition = self.footnotes;
+        options.constructs.gfm_label_start_footnote = self.footnotes;
+        options.constructs.gfm_task_list_item = self.task_lists;
+        options.constructs.gfm_strikethrough = self.strikethrough;
+        options.constructs.frontmatter = self.frontmatter;
+        options.constructs.math_flow = self.math;
+        options.constructs.math_text = self.math;
+        options
+    }
+}
+
+/// Default capacity for [`AstCache`] when constructed via [`AstCache::new`].
+const DEFAULT_AST_CACHE_CAPACITY: usize = 256;
+
 /// Cache for parsed AST nodes
 #[derive(Debug)]
 pub struct AstCache {
     cache: HashMap<u64, Arc<MarkdownAst>>,
+    /// Long-lived per-key access counts, kept even after an entry is evicted so
+    /// reporting (`get_stats`) still reflects a document's total usage history.
     usage_stats: HashMap<u64, u64>,
+    /// Tick each cached key was last touched at, used to find the least-recently-used
+    /// entry to evict. Removed alongside the entry it tracks.
+    last_access: HashMap<u64, u64>,
+    /// Monotonically increasing counter; bumped on every access so `last_access`
+    /// values can be compared to find the least-recently-used entry.
+    tick: u64,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
 }
 
 impl Default for AstCache {
@@ -25,29 +85,90 @@ impl Default for AstCache {
 
 impl AstCache {
     pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_AST_CACHE_CAPACITY)
+    }
+
+    /// Create a cache that holds at most `capacity` parsed documents, evicting the
+    /// least-recently-used entry once a new insert would exceed it.
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
             cache: HashMap::new(),
             usage_stats: HashMap::new(),
+            last_access: HashMap::new(),
+            tick: 0,
+            capacity: capacity.max(1),
+            hits: 0,
+            misses: 0,
         }
     }
 
-    /// Get or parse AST for the given content
+    /// Change the cache's capacity, evicting least-recently-used entries
+    /// immediately if the new capacity is smaller than the current size.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.evict_over_capacity();
+    }
+
+    /// Fraction of `get_or_parse`/`get_or_parse_with` calls that were served from
+    /// the cache, in `[0.0, 1.0]`. Returns `0.0` if the cache has never been queried.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 { 0.0 } else { self.hits as f64 / total as f64 }
+    }
+
+    /// Get or parse AST for the given content, using the default [`ParseProfile`].
     pub fn get_or_parse(&mut self, content: &str) -> Arc<MarkdownAst> {
-        let content_hash = crate::utils::fast_hash(content);
+        self.get_or_parse_with(content, ParseProfile::default())
+    }
 
-        if let Some(ast) = self.cache.get(&content_hash) {
-            *self.usage_stats.entry(content_hash).or_insert(0) += 1;
-            return ast.clone();
+    /// Get or parse AST for the given content under a specific [`ParseProfile`].
+    /// The profile is folded into the cache key so distinct option sets coexist
+    /// instead of one clobbering the other.
+    pub fn get_or_parse_with(&mut self, content: &str, profile: ParseProfile) -> Arc<MarkdownAst> {
+        let cache_key = Self::cache_key(content, &profile);
+
+        if let Some(ast) = self.cache.get(&cache_key) {
+            let ast = ast.clone();
+            self.hits += 1;
+            *self.usage_stats.entry(cache_key).or_insert(0) += 1;
+            self.touch(cache_key);
+            return ast;
         }
 
-        // Parse the AST
-        let ast = Arc::new(parse_markdown_ast(content));
-        self.cache.insert(content_hash, ast.clone());
-        *self.usage_stats.entry(content_hash).or_insert(0) += 1;
+        self.misses += 1;
+        let ast = Arc::new(parse_markdown_ast_with(content, profile));
+        self.cache.insert(cache_key, ast.clone());
+        *self.usage_stats.entry(cache_key).or_insert(0) += 1;
+        self.touch(cache_key);
+        self.evict_over_capacity();
 
         ast
     }
 
+    fn cache_key(content: &str, profile: &ParseProfile) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        content.hash(&mut hasher);
+        profile.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn touch(&mut self, key: u64) {
+        self.tick += 1;
+        self.last_access.insert(key, self.tick);
+    }
+
+    fn evict_over_capacity(&mut self) {
+        while self.cache.len() > self.capacity {
+            let Some((&lru_key, _)) = self.last_access.iter().min_by_key(|&(_, tick)| tick) else {
+                break;
+            };
+            self.cache.remove(&lru_key);
+            self.last_access.remove(&lru_key);
+            // `usage_stats` is intentionally left untouched: it is a long-lived
+            // counter for reporting, not a view of what's currently cached.
+        }
+    }
+
     /// Get cache statistics
     pub fn get_stats(&self) -> HashMap<u64, u64> {
         self.usage_stats.clone()
@@ -57,6 +178,7 @@ impl AstCache {
     pub fn clear(&mut self) {
         self.cache.clear();
         self.usage_stats.clear();
+        self.last_access.clear();
     }
 
     /// Get cache size
@@ -75,12 +197,18 @@ lazy_static! {
     static ref GLOBAL_AST_CACHE: Arc<Mutex<AstCache>> = Arc::new(Mutex::new(AstCache::new()));
 }
 
-/// Get or parse AST from the global cache
+/// Get or parse AST from the global cache, using the default [`ParseProfile`].
 pub fn get_cached_ast(content: &str) -> Arc<MarkdownAst> {
     let mut cache = GLOBAL_AST_CACHE.lock().unwrap();
     cache.get_or_parse(content)
 }
 
+/// Get or parse AST from the global cache under a specific [`ParseProfile`].
+pub fn get_cached_ast_with(content: &str, profile: ParseProfile) -> Arc<MarkdownAst> {
+    let mut cache = GLOBAL_AST_CACHE.lock().unwrap();
+    cache.get_or_parse_with(content, profile)
+}
+
 /// Get AST cache statistics
 pub fn get_ast_cache_stats() -> HashMap<u64, u64> {
     let cache = GLOBAL_AST_CACHE.lock().unwrap();
@@ -93,8 +221,13 @@ pub fn clear_ast_cache() {
     cache.clear();
 }
 
-/// Parse Markdown content into an AST
+/// Parse Markdown content into an AST using the default [`ParseProfile`].
 pub fn parse_markdown_ast(content: &str) -> MarkdownAst {
+    parse_markdown_ast_with(content, ParseProfile::default())
+}
+
+/// Parse Markdown content into an AST under a specific [`ParseProfile`].
+pub fn parse_markdown_ast_with(content: &str, profile: ParseProfile) -> MarkdownAst {
     // Check for problematic patterns that cause the markdown crate to panic
     if content_has_problematic_lists(content) {
         log::debug!("Detected problematic list patterns, skipping AST parsing");
@@ -104,10 +237,10 @@ pub fn parse_markdown_ast(content: &str) -> MarkdownAst {
         });
     }
 
-    // Try to parse AST with GFM extensions enabled, but handle panics from the markdown crate
+    // Try to parse AST with the profile's extensions enabled, but handle panics from
+    // the markdown crate
     match panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        let mut parse_options = markdown::ParseOptions::gfm();
-        parse_options.constructs.frontmatter = true; // Also enable frontmatter parsing
+        let parse_options = profile.to_parse_options();
         markdown::to_mdast(content, &parse_options)
     })) {
         Ok(Ok(ast)) => {
@@ -245,6 +378,366 @@ fn extract_nodes_by_type_recursive<'a>(ast: &'a MarkdownAst, node_type: &str, no
     }
 }
 
+/// Options controlling how [`serialize_ast`] renders a [`MarkdownAst`] back to text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Render level-1/level-2 headings as setext underlines (`===`/`---`)
+    /// instead of ATX (`#`/`##`) style.
+    pub setext_headings: bool,
+    /// Number of spaces each level of list/blockquote nesting indents by.
+    pub indent_width: usize,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            setext_headings: false,
+            indent_width: 2,
+        }
+    }
+}
+
+/// Per-list bookkeeping for [`AstSerializer`]: which marker an unordered list uses
+/// and which number an ordered list is up to.
+struct ListContext {
+    ordered: bool,
+    marker: char,
+    next_number: u32,
+}
+
+/// Mutable state threaded through AST serialization: the growing output buffer, the
+/// indent prefix applied to every new line, and a one-shot prefix (a list marker)
+/// that overrides the indent for the very next line only.
+struct AstSerializer {
+    options: SerializeOptions,
+    output: String,
+    indent: String,
+    pending_prefix: Option<String>,
+    list_stack: Vec<ListContext>,
+}
+
+impl AstSerializer {
+    fn new(options: SerializeOptions) -> Self {
+        Self {
+            options,
+            output: String::new(),
+            indent: String::new(),
+            pending_prefix: None,
+            list_stack: Vec::new(),
+        }
+    }
+
+    /// Start a new line: write the one-shot list marker if one is pending, otherwise
+    /// the current indent.
+    fn write_indent(&mut self) {
+        match self.pending_prefix.take() {
+            Some(prefix) => self.output.push_str(&prefix),
+            None => self.output.push_str(&self.indent),
+        }
+    }
+
+    fn render_node(&mut self, node: &MarkdownAst) {
+        match node {
+            MarkdownAst::Root(root) => self.render_blocks(&root.children),
+            MarkdownAst::Paragraph(p) => {
+                self.write_indent();
+                self.push_inline_content(&render_inline_children(&p.children));
+                self.output.push('\n');
+            }
+            MarkdownAst::Heading(heading) => self.render_heading(heading),
+            MarkdownAst::Blockquote(bq) => self.render_blockquote(bq),
+            MarkdownAst::List(list) => self.render_list(list),
+            MarkdownAst::Code(code) => self.render_code(code),
+            MarkdownAst::ThematicBreak(_) => {
+                self.write_indent();
+                self.output.push_str("---\n");
+            }
+            MarkdownAst::Table(table) => self.render_table(table),
+            MarkdownAst::Html(html) => {
+                self.write_indent();
+                self.output.push_str(&html.value);
+                self.output.push('\n');
+            }
+            MarkdownAst::Definition(def) => {
+                self.write_indent();
+                self.output.push('[');
+                self.output.push_str(def.label.as_deref().unwrap_or(&def.identifier));
+                self.output.push_str("]: ");
+                self.output.push_str(&def.url);
+                if let Some(title) = &def.title {
+                    self.output.push_str(&format!(" \"{title}\""));
+                }
+                self.output.push('\n');
+            }
+            _ => {
+                // Anything else with children (footnotes, MDX, etc.) falls back to a
+                // single inline-rendered line rather than being dropped silently.
+                if let Some(children) = node.children() {
+                    self.write_indent();
+                    self.push_inline_content(&render_inline_children(children));
+                    self.output.push('\n');
+                }
+            }
+        }
+    }
+
+    /// Push already-rendered inline content, re-applying the current indent after
+    /// every embedded newline (e.g. a soft line break within a single paragraph) so
+    /// the enclosing blockquote/list prefix still appears on the continuation line.
+    fn push_inline_content(&mut self, text: &str) {
+        if text.contains('\n') {
+            let indent = self.indent.clone();
+            self.output.push_str(&text.replace('\n', &format!("\n{indent}")));
+        } else {
+            self.output.push_str(text);
+        }
+    }
+
+    /// Render a sequence of sibling block nodes, separated by a blank line.
+    fn render_blocks(&mut self, children: &[MarkdownAst]) {
+        for (i, child) in children.iter().enumerate() {
+            if i > 0 {
+                self.output.push('\n');
+            }
+            self.render_node(child);
+        }
+    }
+
+    /// Render a list item's block children back-to-back with no blank-line
+    /// separation - matching CommonMark's "tight" rendering for ordinary list items.
+    fn render_item_blocks(&mut self, children: &[MarkdownAst]) {
+        for child in children {
+            self.render_node(child);
+        }
+    }
+
+    fn render_heading(&mut self, heading: &markdown::mdast::Heading) {
+        if self.options.setext_headings && (heading.depth == 1 || heading.depth == 2) {
+            let text = render_inline_children(&heading.children);
+            self.write_indent();
+            self.output.push_str(&text);
+            self.output.push('\n');
+            self.write_indent();
+            let underline = if heading.depth == 1 { '=' } else { '-' };
+            self.output
+                .push_str(&underline.to_string().repeat(text.chars().count().max(1)));
+            self.output.push('\n');
+        } else {
+            self.write_indent();
+            self.output.push_str(&"#".repeat(heading.depth as usize));
+            self.output.push(' ');
+            self.output.push_str(&render_inline_children(&heading.children));
+            self.output.push('\n');
+        }
+    }
+
+    fn render_blockquote(&mut self, bq: &markdown::mdast::Blockquote) {
+        let saved_indent = self.indent.clone();
+        self.indent.push_str("> ");
+        self.render_blocks(&bq.children);
+        self.indent = saved_indent;
+    }
+
+    fn render_list(&mut self, list: &markdown::mdast::List) {
+        const UNORDERED_MARKERS: [char; 3] = ['-', '*', '+'];
+        let marker = UNORDERED_MARKERS[self.list_stack.len() % UNORDERED_MARKERS.len()];
+        self.list_stack.push(ListContext {
+            ordered: list.ordered,
+            marker,
+            next_number: list.start.unwrap_or(1),
+        });
+
+        for (i, item) in list.children.iter().enumerate() {
+            if i > 0 && list.spread {
+                self.output.push('\n');
+            }
+            if let MarkdownAst::ListItem(li) = item {
+                self.render_list_item(li);
+            }
+        }
+
+        self.list_stack.pop();
+    }
+
+    fn render_list_item(&mut self, item: &markdown::mdast::ListItem) {
+        let marker_text = {
+            let ctx = self.list_stack.last_mut().expect("list item rendered outside a list");
+            if ctx.ordered {
+                let text = format!("{}. ", ctx.next_number);
+                ctx.next_number += 1;
+                text
+            } else {
+                format!("{} ", ctx.marker)
+            }
+        };
+
+        let saved_indent = self.indent.clone();
+        self.pending_prefix = Some(format!("{}{}", self.indent, marker_text));
+        self.indent = format!("{}{}", self.indent, " ".repeat(marker_text.chars().count()));
+
+        if item.children.is_empty() {
+            // No block content to consume the pending marker - write it out directly.
+            self.write_indent();
+            self.output.push('\n');
+        } else {
+            self.render_item_blocks(&item.children);
+        }
+
+        self.indent = saved_indent;
+    }
+
+    fn render_code(&mut self, code: &markdown::mdast::Code) {
+        let fence_len = (longest_backtick_run(&code.value) + 1).max(3);
+        let fence = "`".repeat(fence_len);
+
+        self.write_indent();
+        self.output.push_str(&fence);
+        if let Some(lang) = &code.lang {
+            self.output.push_str(lang);
+        }
+        if let Some(meta) = &code.meta {
+            self.output.push(' ');
+            self.output.push_str(meta);
+        }
+        self.output.push('\n');
+
+        for line in code.value.lines() {
+            self.output.push_str(&self.indent);
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+
+        self.output.push_str(&self.indent);
+        self.output.push_str(&fence);
+        self.output.push('\n');
+    }
+
+    fn render_table(&mut self, table: &markdown::mdast::Table) {
+        let mut rows = table.children.iter();
+
+        if let Some(MarkdownAst::TableRow(header)) = rows.next() {
+            self.write_indent();
+            self.render_table_row(header);
+            self.output.push('\n');
+
+            self.write_indent();
+            self.output.push('|');
+            for align in &table.align {
+                let cell = match align {
+                    markdown::mdast::AlignKind::Left => ":---",
+                    markdown::mdast::AlignKind::Right => "---:",
+                    markdown::mdast::AlignKind::Center => ":---:",
+                    markdown::mdast::AlignKind::None => "---",
+                };
+                self.output.push_str(cell);
+                self.output.push('|');
+            }
+            self.output.push('\n');
+        }
+
+        for row in rows {
+            if let MarkdownAst::TableRow(row) = row {
+                self.write_indent();
+                self.render_table_row(row);
+                self.output.push('\n');
+            }
+        }
+    }
+
+    fn render_table_row(&mut self, row: &markdown::mdast::TableRow) {
+        self.output.push('|');
+        for cell in &row.children {
+            if let MarkdownAst::TableCell(cell) = cell {
+                self.output.push(' ');
+                self.output
+                    .push_str(&render_inline_children(&cell.children).replace('|', "\\|"));
+                self.output.push_str(" |");
+            }
+        }
+    }
+}
+
+/// Render a sequence of inline nodes (text, emphasis, links, etc.) to a single string.
+fn render_inline_children(children: &[MarkdownAst]) -> String {
+    children.iter().map(render_inline).collect()
+}
+
+fn render_inline(node: &MarkdownAst) -> String {
+    match node {
+        MarkdownAst::Text(text) => escape_text(&text.value),
+        MarkdownAst::Emphasis(e) => format!("*{}*", render_inline_children(&e.children)),
+        MarkdownAst::Strong(s) => format!("**{}**", render_inline_children(&s.children)),
+        MarkdownAst::Delete(d) => format!("~~{}~~", render_inline_children(&d.children)),
+        MarkdownAst::InlineCode(code) => {
+            let fence = "`".repeat(longest_backtick_run(&code.value) + 1);
+            format!("{fence}{}{fence}", code.value)
+        }
+        MarkdownAst::Link(link) => {
+            let text = render_inline_children(&link.children);
+            match &link.title {
+                Some(title) => format!("[{text}]({} \"{title}\")", link.url),
+                None => format!("[{text}]({})", link.url),
+            }
+        }
+        MarkdownAst::Image(image) => match &image.title {
+            Some(title) => format!("![{}]({} \"{title}\")", image.alt, image.url),
+            None => format!("![{}]({})", image.alt, image.url),
+        },
+        MarkdownAst::Break(_) => "  \n".to_string(),
+        MarkdownAst::Html(html) => html.value.clone(),
+        _ => {
+            if let Some(children) = node.children() {
+                render_inline_children(children)
+            } else {
+                String::new()
+            }
+        }
+    }
+}
+
+/// Escape characters in literal text that would otherwise be re-parsed as markup:
+/// backslashes, emphasis/strong/inline-code delimiters, link/image brackets, `<`
+/// and `>` anywhere, and a leading `#` or `-`.
+fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, ch) in text.chars().enumerate() {
+        let needs_escape = matches!(ch, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>')
+            || (i == 0 && matches!(ch, '#' | '-' | '>'));
+        if needs_escape {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+/// Length of the longest run of consecutive backticks in `value`.
+fn longest_backtick_run(value: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for ch in value.chars() {
+        if ch == '`' {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
+
+/// Render a [`MarkdownAst`] back to CommonMark/GFM text.
+///
+/// This is the inverse of [`parse_markdown_ast`]: it walks the tree emitting one
+/// handler per node variant so rules can transform the AST (reorder headings, rewrite
+/// links, etc.) and write the result out instead of doing string surgery on the
+/// original source.
+pub fn serialize_ast(ast: &MarkdownAst, options: &SerializeOptions) -> String {
+    let mut serializer = AstSerializer::new(options.clone());
+    serializer.render_node(ast);
+    serializer.output
+}
+
 /// Utility function to get text content from AST nodes
 pub fn get_text_content(ast: &MarkdownAst) -> String {
     match ast {
@@ -261,6 +754,331 @@ pub fn get_text_content(ast: &MarkdownAst) -> String {
     }
 }
 
+/// Lowercase `text`, drop everything that isn't alphanumeric/space/hyphen, and
+/// collapse runs of spaces into a single hyphen - the slug half of GitHub's
+/// heading-anchor algorithm.
+fn slugify(text: &str) -> String {
+    let filtered: String = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ' || *c == '-')
+        .collect();
+
+    let mut slug = String::with_capacity(filtered.len());
+    let mut last_was_space = false;
+    for ch in filtered.chars() {
+        if ch == ' ' {
+            if !last_was_space {
+                slug.push('-');
+            }
+            last_was_space = true;
+        } else {
+            slug.push(ch);
+            last_was_space = false;
+        }
+    }
+    slug
+}
+
+/// De-duplicates heading slugs the way GitHub and rustdoc do: the first heading
+/// with a given base slug keeps it unchanged; every later collision gets a
+/// `-{n}` suffix, with `n` picking up where the last collision for that base left off.
+#[derive(Debug, Default)]
+pub struct IdMap {
+    seen: HashMap<String, usize>,
+}
+
+impl IdMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Slugify `text` and return a unique anchor, recording it so later calls
+    /// with the same base text collide against it.
+    pub fn unique_slug(&mut self, text: &str) -> String {
+        let base = slugify(text);
+        match self.seen.get(&base).copied() {
+            None => {
+                self.seen.insert(base.clone(), 1);
+                base
+            }
+            Some(mut n) => {
+                let mut candidate = format!("{base}-{n}");
+                while self.seen.contains_key(&candidate) {
+                    n += 1;
+                    candidate = format!("{base}-{n}");
+                }
+                self.seen.insert(base, n + 1);
+                self.seen.insert(candidate.clone(), 1);
+                candidate
+            }
+        }
+    }
+}
+
+/// Collect every heading's text and its unique, GitHub-style anchor slug, in
+/// document order - useful for rules that verify `[text](#fragment)` links
+/// actually resolve to a heading.
+pub fn heading_slugs(ast: &MarkdownAst) -> Vec<(String, String)> {
+    let mut id_map = IdMap::new();
+    let mut results = Vec::new();
+    collect_heading_slugs(ast, &mut id_map, &mut results);
+    results
+}
+
+fn collect_heading_slugs(node: &MarkdownAst, id_map: &mut IdMap, out: &mut Vec<(String, String)>) {
+    if matches!(node, MarkdownAst::Heading(_)) {
+        let text = get_text_content(node);
+        let slug = id_map.unique_slug(&text);
+        out.push((text, slug));
+    }
+    if let Some(children) = node.children() {
+        for child in children {
+            collect_heading_slugs(child, id_map, out);
+        }
+    }
+}
+
+/// Structured form of a fenced code block's info string (the text after the
+/// opening fence, e.g. ` ```rust no_run `), as parsed by [`parse_info_string`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CodeFenceInfo {
+    /// The language token, with any `.`/`{}` wrapping stripped.
+    pub lang: Option<String>,
+    /// Bare directive tokens (e.g. `no_run`, `should_panic`, `ignore-windows`).
+    pub flags: HashSet<String>,
+    /// `key=value` / `key="quoted value"` attributes, with surrounding quotes stripped.
+    pub attributes: HashMap<String, String>,
+}
+
+impl CodeFenceInfo {
+    /// Whether `lang` names Rust, under either of its common spellings.
+    pub fn is_rust(&self) -> bool {
+        matches!(self.lang.as_deref(), Some("rust") | Some("rs"))
+    }
+
+    /// Whether this block should be skipped by a doc-test runner: either a bare
+    /// `ignore` flag, or a target-scoped `ignore-<target>` flag.
+    pub fn is_ignored(&self) -> bool {
+        self.flags.iter().any(|flag| flag == "ignore" || flag.starts_with("ignore-"))
+    }
+}
+
+/// Split a fenced code block's `lang`/`meta` strings (as stored on an mdast
+/// [`markdown::mdast::Code`] node) into structured metadata, rustdoc-style: the
+/// language token tolerates a leading `.` (`.rust`) or `{}` wrapping (`{rust}`,
+/// `{.rust}`), and the remainder is tokenized into bare flags and `key=value` /
+/// `key="quoted value"` attributes (quoted values may contain spaces).
+pub fn parse_info_string(lang: Option<&str>, meta: Option<&str>) -> CodeFenceInfo {
+    let mut info = CodeFenceInfo::default();
+
+    if let Some(raw_lang) = lang {
+        let trimmed = raw_lang.trim();
+        let unbraced = trimmed
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .unwrap_or(trimmed);
+        let bare = unbraced.strip_prefix('.').unwrap_or(unbraced);
+        if !bare.is_empty() {
+            info.lang = Some(bare.to_string());
+        }
+    }
+
+    if let Some(meta) = meta {
+        for token in tokenize_info_meta(meta) {
+            match token.split_once('=') {
+                Some((key, value)) => {
+                    info.attributes.insert(key.to_string(), value.trim_matches('"').to_string());
+                }
+                None => {
+                    info.flags.insert(token);
+                }
+            }
+        }
+    }
+
+    info
+}
+
+/// Split a meta string on whitespace, keeping `key="quoted value"` tokens intact
+/// even when the quoted value itself contains spaces.
+fn tokenize_info_meta(meta: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for ch in meta.chars() {
+        match ch {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(ch);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// A single point of disagreement between the `markdown`-crate AST and a
+/// second CommonMark parser, as reported by [`cross_check_ast`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserDivergence {
+    /// 1-indexed source line the disagreement starts on.
+    pub line: usize,
+    /// The construct kind that disagreed (e.g. `"emphasis"`, `"link"`).
+    pub kind: String,
+    /// How many times `markdown-rs` saw `kind` start on this line.
+    pub markdown_rs_saw: usize,
+    /// How many times the cross-check parser saw `kind` start on this line.
+    pub other_saw: usize,
+}
+
+/// Per-line counts of normalized construct kinds, keyed by line then kind.
+/// Used by [`cross_check_ast`] to compare what each parser saw without caring
+/// about node identity, only about how many of each kind started on a line.
+type ParseEventCounts = HashMap<usize, HashMap<&'static str, usize>>;
+
+/// Cross-check `content` against a second CommonMark parser (`pulldown-cmark`)
+/// and report structural divergences from the `markdown`-crate AST that
+/// rumdl's rules actually see. Parser disagreements silently change what
+/// rules operate on; rustdoc historically ran two Markdown engines side by
+/// side and warned when they diverged, which is the model this follows.
+///
+/// Divergences are reported per line and construct kind (e.g. `"emphasis"`,
+/// `"link"`), covering cases like a link the two parsers delimit differently
+/// or an emphasis run one closes and the other doesn't. This is a diagnostic
+/// aid, not part of the normal lint path: rumdl always lints against the
+/// `markdown`-crate AST regardless of what this reports.
+pub fn cross_check_ast(content: &str) -> Vec<ParserDivergence> {
+    let ast = parse_markdown_ast(content);
+    let mut markdown_rs_counts = ParseEventCounts::new();
+    collect_markdown_rs_events(&ast, &mut markdown_rs_counts);
+
+    let other_counts = collect_pulldown_events(content);
+
+    let mut lines: Vec<usize> = markdown_rs_counts
+        .keys()
+        .chain(other_counts.keys())
+        .copied()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    lines.sort_unstable();
+
+    let mut divergences = Vec::new();
+    for line in lines {
+        let empty = HashMap::new();
+        let ours = markdown_rs_counts.get(&line).unwrap_or(&empty);
+        let theirs = other_counts.get(&line).unwrap_or(&empty);
+
+        let mut kinds: Vec<&&str> = ours.keys().chain(theirs.keys()).collect::<HashSet<_>>().into_iter().collect();
+        kinds.sort_unstable();
+
+        for kind in kinds {
+            let markdown_rs_saw = ours.get(kind).copied().unwrap_or(0);
+            let other_saw = theirs.get(kind).copied().unwrap_or(0);
+            if markdown_rs_saw != other_saw {
+                divergences.push(ParserDivergence {
+                    line,
+                    kind: (*kind).to_string(),
+                    markdown_rs_saw,
+                    other_saw,
+                });
+            }
+        }
+    }
+
+    divergences
+}
+
+/// Map a node to the normalized construct kind [`cross_check_ast`] compares
+/// across parsers, or `None` for node types that don't have a directly
+/// comparable `pulldown-cmark` event (e.g. frontmatter, MDX).
+fn markdown_rs_kind(node: &MarkdownAst) -> Option<&'static str> {
+    match node {
+        MarkdownAst::Heading(_) => Some("heading"),
+        MarkdownAst::Paragraph(_) => Some("paragraph"),
+        MarkdownAst::Blockquote(_) => Some("blockquote"),
+        MarkdownAst::List(_) => Some("list"),
+        MarkdownAst::Code(_) => Some("code"),
+        MarkdownAst::Emphasis(_) => Some("emphasis"),
+        MarkdownAst::Strong(_) => Some("strong"),
+        MarkdownAst::Link(_) => Some("link"),
+        MarkdownAst::Image(_) => Some("image"),
+        MarkdownAst::InlineCode(_) => Some("inline_code"),
+        MarkdownAst::Delete(_) => Some("strikethrough"),
+        MarkdownAst::Table(_) => Some("table"),
+        _ => None,
+    }
+}
+
+fn collect_markdown_rs_events(node: &MarkdownAst, counts: &mut ParseEventCounts) {
+    if let Some(kind) = markdown_rs_kind(node)
+        && let Some(position) = node.position()
+    {
+        *counts.entry(position.start.line).or_default().entry(kind).or_insert(0) += 1;
+    }
+
+    if let Some(children) = node.children() {
+        for child in children {
+            collect_markdown_rs_events(child, counts);
+        }
+    }
+}
+
+/// Parse `content` with `pulldown-cmark`, under the same GFM-ish construct
+/// set `markdown-rs` is parsed with (tables, footnotes, strikethrough, task
+/// lists), and bucket construct starts by line for comparison.
+fn collect_pulldown_events(content: &str) -> ParseEventCounts {
+    use pulldown_cmark::{Event, Options, Parser, Tag};
+
+    let line_offsets: Vec<usize> = std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_GFM);
+
+    let mut counts = ParseEventCounts::new();
+
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        let kind = match event {
+            Event::Start(Tag::Heading { .. }) => Some("heading"),
+            Event::Start(Tag::Paragraph) => Some("paragraph"),
+            Event::Start(Tag::BlockQuote(_)) => Some("blockquote"),
+            Event::Start(Tag::List(_)) => Some("list"),
+            Event::Start(Tag::CodeBlock(_)) => Some("code"),
+            Event::Start(Tag::Emphasis) => Some("emphasis"),
+            Event::Start(Tag::Strong) => Some("strong"),
+            Event::Start(Tag::Link { .. }) => Some("link"),
+            Event::Start(Tag::Image { .. }) => Some("image"),
+            Event::Code(_) => Some("inline_code"),
+            Event::Start(Tag::Strikethrough) => Some("strikethrough"),
+            Event::Start(Tag::Table(_)) => Some("table"),
+            _ => None,
+        };
+
+        let Some(kind) = kind else { continue };
+        let line_idx = line_offsets.partition_point(|&offset| offset <= range.start).saturating_sub(1);
+        *counts.entry(line_idx + 1).or_default().entry(kind).or_insert(0) += 1;
+    }
+
+    counts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,8 +1097,8 @@ mod tests {
 
         // Test usage stats
         let stats = cache.get_stats();
-        let content_hash = crate::utils::fast_hash(content);
-        assert_eq!(stats.get(&content_hash), Some(&2));
+        let cache_key = AstCache::cache_key(content, &ParseProfile::default());
+        assert_eq!(stats.get(&cache_key), Some(&2));
     }
 
     #[test]
@@ -300,8 +1118,8 @@ mod tests {
         assert_eq!(cache.len(), 3); // Still 3 documents
 
         let stats = cache.get_stats();
-        let hash1 = crate::utils::fast_hash(content1);
-        assert_eq!(stats.get(&hash1), Some(&2)); // Accessed twice
+        let key1 = AstCache::cache_key(content1, &ParseProfile::default());
+        assert_eq!(stats.get(&key1), Some(&2)); // Accessed twice
     }
 
     #[test]
@@ -319,6 +1137,59 @@ mod tests {
         assert!(cache.get_stats().is_empty());
     }
 
+    #[test]
+    fn test_ast_cache_evicts_least_recently_used() {
+        let mut cache = AstCache::with_capacity(2);
+        cache.get_or_parse("# One");
+        cache.get_or_parse("# Two");
+        // Re-access "# One" so "# Two" becomes the least-recently-used entry.
+        cache.get_or_parse("# One");
+        // Inserting a third document should evict "# Two", not "# One".
+        cache.get_or_parse("# Three");
+
+        assert_eq!(cache.len(), 2);
+        let key_one = AstCache::cache_key("# One", &ParseProfile::default());
+        let key_two = AstCache::cache_key("# Two", &ParseProfile::default());
+        let key_three = AstCache::cache_key("# Three", &ParseProfile::default());
+        assert!(cache.cache.contains_key(&key_one), "recently re-accessed entry should survive");
+        assert!(!cache.cache.contains_key(&key_two), "least-recently-used entry should be evicted");
+        assert!(cache.cache.contains_key(&key_three));
+    }
+
+    #[test]
+    fn test_ast_cache_usage_stats_survive_eviction() {
+        let mut cache = AstCache::with_capacity(1);
+        cache.get_or_parse("# One");
+        cache.get_or_parse("# Two"); // evicts "# One" from `cache`, not from `usage_stats`
+
+        let key_one = AstCache::cache_key("# One", &ParseProfile::default());
+        assert_eq!(cache.get_stats().get(&key_one), Some(&1));
+    }
+
+    #[test]
+    fn test_ast_cache_set_capacity_evicts_immediately() {
+        let mut cache = AstCache::new();
+        cache.get_or_parse("# One");
+        cache.get_or_parse("# Two");
+        cache.get_or_parse("# Three");
+        assert_eq!(cache.len(), 3);
+
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_ast_cache_hit_rate() {
+        let mut cache = AstCache::new();
+        assert_eq!(cache.hit_rate(), 0.0);
+
+        cache.get_or_parse("# One"); // miss
+        cache.get_or_parse("# One"); // hit
+        cache.get_or_parse("# One"); // hit
+
+        assert!((cache.hit_rate() - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_parse_markdown_ast() {
         let content = "# Hello World\n\nThis is a test.";
@@ -327,6 +1198,34 @@ mod tests {
         assert!(matches!(ast, MarkdownAst::Root(_)));
     }
 
+    #[test]
+    fn test_distinct_profiles_produce_distinct_cache_entries() {
+        let mut cache = AstCache::new();
+        let content = "| a |\n| - |\n| b |\n";
+
+        let default_profile = ParseProfile::default();
+        let mut no_tables = ParseProfile::default();
+        no_tables.gfm_tables = false;
+
+        let with_tables = cache.get_or_parse_with(content, default_profile);
+        let without_tables = cache.get_or_parse_with(content, no_tables);
+
+        assert!(!Arc::ptr_eq(&with_tables, &without_tables));
+        assert_eq!(cache.len(), 2);
+        assert!(ast_contains_node_type(&with_tables, "table"));
+        assert!(!ast_contains_node_type(&without_tables, "table"));
+    }
+
+    #[test]
+    fn test_parse_profile_disables_strikethrough() {
+        let mut profile = ParseProfile::default();
+        profile.strikethrough = false;
+        let ast = parse_markdown_ast_with("a ~~b~~ c\n", profile);
+        // With strikethrough off, `~~b~~` is left as literal text rather than a
+        // `Delete` node.
+        assert!(!format!("{ast:?}").contains("Delete"));
+    }
+
     #[test]
     fn test_problematic_list_detection() {
         // Mixed list markers that would cause panic
@@ -633,4 +1532,258 @@ code block
         let thematic_break = MarkdownAst::ThematicBreak(markdown::mdast::ThematicBreak { position: None });
         assert_eq!(get_text_content(&thematic_break), "");
     }
+
+    #[test]
+    fn test_serialize_paragraph_roundtrip() {
+        let ast = parse_markdown_ast("Hello world.\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "Hello world.\n");
+    }
+
+    #[test]
+    fn test_serialize_atx_heading_roundtrip() {
+        let ast = parse_markdown_ast("## Title\n\nBody text.\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "## Title\n\nBody text.\n");
+    }
+
+    #[test]
+    fn test_serialize_setext_heading() {
+        let ast = parse_markdown_ast("# Title\n");
+        let options = SerializeOptions {
+            setext_headings: true,
+            ..Default::default()
+        };
+        let out = serialize_ast(&ast, &options);
+        assert_eq!(out, "Title\n=====\n");
+    }
+
+    #[test]
+    fn test_serialize_unordered_list_roundtrip() {
+        let ast = parse_markdown_ast("- one\n- two\n- three\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "- one\n- two\n- three\n");
+    }
+
+    #[test]
+    fn test_serialize_ordered_list_preserves_start() {
+        let ast = parse_markdown_ast("3. one\n4. two\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "3. one\n4. two\n");
+    }
+
+    #[test]
+    fn test_serialize_nested_list_rotates_marker_and_indents() {
+        let ast = parse_markdown_ast("- one\n  - nested\n- two\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "- one\n  * nested\n- two\n");
+    }
+
+    #[test]
+    fn test_serialize_blockquote_prefixes_every_line() {
+        let ast = parse_markdown_ast("> quoted text\n> more\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "> quoted text\n> more\n");
+    }
+
+    #[test]
+    fn test_serialize_code_block_roundtrip() {
+        let ast = parse_markdown_ast("```rust\nfn main() {}\n```\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "```rust\nfn main() {}\n```\n");
+    }
+
+    #[test]
+    fn test_serialize_code_block_widens_fence_for_internal_backticks() {
+        let ast = parse_markdown_ast("````\ncode with ``` inside\n````\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "````\ncode with ``` inside\n````\n");
+    }
+
+    #[test]
+    fn test_serialize_emphasis_strong_inline_code_roundtrip() {
+        let ast = parse_markdown_ast("Some *em* and **strong** and `code`.\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "Some *em* and **strong** and `code`.\n");
+    }
+
+    #[test]
+    fn test_serialize_link_and_image_roundtrip() {
+        let ast = parse_markdown_ast("[text](https://example.com \"Title\") and ![alt](img.png)\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "[text](https://example.com \"Title\") and ![alt](img.png)\n");
+    }
+
+    #[test]
+    fn test_serialize_table_roundtrip() {
+        let ast = parse_markdown_ast("| a | b |\n| :-- | --: |\n| 1 | 2 |\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "| a | b |\n|:---|---:|\n| 1 | 2 |\n");
+    }
+
+    #[test]
+    fn test_serialize_escapes_leading_marker() {
+        let ast = parse_markdown_ast("\\# not a heading\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "\\# not a heading\n");
+    }
+
+    #[test]
+    fn test_serialize_thematic_break() {
+        let ast = parse_markdown_ast("---\n");
+        let out = serialize_ast(&ast, &SerializeOptions::default());
+        assert_eq!(out, "---\n");
+    }
+
+    #[test]
+    fn test_id_map_first_occurrence_unchanged() {
+        let mut map = IdMap::new();
+        assert_eq!(map.unique_slug("Notes"), "notes");
+    }
+
+    #[test]
+    fn test_id_map_duplicates_get_numeric_suffixes() {
+        let mut map = IdMap::new();
+        assert_eq!(map.unique_slug("Notes"), "notes");
+        assert_eq!(map.unique_slug("Notes"), "notes-1");
+        assert_eq!(map.unique_slug("Notes"), "notes-2");
+    }
+
+    #[test]
+    fn test_id_map_skips_slots_taken_by_literal_headings() {
+        let mut map = IdMap::new();
+        assert_eq!(map.unique_slug("Notes"), "notes");
+        // A heading literally titled "Notes 1" also slugifies to "notes-1", so the
+        // next duplicate of "Notes" must skip past it.
+        assert_eq!(map.unique_slug("Notes 1"), "notes-1");
+        assert_eq!(map.unique_slug("Notes"), "notes-2");
+    }
+
+    #[test]
+    fn test_id_map_strips_punctuation_and_collapses_spaces() {
+        let mut map = IdMap::new();
+        assert_eq!(map.unique_slug("Hello, World!!"), "hello-world");
+    }
+
+    #[test]
+    fn test_id_map_case_only_difference_collides() {
+        let mut map = IdMap::new();
+        assert_eq!(map.unique_slug("Set up"), "set-up");
+        assert_eq!(map.unique_slug("Set Up"), "set-up-1");
+    }
+
+    #[test]
+    fn test_heading_slugs_collects_in_document_order() {
+        let ast = parse_markdown_ast("# Notes\n\nSome text.\n\n## Notes\n\n### Usage\n");
+        let slugs = heading_slugs(&ast);
+        assert_eq!(
+            slugs,
+            vec![
+                ("Notes".to_string(), "notes".to_string()),
+                ("Notes".to_string(), "notes-1".to_string()),
+                ("Usage".to_string(), "usage".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heading_slugs_ignores_non_heading_text() {
+        let ast = parse_markdown_ast("Just a paragraph, no headings here.\n");
+        assert!(heading_slugs(&ast).is_empty());
+    }
+
+    #[test]
+    fn test_parse_info_string_plain_lang() {
+        let info = parse_info_string(Some("rust"), None);
+        assert_eq!(info.lang.as_deref(), Some("rust"));
+        assert!(info.is_rust());
+    }
+
+    #[test]
+    fn test_parse_info_string_dotted_and_braced_lang() {
+        assert_eq!(parse_info_string(Some(".rust"), None).lang.as_deref(), Some("rust"));
+        assert_eq!(parse_info_string(Some("{rust}"), None).lang.as_deref(), Some("rust"));
+        assert_eq!(parse_info_string(Some("{.rust}"), None).lang.as_deref(), Some("rust"));
+    }
+
+    #[test]
+    fn test_parse_info_string_flags_and_attributes() {
+        let info = parse_info_string(Some("rust"), Some("no_run should_panic edition=2021"));
+        assert!(info.flags.contains("no_run"));
+        assert!(info.flags.contains("should_panic"));
+        assert_eq!(info.attributes.get("edition").map(String::as_str), Some("2021"));
+    }
+
+    #[test]
+    fn test_parse_info_string_quoted_attribute_with_spaces() {
+        let info = parse_info_string(Some("rust"), Some(r#"title="Hello World" ignore"#));
+        assert_eq!(info.attributes.get("title").map(String::as_str), Some("Hello World"));
+        assert!(info.is_ignored());
+    }
+
+    #[test]
+    fn test_parse_info_string_ignore_target_flag() {
+        let info = parse_info_string(Some("rust"), Some("ignore-windows"));
+        assert!(info.is_ignored());
+    }
+
+    #[test]
+    fn test_parse_info_string_no_lang_no_meta() {
+        let info = parse_info_string(None, None);
+        assert_eq!(info.lang, None);
+        assert!(info.flags.is_empty());
+        assert!(info.attributes.is_empty());
+    }
+
+    #[test]
+    fn test_cross_check_ast_agrees_on_plain_document() {
+        let content = "# Heading\n\nA paragraph with **strong** and *emphasis* and a [link](url).\n";
+        let divergences = cross_check_ast(content);
+        assert!(divergences.is_empty(), "expected no divergences, got {divergences:?}");
+    }
+
+    #[test]
+    fn test_cross_check_ast_lone_underscore_no_emphasis_divergence() {
+        // `markdown-rs` treats an unmatched `_` as literal text with no emphasis run,
+        // while `pulldown-cmark` also requires a matching closer, so both should agree
+        // here (no emphasis node at all) -- this exercises the "equal, so no report" path.
+        let content = "this has a lone _ underscore\n";
+        let divergences = cross_check_ast(content);
+        assert!(
+            divergences.iter().all(|d| d.kind != "emphasis"),
+            "expected no emphasis divergence, got {divergences:?}"
+        );
+    }
+
+    #[test]
+    fn test_cross_check_ast_no_strikethrough_divergence() {
+        // Both parsers run with GFM strikethrough enabled (markdown-rs through
+        // `parse_markdown_ast`'s defaults), so `~~struck~~` is one strikethrough to
+        // each and must not be reported as a divergence.
+        let content = "~~struck~~ text\n";
+        let divergences = cross_check_ast(content);
+        assert!(
+            divergences.iter().all(|d| d.kind != "strikethrough"),
+            "expected no strikethrough divergence, got {divergences:?}"
+        );
+    }
+
+    #[test]
+    fn test_cross_check_ast_empty_document_has_no_divergences() {
+        assert!(cross_check_ast("").is_empty());
+    }
+
+    #[test]
+    fn test_cross_check_ast_divergence_reports_line_and_counts() {
+        // A link whose destination contains spaces and has no closing `)`; CommonMark
+        // makes this plain text, so the parsers may well agree on it.
+        let content = "[text](not a valid dest because of spaces\n";
+        let divergences = cross_check_ast(content);
+        // Whether or not this specific construct diverges depends on parser internals;
+        // what matters is that any reported divergence carries correct metadata.
+        for d in &divergences {
+            assert_eq!(d.line, 1);
+            assert_ne!(d.markdown_rs_saw, d.other_saw);
+        }
+    }
 }
@@ -0,0 +1,166 @@
+//! Serializable snapshot of the detected document structure.
+//!
+//! [`DocumentModel`] flattens the headings, blockquotes, HTML blocks, and
+//! thematic breaks discovered by the detection pipeline into a plain data
+//! structure that can be dumped as JSON (for editor/LSP integrations and
+//! scripting) or as a compact S-expression (a diffable form handy for debugging
+//! the detector and for golden tests of the parse itself).
+
+use crate::lint_context::LineInfo;
+use serde::Serialize;
+
+/// A detected heading.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelHeading {
+    pub line: usize,
+    pub level: u8,
+    pub text: String,
+}
+
+/// A detected blockquote line.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelBlockquote {
+    pub line: usize,
+    pub nesting_level: usize,
+    pub marker_column: usize,
+    pub prefix: String,
+}
+
+/// A detected HTML block, as a 1-indexed inclusive line range.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelHtmlBlock {
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// A serializable snapshot of the document's block structure.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DocumentModel {
+    pub headings: Vec<ModelHeading>,
+    pub blockquotes: Vec<ModelBlockquote>,
+    pub html_blocks: Vec<ModelHtmlBlock>,
+    pub horizontal_rules: Vec<usize>,
+}
+
+impl DocumentModel {
+    /// Build a model from a detected line slice.
+    pub fn from_lines(lines: &[LineInfo]) -> Self {
+        use super::document_visitor::{walk_document, DocumentVisitor};
+        use crate::lint_context::{BlockquoteInfo, HeadingInfo};
+
+        #[derive(Default)]
+        struct Builder {
+            model: DocumentModel,
+        }
+
+        impl DocumentVisitor for Builder {
+            fn heading(&mut self, info: &HeadingInfo, line: usize) {
+                self.model.headings.push(ModelHeading {
+                    line,
+                    level: info.level,
+                    text: info.text.clone(),
+                });
+            }
+            fn horizontal_rule(&mut self, line: usize) {
+                self.model.horizontal_rules.push(line);
+            }
+            fn html_block(&mut self, range: std::ops::Range<usize>) {
+                self.model.html_blocks.push(ModelHtmlBlock {
+                    start_line: range.start,
+                    end_line: range.end.saturating_sub(1),
+                });
+            }
+            fn blockquote_enter(&mut self, _info: &BlockquoteInfo, _level: usize) {}
+        }
+
+        let mut builder = Builder::default();
+        walk_document(lines, &mut builder);
+
+        // The visitor only reports nesting transitions; capture every blockquote
+        // line directly so the model carries per-line marker details.
+        for (idx, line) in lines.iter().enumerate() {
+            if let Some(bq) = line.blockquote.as_ref() {
+                builder.model.blockquotes.push(ModelBlockquote {
+                    line: idx + 1,
+                    nesting_level: bq.nesting_level,
+                    marker_column: bq.marker_column,
+                    prefix: bq.prefix.clone(),
+                });
+            }
+        }
+
+        builder.model
+    }
+
+    /// Serialize the model to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Serialize the model to a compact S-expression.
+    pub fn to_sexpr(&self) -> String {
+        let mut out = String::from("(document\n");
+        for h in &self.headings {
+            out.push_str(&format!(
+                "  (heading (line {}) (level {}) (text {:?}))\n",
+                h.line, h.level, h.text
+            ));
+        }
+        for bq in &self.blockquotes {
+            out.push_str(&format!(
+                "  (blockquote (line {}) (level {}) (marker-column {}) (prefix {:?}))\n",
+                bq.line, bq.nesting_level, bq.marker_column, bq.prefix
+            ));
+        }
+        for hb in &self.html_blocks {
+            out.push_str(&format!(
+                "  (html-block (start {}) (end {}))\n",
+                hb.start_line, hb.end_line
+            ));
+        }
+        for line in &self.horizontal_rules {
+            out.push_str(&format!("  (horizontal-rule (line {line}))\n"));
+        }
+        out.push_str(")\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use crate::lint_context::LintContext;
+
+    fn model(content: &str) -> DocumentModel {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        DocumentModel::from_lines(&ctx.lines)
+    }
+
+    #[test]
+    fn test_collects_headings_and_rules() {
+        let m = model("# Title\n\n---\n");
+        assert_eq!(m.headings.len(), 1);
+        assert_eq!(m.headings[0].text, "Title");
+        assert_eq!(m.horizontal_rules, vec![3]);
+    }
+
+    #[test]
+    fn test_collects_blockquotes() {
+        let m = model("> quoted\n");
+        assert_eq!(m.blockquotes.len(), 1);
+        assert_eq!(m.blockquotes[0].nesting_level, 1);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let m = model("# A\n");
+        assert!(m.to_json().contains("\"headings\""));
+    }
+
+    #[test]
+    fn test_sexpr_contains_heading() {
+        let m = model("# A\n");
+        assert!(m.to_sexpr().contains("(heading (line 1) (level 1)"));
+    }
+}
@@ -103,6 +103,26 @@ pub struct ListItem {
     pub content: String,
 }
 
+/// CommonMark HTML block type (§4.6). The variant determines the block's end
+/// condition, which is why it is threaded from start detection to end detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HtmlBlockType {
+    /// Type 1: `<script>`, `<pre>`, `<style>`, `<textarea>` — ends at the first line containing any of their four close tags.
+    RawText,
+    /// Type 2: `<!-- ... -->`.
+    Comment,
+    /// Type 3: `<? ... ?>`.
+    ProcessingInstruction,
+    /// Type 4: `<!DOCTYPE ...>` and other declarations — ends at `>`.
+    Declaration,
+    /// Type 5: `<![CDATA[ ... ]]>`.
+    Cdata,
+    /// Type 6: a recognized block-level tag — ends at a blank line.
+    BlockTag,
+    /// Type 7: any other complete tag alone on a line — ends at a blank line.
+    OtherTag,
+}
+
 /// Type of list marker
 #[derive(Debug, Clone, PartialEq)]
 pub enum ListMarkerType {
@@ -1079,12 +1099,12 @@ impl DocumentStructure {
                 continue;
             }
 
-            // Check for HTML block start conditions (simplified version of CommonMark)
-            if self.is_html_block_start(trimmed) {
+            // Check for HTML block start conditions (CommonMark §4.6, types 1–7)
+            if let Some(block_type) = self.classify_html_block_start(trimmed) {
                 let start_line = i;
 
-                // Find the end of the HTML block
-                let end_line = self.find_html_block_end(&lines, start_line);
+                // Find the end of the HTML block according to the type's end condition
+                let end_line = self.find_html_block_end(&lines, start_line, block_type);
 
                 // Mark all lines in the block as HTML
                 for line_idx in start_line..=end_line {
@@ -1101,36 +1121,65 @@ impl DocumentStructure {
         }
     }
 
-    /// Check if a line starts an HTML block
-    fn is_html_block_start(&self, trimmed: &str) -> bool {
+    /// Classify the CommonMark HTML block type (§4.6) that a line begins, if any.
+    ///
+    /// The seven types differ in both their start *and* end conditions, so the
+    /// type has to be carried through to [`find_html_block_end`].
+    fn classify_html_block_start(&self, trimmed: &str) -> Option<HtmlBlockType> {
         if trimmed.is_empty() || !trimmed.starts_with('<') {
-            return false;
+            return None;
         }
 
-        // Extract tag name
-        let mut chars = trimmed[1..].chars();
-        let mut tag_name = String::new();
+        let lower = trimmed.to_ascii_lowercase();
+
+        // Type 2: <!-- ... -->
+        if trimmed.starts_with("<!--") {
+            return Some(HtmlBlockType::Comment);
+        }
+        // Type 3: <? ... ?>
+        if trimmed.starts_with("<?") {
+            return Some(HtmlBlockType::ProcessingInstruction);
+        }
+        // Type 5: <![CDATA[ ... ]]>
+        if trimmed.starts_with("<![CDATA[") {
+            return Some(HtmlBlockType::Cdata);
+        }
+        // Type 4: <! followed by an ASCII letter (declarations like <!DOCTYPE)
+        if let Some(rest) = trimmed.strip_prefix("<!")
+            && rest.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        {
+            return Some(HtmlBlockType::Declaration);
+        }
+
+        // Type 1: <script, <pre, <style, <textarea followed by ws, >, or EOL
+        const RAW_TEXT: &[&str] = &["script", "pre", "style", "textarea"];
+        for name in RAW_TEXT {
+            if let Some(rest) = lower.strip_prefix('<').and_then(|s| s.strip_prefix(name))
+                && (rest.is_empty() || rest.starts_with([' ', '\t', '>']))
+            {
+                return Some(HtmlBlockType::RawText);
+            }
+        }
 
-        // Handle closing tags
+        // Extract the (possibly closing) tag name for types 6 and 7.
+        let mut chars = trimmed[1..].chars();
         let is_closing = chars.as_str().starts_with('/');
         if is_closing {
-            chars.next(); // Skip the '/'
+            chars.next();
         }
-
-        // Extract tag name
+        let mut tag_name = String::new();
         for ch in chars {
-            if ch.is_ascii_alphabetic() || ch == '-' {
+            if ch.is_ascii_alphanumeric() || ch == '-' {
                 tag_name.push(ch);
             } else {
                 break;
             }
         }
-
         if tag_name.is_empty() {
-            return false;
+            return None;
         }
 
-        // List of HTML block elements (based on CommonMark and markdownlint)
+        // Type 6: a recognized block element, followed by ws, >, />, or EOL.
         const BLOCK_ELEMENTS: &[&str] = &[
             "address", "article", "aside", "base", "basefont", "blockquote", "body",
             "caption", "center", "col", "colgroup", "dd", "details", "dialog", "dir",
@@ -1139,69 +1188,55 @@ impl DocumentStructure {
             "hr", "html", "iframe", "legend", "li", "link", "main", "menu", "menuitem",
             "nav", "noframes", "ol", "optgroup", "option", "p", "param", "section",
             "source", "summary", "table", "tbody", "td", "tfoot", "th", "thead",
-            "title", "tr", "track", "ul", "img", "picture"
+            "title", "tr", "track", "ul", "img", "picture",
         ];
+        if BLOCK_ELEMENTS.contains(&tag_name.to_ascii_lowercase().as_str()) {
+            return Some(HtmlBlockType::BlockTag);
+        }
 
-        BLOCK_ELEMENTS.contains(&tag_name.to_ascii_lowercase().as_str())
-    }
-
-    /// Find the end line of an HTML block starting at start_line
-    fn find_html_block_end(&self, lines: &[&str], start_line: usize) -> usize {
-        let start_trimmed = lines[start_line].trim_start();
-
-        // Extract the tag name from the start line
-        let tag_name = self.extract_tag_name(start_trimmed);
+        // Type 7: any other complete open or closing tag alone on the line.
+        // Require that the tag closes on this line to avoid swallowing inline HTML.
+        if trimmed.ends_with('>') || trimmed.ends_with("/>") {
+            return Some(HtmlBlockType::OtherTag);
+        }
 
-        // Look for the closing tag or blank line
-        for i in (start_line + 1)..lines.len() {
-            let line = lines[i];
-            let trimmed = line.trim();
+        None
+    }
 
-            // HTML block ends on blank line
-            if trimmed.is_empty() {
-                return i - 1; // Don't include the blank line
-            }
+    /// Find the end line of an HTML block, honoring the type's end condition.
+    fn find_html_block_end(&self, lines: &[&str], start_line: usize, block_type: HtmlBlockType) -> usize {
+        // Types 1–5 end on the line that contains their closing marker (which may
+        // be the start line itself); types 6 and 7 end at the next blank line.
+        let end_marker: Option<&[&str]> = match block_type {
+            HtmlBlockType::RawText => Some(&["</script>", "</pre>", "</style>", "</textarea>"]),
+            HtmlBlockType::Comment => Some(&["-->"]),
+            HtmlBlockType::ProcessingInstruction => Some(&["?>"]),
+            HtmlBlockType::Declaration => Some(&[">"]),
+            HtmlBlockType::Cdata => Some(&["]]>"]),
+            HtmlBlockType::BlockTag | HtmlBlockType::OtherTag => None,
+        };
 
-            // HTML block ends when we find the matching closing tag
-            if let Some(ref tag) = tag_name {
-                let closing_tag = format!("</{}", tag);
-                if trimmed.contains(&closing_tag) {
+        if let Some(markers) = end_marker {
+            for (i, line) in lines.iter().enumerate().skip(start_line) {
+                let haystack = if block_type == HtmlBlockType::RawText {
+                    line.to_ascii_lowercase()
+                } else {
+                    line.to_string()
+                };
+                if markers.iter().any(|m| haystack.contains(m)) {
                     return i;
                 }
             }
+            return lines.len() - 1;
         }
 
-        // If no end found, block continues to end of document
-        lines.len() - 1
-    }
-
-    /// Extract tag name from an HTML line
-    fn extract_tag_name(&self, trimmed: &str) -> Option<String> {
-        if !trimmed.starts_with('<') {
-            return None;
-        }
-
-        let mut chars = trimmed[1..].chars();
-
-        // Skip closing tag indicator
-        if chars.as_str().starts_with('/') {
-            chars.next();
-        }
-
-        let mut tag_name = String::new();
-        for ch in chars {
-            if ch.is_ascii_alphabetic() || ch == '-' {
-                tag_name.push(ch);
-            } else {
-                break;
+        // Types 6 and 7: end on the line before the next blank line.
+        for i in (start_line + 1)..lines.len() {
+            if lines[i].trim().is_empty() {
+                return i - 1;
             }
         }
-
-        if tag_name.is_empty() {
-            None
-        } else {
-            Some(tag_name.to_ascii_lowercase())
-        }
+        lines.len() - 1
     }
 
     /// Check if a position is inside a code span
@@ -0,0 +1,194 @@
+//! Structural visitor over detected document lines.
+//!
+//! The detection pipeline in [`crate::lint_context`] fills each [`LineInfo`]
+//! with [`HeadingInfo`] and [`BlockquoteInfo`], but consumers can otherwise
+//! only poll the slice line-by-line. This module exposes a push-style
+//! [`DocumentVisitor`] trait — modelled on orgize's `HtmlHandler` callbacks and
+//! comrak's AST traversal — together with a [`walk_document`] driver that turns
+//! the flat line slice into enter/exit events. It lets TOC extractors, outline
+//! renderers, and external linters reuse rumdl's block structure without
+//! re-deriving it.
+
+use crate::lint_context::{BlockquoteInfo, HeadingInfo, LineInfo};
+use std::ops::Range;
+
+/// A handler that receives structural events as the document is walked.
+///
+/// Every method has a default no-op implementation, so a visitor only overrides
+/// the events it cares about. Line numbers are 1-indexed, matching the rest of
+/// the lint API.
+pub trait DocumentVisitor {
+    /// Called for each heading line.
+    fn heading(&mut self, _info: &HeadingInfo, _line: usize) {}
+
+    /// Called when blockquote nesting deepens to `level` (1 for the first `>`).
+    ///
+    /// Each step of an increase is reported separately, so moving from top
+    /// level into `>>` emits `blockquote_enter(1)` then `blockquote_enter(2)`.
+    fn blockquote_enter(&mut self, _info: &BlockquoteInfo, _level: usize) {}
+
+    /// Called when blockquote nesting shrinks past `level`.
+    ///
+    /// As with [`DocumentVisitor::blockquote_enter`], each closed level is
+    /// reported separately and in decreasing order.
+    fn blockquote_exit(&mut self, _level: usize) {}
+
+    /// Called once per contiguous HTML block with its 1-indexed line range.
+    fn html_block(&mut self, _range: Range<usize>) {}
+
+    /// Called for each thematic break (horizontal rule).
+    fn horizontal_rule(&mut self, _line: usize) {}
+}
+
+/// Return `true` if `line` is a thematic break (`---`, `***`, `___`).
+fn is_horizontal_rule(line: &str) -> bool {
+    let trimmed = line.trim();
+    if trimmed.len() < 3 {
+        return false;
+    }
+    let Some(&marker) = trimmed.chars().collect::<Vec<_>>().first() else {
+        return false;
+    };
+    if marker != '-' && marker != '*' && marker != '_' {
+        return false;
+    }
+    let marker_count = trimmed.chars().filter(|&c| c == marker).count();
+    let other_count = trimmed.chars().filter(|&c| c != marker && c != ' ').count();
+    marker_count >= 3 && other_count == 0
+}
+
+/// Return `true` if `line` looks like the start of an HTML block.
+fn looks_like_html(line: &str) -> bool {
+    line.trim_start().starts_with('<')
+}
+
+/// Drive `visitor` over the detected structure of `lines`.
+///
+/// Blockquote nesting transitions are derived from
+/// [`BlockquoteInfo::nesting_level`]; headings, thematic breaks, and HTML blocks
+/// are emitted in document order.
+pub fn walk_document(lines: &[LineInfo], visitor: &mut impl DocumentVisitor) {
+    let mut bq_level = 0usize;
+    let mut html_start: Option<usize> = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let line_num = idx + 1;
+
+        // An open HTML block runs until a blank line (CommonMark type 6/7).
+        if html_start.is_some() && (line.is_blank || line.in_code_block) {
+            if let Some(start) = html_start.take() {
+                visitor.html_block(start..line_num);
+            }
+        }
+
+        // Blockquote enter/exit based on nesting transitions.
+        let level = line.blockquote.as_ref().map(|bq| bq.nesting_level).unwrap_or(0);
+        if level > bq_level {
+            if let Some(bq) = line.blockquote.as_ref() {
+                for l in bq_level + 1..=level {
+                    visitor.blockquote_enter(bq, l);
+                }
+            }
+        } else if level < bq_level {
+            for l in (level + 1..=bq_level).rev() {
+                visitor.blockquote_exit(l);
+            }
+        }
+        bq_level = level;
+
+        if let Some(heading) = line.heading.as_ref() {
+            visitor.heading(heading, line_num);
+        } else if !line.in_code_block && line.blockquote.is_none() && is_horizontal_rule(&line.content) {
+            visitor.horizontal_rule(line_num);
+        } else if !line.in_code_block
+            && line.blockquote.is_none()
+            && html_start.is_none()
+            && looks_like_html(&line.content)
+        {
+            html_start = Some(line_num);
+        }
+    }
+
+    // Flush trailing state.
+    if bq_level > 0 {
+        for l in (1..=bq_level).rev() {
+            visitor.blockquote_exit(l);
+        }
+    }
+    if let Some(start) = html_start.take() {
+        visitor.html_block(start..lines.len() + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use crate::lint_context::LintContext;
+
+    #[derive(Default)]
+    struct Collector {
+        headings: Vec<(usize, usize)>,
+        enters: Vec<usize>,
+        exits: Vec<usize>,
+        hrs: Vec<usize>,
+        html_blocks: Vec<Range<usize>>,
+    }
+
+    impl DocumentVisitor for Collector {
+        fn heading(&mut self, info: &HeadingInfo, line: usize) {
+            self.headings.push((line, info.level as usize));
+        }
+        fn blockquote_enter(&mut self, _info: &BlockquoteInfo, level: usize) {
+            self.enters.push(level);
+        }
+        fn blockquote_exit(&mut self, level: usize) {
+            self.exits.push(level);
+        }
+        fn horizontal_rule(&mut self, line: usize) {
+            self.hrs.push(line);
+        }
+        fn html_block(&mut self, range: Range<usize>) {
+            self.html_blocks.push(range);
+        }
+    }
+
+    fn walk(content: &str) -> Collector {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let mut collector = Collector::default();
+        walk_document(&ctx.lines, &mut collector);
+        collector
+    }
+
+    #[test]
+    fn test_emits_headings() {
+        let c = walk("# One\n\n## Two\n");
+        assert_eq!(c.headings, vec![(1, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn test_blockquote_enter_exit() {
+        let c = walk("> quoted\n\ntext\n");
+        assert_eq!(c.enters, vec![1]);
+        assert_eq!(c.exits, vec![1]);
+    }
+
+    #[test]
+    fn test_nested_blockquote_transitions() {
+        let c = walk(">> deep\n");
+        assert_eq!(c.enters, vec![1, 2]);
+        assert_eq!(c.exits, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_horizontal_rule() {
+        let c = walk("text\n\n---\n");
+        assert_eq!(c.hrs, vec![3]);
+    }
+
+    #[test]
+    fn test_html_block_range() {
+        let c = walk("<div>\n<p>hi</p>\n</div>\n\nafter\n");
+        assert_eq!(c.html_blocks, vec![1..4]);
+    }
+}
@@ -3,8 +3,10 @@
 //! This module provides shared logic for applying markdown fixes to ensure
 //! that both CLI batch fixes and LSP individual fixes produce identical results.
 
-use crate::rule::{Fix, LintWarning};
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintWarning, Rule};
 use crate::utils::ensure_consistent_line_endings;
+use std::collections::HashSet;
 
 /// Apply a list of warning fixes to content, simulating how the LSP client would apply them
 /// This is used for testing consistency between CLI and LSP fix methods
@@ -91,6 +93,89 @@ pub fn apply_warning_fixes(content: &str, warnings: &[LintWarning]) -> Result<St
     Ok(ensure_consistent_line_endings(content, &result))
 }
 
+/// A rule whose fixes failed to reach a stable fixed point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonConvergentRule {
+    /// Name of the rule that kept changing the document.
+    pub rule_name: String,
+    /// Why convergence failed (oscillation vs. exceeded the pass limit).
+    pub reason: String,
+}
+
+/// Apply `rules`' fixes repeatedly until the document stops changing, a maximum
+/// number of passes is reached, or oscillation is detected.
+///
+/// This mirrors rustfmt's stability guarantee: fixing an already-fixed document
+/// should be a no-op. Each pass applies every rule's `fix` in order and
+/// re-checks for further changes. To avoid looping forever when two rules
+/// undo each other's work, the hash of every intermediate document is recorded;
+/// if a hash repeats, the rules that changed the document in that pass are
+/// reported as oscillating rather than being re-applied indefinitely.
+///
+/// Returns the best-effort fixed content plus the list of rules that did not
+/// converge (empty when a stable fixed point was reached).
+pub fn fix_until_stable(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    max_passes: usize,
+) -> (String, Vec<NonConvergentRule>) {
+    let mut current = content.to_string();
+    let mut seen: HashSet<u64> = HashSet::new();
+    seen.insert(crate::utils::fast_hash(&current));
+
+    for _ in 0..max_passes.max(1) {
+        let mut next = current.clone();
+        let mut changed_this_pass: Vec<String> = Vec::new();
+
+        for rule in rules {
+            let ctx = LintContext::new(&next);
+            if let Ok(fixed) = rule.fix(&ctx) {
+                if fixed != next {
+                    changed_this_pass.push(rule.name().to_string());
+                    next = fixed;
+                }
+            }
+        }
+
+        // A pass that changed nothing means we have reached a stable fixed point.
+        if next == current {
+            return (next, Vec::new());
+        }
+
+        let hash = crate::utils::fast_hash(&next);
+        if !seen.insert(hash) {
+            // We have produced this document before: the rules that changed it
+            // this pass form an oscillating cycle.
+            let non_convergent = changed_this_pass
+                .into_iter()
+                .map(|rule_name| NonConvergentRule {
+                    rule_name,
+                    reason: "oscillating fix (re-produced an earlier document)".to_string(),
+                })
+                .collect();
+            return (next, non_convergent);
+        }
+
+        current = next;
+    }
+
+    // Exhausted the pass budget while still changing: report whatever rules are
+    // still emitting fixes as non-convergent.
+    let mut non_convergent = Vec::new();
+    for rule in rules {
+        let ctx = LintContext::new(&current);
+        if let Ok(fixed) = rule.fix(&ctx) {
+            if fixed != current {
+                non_convergent.push(NonConvergentRule {
+                    rule_name: rule.name().to_string(),
+                    reason: format!("did not converge within {max_passes} passes"),
+                });
+            }
+        }
+    }
+    (current, non_convergent)
+}
+
 /// Convert a single warning fix to a text edit-style representation
 /// This helps validate that individual warning fixes are correctly structured
 pub fn warning_fix_to_edit(content: &str, warning: &LintWarning) -> Result<(usize, usize, String), String> {
@@ -584,4 +669,31 @@ mod tests {
         assert!(result_windows.starts_with("Line 1 added"));
         assert!(result_windows.contains("Line 2"));
     }
+
+    #[test]
+    fn test_fix_until_stable_converges_and_is_idempotent() {
+        use crate::config::Config;
+        use crate::rules::all_rules;
+
+        let rules = all_rules(&Config::default());
+        let content = "#Heading\n\n\n\nsome   text  \n";
+
+        let (fixed, non_convergent) = fix_until_stable(content, &rules, 10);
+        assert!(non_convergent.is_empty(), "unexpected cycle: {non_convergent:?}");
+
+        // Re-fixing an already-fixed document is a no-op (a stable fixed point).
+        let (refixed, _) = fix_until_stable(&fixed, &rules, 10);
+        assert_eq!(fixed, refixed);
+    }
+
+    #[test]
+    fn test_fix_until_stable_respects_zero_pass_floor() {
+        use crate::config::Config;
+        use crate::rules::all_rules;
+
+        // A max of 0 is treated as a single pass rather than doing nothing.
+        let rules = all_rules(&Config::default());
+        let (fixed, _) = fix_until_stable("# Heading\n", &rules, 0);
+        assert_eq!(fixed, "# Heading\n");
+    }
 }
@@ -2,6 +2,8 @@
 pub enum LineEnding {
     Lf,
     Crlf,
+    /// Bare `\r` (classic Mac OS style), still found in some legacy files.
+    Cr,
     Mixed,
 }
 
@@ -10,31 +12,42 @@ pub fn detect_line_ending_enum(content: &str) -> LineEnding {
     // Check if there are LF characters that are NOT part of CRLF
     let content_without_crlf = content.replace("\r\n", "");
     let has_standalone_lf = content_without_crlf.contains('\n');
-
-    match (has_crlf, has_standalone_lf) {
-        (true, true) => LineEnding::Mixed, // Has both CRLF and standalone LF
-        (true, false) => LineEnding::Crlf, // Only CRLF
-        (false, true) => LineEnding::Lf,   // Only LF
-        (false, false) => LineEnding::Lf,  // No line endings, default to LF
+    // Check if there are CR characters that are NOT part of CRLF
+    let has_standalone_cr = content_without_crlf.contains('\r');
+
+    match (has_crlf, has_standalone_lf, has_standalone_cr) {
+        (false, false, false) => LineEnding::Lf, // No line endings, default to LF
+        (false, true, false) => LineEnding::Lf,  // Only LF
+        (true, false, false) => LineEnding::Crlf, // Only CRLF
+        (false, false, true) => LineEnding::Cr,  // Only CR
+        _ => LineEnding::Mixed,                  // Any other combination
     }
 }
 
 pub fn detect_line_ending(content: &str) -> &'static str {
     // Compatibility function matching the old signature
     let crlf_count = content.matches("\r\n").count();
-    let lf_count = content.matches('\n').count() - crlf_count;
+    let content_without_crlf = content.replace("\r\n", "");
+    let lf_count = content_without_crlf.matches('\n').count();
+    let cr_count = content_without_crlf.matches('\r').count();
 
-    if crlf_count > lf_count { "\r\n" } else { "\n" }
+    if crlf_count > lf_count && crlf_count > cr_count {
+        "\r\n"
+    } else if cr_count > lf_count && cr_count > crlf_count {
+        "\r"
+    } else {
+        "\n"
+    }
 }
 
 pub fn normalize_line_ending(content: &str, target: LineEnding) -> String {
+    // First normalize everything down to bare LF...
+    let normalized = content.replace("\r\n", "\n").replace('\r', "\n");
+
     match target {
-        LineEnding::Lf => content.replace("\r\n", "\n"),
-        LineEnding::Crlf => {
-            // First normalize everything to LF, then convert to CRLF
-            let normalized = content.replace("\r\n", "\n");
-            normalized.replace('\n', "\r\n")
-        }
+        LineEnding::Lf => normalized,
+        LineEnding::Crlf => normalized.replace('\n', "\r\n"),
+        LineEnding::Cr => normalized.replace('\n', "\r"),
         LineEnding::Mixed => content.to_string(), // Don't change mixed endings
     }
 }
@@ -44,13 +57,10 @@ pub fn ensure_consistent_line_endings(original: &str, modified: &str) -> String
 
     // For mixed line endings, normalize to the most common one (like detect_line_ending does)
     let target_ending = if original_ending == LineEnding::Mixed {
-        // Use the same logic as detect_line_ending: prefer the more common one
-        let crlf_count = original.matches("\r\n").count();
-        let lf_count = original.matches('\n').count() - crlf_count;
-        if crlf_count > lf_count {
-            LineEnding::Crlf
-        } else {
-            LineEnding::Lf
+        match detect_line_ending(original) {
+            "\r\n" => LineEnding::Crlf,
+            "\r" => LineEnding::Cr,
+            _ => LineEnding::Lf,
         }
     } else {
         original_ending
@@ -69,6 +79,7 @@ pub fn get_line_ending_str(ending: LineEnding) -> &'static str {
     match ending {
         LineEnding::Lf => "\n",
         LineEnding::Crlf => "\r\n",
+        LineEnding::Cr => "\r",
         LineEnding::Mixed => "\n", // Default to LF for mixed
     }
 }
@@ -122,4 +133,27 @@ mod tests {
             "hello\nworld\nextra"
         );
     }
+
+    #[test]
+    fn test_detect_cr_line_ending() {
+        assert_eq!(detect_line_ending_enum("hello\rworld"), LineEnding::Cr);
+        assert_eq!(detect_line_ending("hello\rworld"), "\r");
+    }
+
+    #[test]
+    fn test_detect_mixed_with_cr() {
+        assert_eq!(detect_line_ending_enum("hello\rworld\nmixed"), LineEnding::Mixed);
+        assert_eq!(detect_line_ending_enum("hello\r\nworld\rmixed"), LineEnding::Mixed);
+    }
+
+    #[test]
+    fn test_normalize_to_cr() {
+        assert_eq!(normalize_line_ending("hello\nworld", LineEnding::Cr), "hello\rworld");
+        assert_eq!(normalize_line_ending("hello\r\nworld", LineEnding::Cr), "hello\rworld");
+    }
+
+    #[test]
+    fn test_get_line_ending_str_cr() {
+        assert_eq!(get_line_ending_str(LineEnding::Cr), "\r");
+    }
 }
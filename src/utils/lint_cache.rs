@@ -0,0 +1,397 @@
+//!
+//! Persistent incremental lint cache for rumdl
+//!
+//! Re-linting an unchanged file with an unchanged rule set is a common pattern
+//! for editor and CI flows. This module stores the `Vec<LintWarning>` produced
+//! for a `(content, rule set)` pair on disk, keyed by a 64-bit hash of
+//! `(content hash, ordered enabled rule names + their serialized config, crate
+//! version)`, so a second lint of the same input can skip rule execution
+//! entirely.
+//!
+//! The cache is size-bounded with least-recently-used eviction: each entry
+//! records a monotonically increasing use tick, and the oldest entries are
+//! dropped when the entry count exceeds the configured capacity. Hit/miss
+//! counts are surfaced through [`get_stats`] and folded into the crate-wide
+//! cache performance report.
+
+use crate::rule::{Fix, LintWarning, Severity};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Default number of entries retained before LRU eviction kicks in.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Hit/miss counters for the lint cache.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LintCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub writes: u64,
+    pub evictions: u64,
+}
+
+impl LintCacheStats {
+    /// Fraction of lookups served from cache, as a percentage.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            (self.hits as f64 / total as f64) * 100.0
+        }
+    }
+}
+
+/// Owned, serializable mirror of [`LintWarning`].
+///
+/// `LintWarning::rule_name` is a `&'static str`, which serde cannot deserialize
+/// directly, so the on-disk form carries an owned `String` that is re-interned
+/// to `'static` on read.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredWarning {
+    message: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    severity: Severity,
+    fix: Option<StoredFix>,
+    rule_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredFix {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+impl StoredWarning {
+    fn from_warning(w: &LintWarning) -> Self {
+        Self {
+            message: w.message.clone(),
+            line: w.line,
+            column: w.column,
+            end_line: w.end_line,
+            end_column: w.end_column,
+            severity: w.severity,
+            fix: w.fix.as_ref().map(|f| StoredFix {
+                start: f.range.start,
+                end: f.range.end,
+                replacement: f.replacement.clone(),
+            }),
+            rule_name: w.rule_name.map(|s| s.to_string()),
+        }
+    }
+
+    fn into_warning(self) -> LintWarning {
+        LintWarning {
+            message: self.message,
+            line: self.line,
+            column: self.column,
+            end_line: self.end_line,
+            end_column: self.end_column,
+            severity: self.severity,
+            fix: self.fix.map(|f| Fix {
+                range: f.start..f.end,
+                replacement: f.replacement,
+            }),
+            rule_name: self.rule_name.map(intern_static),
+        }
+    }
+}
+
+/// On-disk cache entry: the serialized warnings plus a last-use tick so the
+/// garbage collector can evict the least-recently-used documents.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    key: u64,
+    last_use: u64,
+    warnings: Vec<StoredWarning>,
+}
+
+/// Compute the 64-bit cache key from the content and the enabled rule set.
+///
+/// The key folds in the content hash, each enabled rule's name and serialized
+/// config (in the order supplied), and the crate version, so any change to the
+/// document, the rule selection, a rule's configuration, or an upgrade
+/// invalidates the entry.
+pub fn compute_key(content: &str, rules: &[Box<dyn crate::rule::Rule>]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    content.hash(&mut hasher);
+    for rule in rules {
+        rule.name().hash(&mut hasher);
+        if let Some((section, value)) = rule.default_config_section() {
+            section.hash(&mut hasher);
+            // toml values do not implement Hash; serialize to a stable string.
+            if let Ok(serialized) = toml::to_string(&value) {
+                serialized.hash(&mut hasher);
+            }
+        }
+    }
+    VERSION.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// LRU-bounded lint result cache backed by files under a cache directory.
+pub struct LintCache {
+    cache_dir: PathBuf,
+    capacity: usize,
+    /// key -> last-use tick, mirroring what is persisted on disk.
+    index: HashMap<u64, u64>,
+    tick: u64,
+    stats: LintCacheStats,
+}
+
+impl LintCache {
+    /// Create a cache rooted at `cache_dir` with the default capacity.
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self::with_capacity(cache_dir, DEFAULT_CAPACITY)
+    }
+
+    /// Create a cache with an explicit entry capacity.
+    pub fn with_capacity(cache_dir: PathBuf, capacity: usize) -> Self {
+        let mut cache = Self {
+            cache_dir,
+            capacity: capacity.max(1),
+            index: HashMap::new(),
+            tick: 0,
+            stats: LintCacheStats::default(),
+        };
+        cache.rebuild_index();
+        cache
+    }
+
+    fn version_dir(&self) -> PathBuf {
+        self.cache_dir.join(VERSION)
+    }
+
+    fn entry_path(&self, key: u64) -> PathBuf {
+        self.version_dir().join(format!("{key:016x}.json"))
+    }
+
+    /// Populate the in-memory index from any entries already on disk so the
+    /// LRU ordering survives across process restarts.
+    fn rebuild_index(&mut self) {
+        let dir = self.version_dir();
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Ok(data) = std::fs::read_to_string(&path) {
+                if let Ok(file) = serde_json::from_str::<CacheFile>(&data) {
+                    self.index.insert(file.key, file.last_use);
+                    self.tick = self.tick.max(file.last_use);
+                }
+            }
+        }
+    }
+
+    /// Look up cached warnings for `key`, bumping its recency on a hit.
+    pub fn get(&mut self, key: u64) -> Option<Vec<LintWarning>> {
+        if !self.index.contains_key(&key) {
+            self.stats.misses += 1;
+            return None;
+        }
+        let data = match std::fs::read_to_string(self.entry_path(key)) {
+            Ok(data) => data,
+            Err(_) => {
+                self.index.remove(&key);
+                self.stats.misses += 1;
+                return None;
+            }
+        };
+        let mut file: CacheFile = match serde_json::from_str(&data) {
+            Ok(file) if file.key == key => file,
+            _ => {
+                self.stats.misses += 1;
+                return None;
+            }
+        };
+
+        self.tick += 1;
+        file.last_use = self.tick;
+        self.index.insert(key, self.tick);
+        let _ = std::fs::write(self.entry_path(key), serde_json::to_string(&file).unwrap_or_default());
+
+        self.stats.hits += 1;
+        Some(file.warnings.into_iter().map(StoredWarning::into_warning).collect())
+    }
+
+    /// Store `warnings` for `key`, evicting the least-recently-used entry if the
+    /// cache is at capacity.
+    pub fn set(&mut self, key: u64, warnings: &[LintWarning]) {
+        if let Err(e) = std::fs::create_dir_all(self.version_dir()) {
+            log::debug!("lint cache dir creation failed: {e}");
+            return;
+        }
+
+        self.tick += 1;
+        let file = CacheFile {
+            key,
+            last_use: self.tick,
+            warnings: warnings.iter().map(StoredWarning::from_warning).collect(),
+        };
+        match serde_json::to_string(&file) {
+            Ok(json) => {
+                if std::fs::write(self.entry_path(key), json).is_ok() {
+                    self.index.insert(key, self.tick);
+                    self.stats.writes += 1;
+                }
+            }
+            Err(e) => log::debug!("lint cache serialization failed: {e}"),
+        }
+
+        self.evict_to_capacity();
+    }
+
+    /// Drop least-recently-used entries until the index is within capacity.
+    fn evict_to_capacity(&mut self) {
+        while self.index.len() > self.capacity {
+            let Some((&victim, _)) = self.index.iter().min_by_key(|(_, &tick)| tick) else {
+                break;
+            };
+            self.index.remove(&victim);
+            let _ = std::fs::remove_file(self.entry_path(victim));
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Remove every cached entry for the current version.
+    pub fn clear(&mut self) {
+        let _ = std::fs::remove_dir_all(self.version_dir());
+        self.index.clear();
+    }
+
+    /// Current hit/miss statistics.
+    pub fn stats(&self) -> LintCacheStats {
+        self.stats
+    }
+
+    /// Number of live entries.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+}
+
+/// Default on-disk location for the global lint cache.
+fn default_cache_dir() -> PathBuf {
+    std::env::temp_dir().join("rumdl-lint-cache")
+}
+
+lazy_static! {
+    /// Global lint result cache instance.
+    static ref GLOBAL_LINT_CACHE: Mutex<LintCache> = Mutex::new(LintCache::new(default_cache_dir()));
+}
+
+/// Intern an owned rule name to `'static`.
+///
+/// Rule names come from a small fixed set, so leaking the interned string once
+/// per distinct name keeps memory bounded while satisfying the `&'static str`
+/// field on [`LintWarning`].
+fn intern_static(name: String) -> &'static str {
+    use std::collections::HashSet;
+    lazy_static! {
+        static ref INTERNED: Mutex<HashSet<&'static str>> = Mutex::new(HashSet::new());
+    }
+    let mut set = INTERNED.lock().unwrap();
+    if let Some(existing) = set.get(name.as_str()) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(name.into_boxed_str());
+    set.insert(leaked);
+    leaked
+}
+
+/// Look up cached warnings for `key` in the global cache.
+pub fn get(key: u64) -> Option<Vec<LintWarning>> {
+    GLOBAL_LINT_CACHE.lock().unwrap().get(key)
+}
+
+/// Store warnings for `key` in the global cache.
+pub fn set(key: u64, warnings: &[LintWarning]) {
+    GLOBAL_LINT_CACHE.lock().unwrap().set(key, warnings);
+}
+
+/// Global lint cache statistics.
+pub fn get_stats() -> LintCacheStats {
+    GLOBAL_LINT_CACHE.lock().unwrap().stats()
+}
+
+/// Clear the global lint cache.
+pub fn clear() {
+    GLOBAL_LINT_CACHE.lock().unwrap().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{LintWarning, Severity};
+
+    fn sample_warning() -> LintWarning {
+        LintWarning {
+            message: "example".to_string(),
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 2,
+            severity: Severity::Warning,
+            fix: None,
+            rule_name: Some("MD001"),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_hit_and_miss() {
+        let dir = std::env::temp_dir().join(format!("rumdl-lint-cache-test-{}", std::process::id()));
+        let mut cache = LintCache::new(dir.clone());
+        cache.clear();
+
+        let key = 0xdead_beef;
+        assert!(cache.get(key).is_none());
+        assert_eq!(cache.stats().misses, 1);
+
+        cache.set(key, &[sample_warning()]);
+        let hit = cache.get(key).expect("cached entry");
+        assert_eq!(hit.len(), 1);
+        assert_eq!(hit[0].rule_name, Some("MD001"));
+        assert_eq!(cache.stats().hits, 1);
+
+        cache.clear();
+    }
+
+    #[test]
+    fn test_lru_eviction_drops_oldest() {
+        let dir = std::env::temp_dir().join(format!("rumdl-lint-cache-lru-{}", std::process::id()));
+        let mut cache = LintCache::with_capacity(dir, 2);
+        cache.clear();
+
+        cache.set(1, &[sample_warning()]);
+        cache.set(2, &[sample_warning()]);
+        // Touch key 1 so key 2 becomes the least-recently-used.
+        let _ = cache.get(1);
+        cache.set(3, &[sample_warning()]);
+
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(3).is_some());
+        assert!(cache.get(2).is_none());
+        assert!(cache.stats().evictions >= 1);
+
+        cache.clear();
+    }
+}
@@ -6,12 +6,15 @@ pub mod anchor_styles;
 pub mod ast_utils;
 pub mod code_block_utils;
 pub mod document_structure;
+pub mod document_model;
+pub mod document_visitor;
 pub mod early_returns;
 pub mod element_cache;
 pub mod emphasis_utils;
 pub mod fix_utils;
 pub mod header_id_utils;
 pub mod kramdown_utils;
+pub mod lint_cache;
 pub mod markdown_elements;
 pub mod range_utils;
 pub mod regex_cache;
@@ -8,6 +8,7 @@ use crate::utils::regex_cache::{
     INLINE_IMAGE_FANCY_REGEX, INLINE_LINK_FANCY_REGEX, INLINE_MATH_REGEX, REF_IMAGE_REGEX, REF_LINK_REGEX,
     SHORTCUT_REF_REGEX, STRIKETHROUGH_FANCY_REGEX, WIKI_LINK_REGEX,
 };
+use unicode_width::UnicodeWidthStr;
 /// Options for reflowing text
 #[derive(Clone)]
 pub struct ReflowOptions {
@@ -194,7 +195,7 @@ pub fn reflow_line(line: &str, options: &ReflowOptions) -> Vec<String> {
     }
 
     // Quick check: if line is already short enough, return as-is
-    if line.chars().count() <= options.line_length {
+    if line.width() <= options.line_length {
         return vec![line.to_string()];
     }
 
@@ -275,27 +276,31 @@ impl std::fmt::Display for Element {
 }
 
 impl Element {
+    /// Visual width of the element as it would render in a monospace
+    /// terminal, so CJK wide characters (width 2) and zero-width combining
+    /// marks (width 0) wrap the same way `md060`'s table formatter already
+    /// measures cell widths, instead of counting one column per `char`.
     fn len(&self) -> usize {
         match self {
-            Element::Text(s) => s.chars().count(),
-            Element::Link { text, url } => text.chars().count() + url.chars().count() + 4, // [text](url)
-            Element::ReferenceLink { text, reference } => text.chars().count() + reference.chars().count() + 4, // [text][ref]
-            Element::EmptyReferenceLink { text } => text.chars().count() + 4, // [text][]
-            Element::ShortcutReference { reference } => reference.chars().count() + 2, // [ref]
-            Element::InlineImage { alt, url } => alt.chars().count() + url.chars().count() + 5, // ![alt](url)
-            Element::ReferenceImage { alt, reference } => alt.chars().count() + reference.chars().count() + 5, // ![alt][ref]
-            Element::EmptyReferenceImage { alt } => alt.chars().count() + 5, // ![alt][]
-            Element::FootnoteReference { note } => note.chars().count() + 3, // [^note]
-            Element::Strikethrough(s) => s.chars().count() + 4,              // ~~text~~
-            Element::WikiLink(s) => s.chars().count() + 4,                   // [[wiki]]
-            Element::InlineMath(s) => s.chars().count() + 2,                 // $math$
-            Element::DisplayMath(s) => s.chars().count() + 4,                // $$math$$
-            Element::EmojiShortcode(s) => s.chars().count() + 2,             // :emoji:
-            Element::HtmlTag(s) => s.chars().count(),                        // <tag> - already includes brackets
-            Element::HtmlEntity(s) => s.chars().count(),                     // &nbsp; - already complete
-            Element::Code(s) => s.chars().count() + 2,                       // `code`
-            Element::Bold(s) => s.chars().count() + 4,                       // **text**
-            Element::Italic(s) => s.chars().count() + 2,                     // *text*
+            Element::Text(s) => s.width(),
+            Element::Link { text, url } => text.width() + url.width() + 4, // [text](url)
+            Element::ReferenceLink { text, reference } => text.width() + reference.width() + 4, // [text][ref]
+            Element::EmptyReferenceLink { text } => text.width() + 4, // [text][]
+            Element::ShortcutReference { reference } => reference.width() + 2, // [ref]
+            Element::InlineImage { alt, url } => alt.width() + url.width() + 5, // ![alt](url)
+            Element::ReferenceImage { alt, reference } => alt.width() + reference.width() + 5, // ![alt][ref]
+            Element::EmptyReferenceImage { alt } => alt.width() + 5, // ![alt][]
+            Element::FootnoteReference { note } => note.width() + 3, // [^note]
+            Element::Strikethrough(s) => s.width() + 4,              // ~~text~~
+            Element::WikiLink(s) => s.width() + 4,                   // [[wiki]]
+            Element::InlineMath(s) => s.width() + 2,                 // $math$
+            Element::DisplayMath(s) => s.width() + 4,                // $$math$$
+            Element::EmojiShortcode(s) => s.width() + 2,             // :emoji:
+            Element::HtmlTag(s) => s.width(),                        // <tag> - already includes brackets
+            Element::HtmlEntity(s) => s.width(),                     // &nbsp; - already complete
+            Element::Code(s) => s.width() + 2,                       // `code`
+            Element::Bold(s) => s.width() + 4,                       // **text**
+            Element::Italic(s) => s.width() + 2,                     // *text*
         }
     }
 }
@@ -738,7 +743,7 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
             let words: Vec<&str> = text.split_whitespace().collect();
 
             for word in words {
-                let word_len = word.chars().count();
+                let word_len = word.width();
                 if current_length > 0 && current_length + 1 + word_len > options.line_length {
                     // Start a new line
                     lines.push(current_line.trim().to_string());
@@ -1026,7 +1031,7 @@ pub fn reflow_markdown(content: &str, options: &ReflowOptions) -> String {
         }
 
         // If it's a single line that fits, just add it as-is
-        if is_single_line_paragraph && line.chars().count() <= options.line_length {
+        if is_single_line_paragraph && line.width() <= options.line_length {
             result.push(line.to_string());
             i += 1;
             continue;
@@ -1190,6 +1195,27 @@ mod tests {
         assert!(result[2].chars().count() <= 20);
     }
 
+    #[test]
+    fn test_reflow_wraps_by_display_width_not_char_count() {
+        // Each CJK character below is 1 `char` but 2 display columns, so a
+        // naive char-count wrap would fit twice as much per line as it should.
+        let options = ReflowOptions {
+            line_length: 10,
+            ..Default::default()
+        };
+
+        let input = "你好 世界 测试 一二三四五";
+        let result = reflow_line(input, &options);
+
+        for line in &result {
+            assert!(
+                line.width() <= 10,
+                "line '{line}' has display width {} which exceeds 10",
+                line.width()
+            );
+        }
+    }
+
     #[test]
     fn test_preserve_inline_code() {
         let options = ReflowOptions {
@@ -74,6 +74,61 @@ pub fn clear_screen() {
     let _ = io::stdout().flush();
 }
 
+/// Build the combined `--file-lines`/`--file-lines-json` restriction from
+/// `args`, or `None` if neither was passed.
+fn resolve_line_ranges(args: &crate::CheckArgs) -> Result<Option<rumdl_lib::line_ranges::LineRanges>, String> {
+    use rumdl_lib::line_ranges::LineRanges;
+
+    let from_specs = if args.file_lines.is_empty() {
+        None
+    } else {
+        Some(LineRanges::parse_specs(&args.file_lines)?)
+    };
+
+    let from_json = args
+        .file_lines_json
+        .as_deref()
+        .map(LineRanges::parse_json)
+        .transpose()?;
+
+    Ok(match (from_specs, from_json) {
+        (Some(a), Some(b)) => Some(a.merge(b)),
+        (Some(a), None) => Some(a),
+        (None, Some(b)) => Some(b),
+        (None, None) => None,
+    })
+}
+
+/// Build the combined `--lines`/`--diff-only` restriction from `args`, or
+/// `None` if neither was passed.
+fn resolve_file_lines(args: &crate::CheckArgs) -> Result<Option<rumdl_lib::file_lines::FileLines>, String> {
+    use rumdl_lib::file_lines::FileLines;
+
+    let from_lines = if args.lines.is_empty() {
+        None
+    } else {
+        Some(FileLines::parse_specs(&args.lines)?)
+    };
+
+    let from_diff = if args.diff_only {
+        let output = std::process::Command::new("git")
+            .args(["diff", "--unified=0"])
+            .output()
+            .map_err(|e| format!("failed to run `git diff` for --diff-only: {e}"))?;
+        let diff = String::from_utf8_lossy(&output.stdout);
+        Some(FileLines::from_ranges(FileLines::parse_diff_hunks(&diff)))
+    } else {
+        None
+    };
+
+    Ok(match (from_lines, from_diff) {
+        (Some(a), Some(b)) => Some(a.merge(b)),
+        (Some(a), None) => Some(a),
+        (None, Some(b)) => Some(b),
+        (None, None) => None,
+    })
+}
+
 /// Perform a single check run (extracted from run_check for reuse in watch mode)
 pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config, quiet: bool) -> bool {
     use rumdl_lib::output::{OutputFormat, OutputWriter};
@@ -103,6 +158,32 @@ pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config,
     // Initialize rules with configuration
     let enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, config);
 
+    // Resolve --file-lines / --file-lines-json into the per-file ranges warnings get
+    // restricted to, if either was passed.
+    let line_ranges = match resolve_line_ranges(args) {
+        Ok(ranges) => ranges,
+        Err(e) => {
+            if !args.silent {
+                eprintln!("{}: {}", "Error".red().bold(), e);
+            }
+            return true; // Has errors
+        }
+    };
+    let line_ranges = line_ranges.as_ref();
+
+    // Resolve --lines / --diff-only into the single-file restriction threaded
+    // into LintContext, if either was passed.
+    let file_lines = match resolve_file_lines(args) {
+        Ok(fl) => fl,
+        Err(e) => {
+            if !args.silent {
+                eprintln!("{}: {}", "Error".red().bold(), e);
+            }
+            return true; // Has errors
+        }
+    };
+    let file_lines = file_lines.as_ref();
+
     // Handle stdin input - either explicit --stdin flag or "-" as file argument
     if args.stdin || (args.paths.len() == 1 && args.paths[0] == "-") {
         crate::stdin_processor::process_stdin(&enabled_rules, args, config);
@@ -129,7 +210,12 @@ pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config,
     // For formats that need to collect all warnings first
     let needs_collection = matches!(
         output_format,
-        OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit
+        OutputFormat::Json
+            | OutputFormat::GitLab
+            | OutputFormat::Sarif
+            | OutputFormat::Junit
+            | OutputFormat::Checkstyle
+            | OutputFormat::FixesJson
     );
 
     if needs_collection {
@@ -147,6 +233,8 @@ pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config,
                 args.verbose && !args.silent,
                 quiet,
                 config,
+                line_ranges,
+                file_lines,
             );
 
             if !warnings.is_empty() {
@@ -167,6 +255,12 @@ pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config,
             OutputFormat::Junit => {
                 rumdl_lib::output::formatters::junit::format_junit_report(&all_file_warnings, duration_ms)
             }
+            OutputFormat::Checkstyle => {
+                rumdl_lib::output::formatters::checkstyle::format_checkstyle_report(&all_file_warnings)
+            }
+            OutputFormat::FixesJson => {
+                rumdl_lib::output::formatters::fixes_json::format_all_fixes_as_json(&all_file_warnings)
+            }
             _ => unreachable!(),
         };
 
@@ -214,6 +308,8 @@ pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config,
                         &output_format,
                         &output_writer,
                         config,
+                        line_ranges,
+                        file_lines,
                     )
                 })
                 .collect();
@@ -270,6 +366,8 @@ pub fn perform_check_run(args: &crate::CheckArgs, config: &rumdl_config::Config,
                         &output_format,
                         &output_writer,
                         config,
+                        line_ranges,
+                        file_lines,
                     );
 
                 total_files_processed += 1;
@@ -0,0 +1,150 @@
+//! Snapshot-based fixture harness for rule fix testing.
+//!
+//! Drops the per-rule `assert!`-chain boilerplate in favour of data files:
+//! each case is a pair of `<case>.md` (input) and `<case>.fixed.md` (expected
+//! output) under `tests/fixtures/fix/`. The harness copies the input into a
+//! temp dir, runs the real `rumdl check --fix` pipeline over it, and diffs the
+//! produced content against the expected snapshot — printing a context diff on
+//! mismatch.
+//!
+//! A case may declare which rules to enable with a leading `// rules:` comment,
+//! e.g. `// rules: MD018,MD030`; the directive line is stripped before the file
+//! is written, so only real Markdown reaches the fixer. With no directive every
+//! rule runs, exercising full fixable/unfixable interactions.
+//!
+//! Set `RUMDL_BLESS=1` to regenerate the `.fixed.md` snapshots in place instead
+//! of asserting, so adding a case is a matter of dropping one `.md` file and
+//! blessing.
+
+use assert_cmd::Command;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Directory holding the `<case>.md` / `<case>.fixed.md` pairs.
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/fix")
+}
+
+/// A parsed fixture input: the Markdown body with directive lines stripped and
+/// the set of rules the `// rules:` header requested (empty = all rules).
+struct Case {
+    body: String,
+    rules: Vec<String>,
+}
+
+/// Split a raw `<case>.md` into its directive header and Markdown body. Only
+/// leading `// key: value` lines are treated as directives.
+fn parse_case(raw: &str) -> Case {
+    let mut rules = Vec::new();
+    let mut body_start = 0;
+    for line in raw.split_inclusive('\n') {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        let Some(directive) = trimmed.strip_prefix("//") else {
+            break;
+        };
+        if let Some(spec) = directive.trim_start().strip_prefix("rules:") {
+            rules.extend(
+                spec.split(',')
+                    .map(|r| r.trim().to_string())
+                    .filter(|r| !r.is_empty()),
+            );
+        }
+        body_start += line.len();
+    }
+    Case {
+        body: raw[body_start..].to_string(),
+        rules,
+    }
+}
+
+/// Run `rumdl check --fix` over `body` (enabling `rules` if non-empty) and
+/// return the resulting file content.
+fn run_fix(case: &Case) -> String {
+    let temp = TempDir::new().expect("create temp dir");
+    let file = temp.path().join("case.md");
+    fs::write(&file, &case.body).expect("write input");
+
+    let mut cmd = Command::cargo_bin("rumdl").unwrap();
+    cmd.current_dir(temp.path()).args(["check", "--fix", "--no-config"]);
+    if !case.rules.is_empty() {
+        cmd.args(["--enable", &case.rules.join(",")]);
+    }
+    cmd.arg("case.md");
+    // --fix exits non-zero when issues remain unfixable; we only care about the
+    // file content it produced, so the exit status is ignored here.
+    let _ = cmd.output().expect("run rumdl");
+
+    fs::read_to_string(&file).expect("read fixed file")
+}
+
+/// A minimal context diff between `expected` and `actual`, line by line.
+fn context_diff(expected: &str, actual: &str) -> String {
+    let mut out = String::new();
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let max = expected_lines.len().max(actual_lines.len());
+    for i in 0..max {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push_str(&format!("  {e}\n")),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push_str(&format!("- {e}\n"));
+                }
+                if let Some(a) = a {
+                    out.push_str(&format!("+ {a}\n"));
+                }
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn fix_fixtures() {
+    let dir = fixtures_dir();
+    let bless = std::env::var("RUMDL_BLESS").is_ok_and(|v| v == "1");
+
+    let mut cases = 0;
+    let mut failures = Vec::new();
+
+    let entries = fs::read_dir(&dir).unwrap_or_else(|e| panic!("read {}: {e}", dir.display()));
+    for entry in entries {
+        let path = entry.expect("dir entry").path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        // Only the `<case>.md` inputs drive the harness; skip the snapshots.
+        if !name.ends_with(".md") || name.ends_with(".fixed.md") {
+            continue;
+        }
+        cases += 1;
+
+        let raw = fs::read_to_string(&path).expect("read case");
+        let case = parse_case(&raw);
+        let produced = run_fix(&case);
+
+        let expected_path = path.with_extension("fixed.md");
+        if bless {
+            fs::write(&expected_path, &produced).expect("bless snapshot");
+            continue;
+        }
+
+        let expected = fs::read_to_string(&expected_path).unwrap_or_else(|e| {
+            panic!(
+                "missing snapshot {} ({e}); run with RUMDL_BLESS=1 to create it",
+                expected_path.display()
+            )
+        });
+
+        if produced != expected {
+            failures.push(format!("{name}:\n{}", context_diff(&expected, &produced)));
+        }
+    }
+
+    assert!(cases > 0, "no fixture cases found in {}", dir.display());
+    assert!(
+        failures.is_empty(),
+        "{} fixture(s) did not match their snapshot (set RUMDL_BLESS=1 to update):\n\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
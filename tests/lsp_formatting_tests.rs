@@ -499,3 +499,121 @@ Final paragraph"#;
         assert!(edit.new_text.ends_with('\n'), "Final newline added");
     }
 }
+
+/// MD063 heading-capitalization violations should be offered as a quick-fix
+/// code action whose edit rewrites the heading to the enforced style.
+#[tokio::test]
+async fn test_code_action_rewrites_heading_capitalization() {
+    let (service, _socket) = LspService::new(|client| RumdlLanguageServer::new(client, None));
+
+    // Work inside a unique temp directory holding a config that enables the
+    // opt-in MD063 rule, so config resolution picks it up for this file.
+    let dir = std::env::temp_dir().join(format!("rumdl_md063_action_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(".rumdl.toml"), "[MD063]\nenabled = true\nstyle = \"title_case\"\n").unwrap();
+    let file = dir.join("doc.md");
+    std::fs::write(&file, "# heading title\n").unwrap();
+    let uri = Url::from_file_path(&file).unwrap();
+
+    let init_params = InitializeParams {
+        process_id: Some(1),
+        root_path: None,
+        root_uri: Some(Url::from_file_path(&dir).unwrap()),
+        initialization_options: None,
+        capabilities: ClientCapabilities::default(),
+        trace: None,
+        workspace_folders: None,
+        client_info: None,
+        locale: None,
+    };
+    service.inner().initialize(init_params).await.unwrap();
+    service.inner().initialized(InitializedParams {}).await;
+
+    service
+        .inner()
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# heading title\n".to_string(),
+            },
+        })
+        .await;
+
+    let params = CodeActionParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        range: Range {
+            start: Position { line: 0, character: 0 },
+            end: Position { line: 0, character: 15 },
+        },
+        context: CodeActionContext::default(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    let response = service.inner().code_action(params).await.unwrap();
+
+    std::fs::remove_dir_all(&dir).ok();
+
+    let actions = response.expect("code actions returned");
+    let edit = actions
+        .iter()
+        .find_map(|a| match a {
+            CodeActionOrCommand::CodeAction(action) if action.title == "Change heading to Title Case" => {
+                Some(action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri][0].new_text.clone())
+            }
+            _ => None,
+        })
+        .expect("MD063 heading-case action present");
+
+    assert_eq!(edit, "# Heading Title");
+}
+
+/// Formatting must work when the document was never opened via didOpen: the
+/// handler falls back to reading the file from disk (through
+/// `get_document_content`) and returns a whole-document replacement, returning
+/// `None` only when the file genuinely does not exist.
+#[tokio::test]
+async fn test_formatting_falls_back_to_disk_for_unopened_document() {
+    let (service, _socket) = LspService::new(|client| RumdlLanguageServer::new(client, None));
+
+    let init_params = InitializeParams {
+        process_id: Some(1),
+        root_path: None,
+        root_uri: None,
+        initialization_options: None,
+        capabilities: ClientCapabilities::default(),
+        trace: None,
+        workspace_folders: None,
+        client_info: None,
+        locale: None,
+    };
+    service.inner().initialize(init_params).await.unwrap();
+
+    let file = std::env::temp_dir().join(format!("rumdl_fmt_unopened_{}.md", std::process::id()));
+    std::fs::write(&file, "#Missing space\n\nTrailing spaces   \n").unwrap();
+    let uri = Url::from_file_path(&file).unwrap();
+
+    let params = DocumentFormattingParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            properties: std::collections::HashMap::new(),
+            trim_trailing_whitespace: Some(true),
+            insert_final_newline: Some(true),
+            trim_final_newlines: Some(true),
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    // The document was never opened, so the only source is disk.
+    let edits = service.inner().formatting(params).await.unwrap();
+    std::fs::remove_file(&file).ok();
+
+    let edits = edits.expect("formatting should read the file from disk, not return None");
+    assert_eq!(edits.len(), 1, "whole-document replacement edit");
+    assert!(edits[0].new_text.contains("# Missing space"));
+    assert!(!edits[0].new_text.contains("   \n"));
+}
@@ -639,3 +639,32 @@ fn test_mixed_multiline_links_and_bare_urls() {
         "Should not modify the second multi-line markdown link"
     );
 }
+
+#[test]
+fn test_autolink_fix_wraps_various_schemes() {
+    let rule = MD034NoBareUrls;
+    // Each bare URL should be detected and wrapped in angle brackets by the fix.
+    let content = "See https://example.com and ftp://files.example.com for details.";
+    let ctx = LintContext::new(content);
+
+    let result = rule.check(&ctx).unwrap();
+    assert_eq!(result.len(), 2, "Both bare URLs should be flagged");
+
+    let fixed = rule.fix(&ctx).unwrap();
+    assert_eq!(
+        fixed,
+        "See <https://example.com> and <ftp://files.example.com> for details."
+    );
+}
+
+#[test]
+fn test_autolink_fix_is_idempotent() {
+    let rule = MD034NoBareUrls;
+    let content = "Visit https://example.com now.";
+    let ctx = LintContext::new(content);
+
+    let fixed = rule.fix(&ctx).unwrap();
+    // Re-linting the fixed output must produce no further warnings.
+    let ctx2 = LintContext::new(&fixed);
+    assert!(rule.check(&ctx2).unwrap().is_empty());
+}
@@ -11,10 +11,42 @@ use rumdl::rules::code_fence_utils::CodeFenceStyle;
 use rumdl::rules::strong_style::StrongStyle;
 use rumdl::rules::*;
 
+/// Convert a warning's 1-indexed `(line, column)` start/end into an absolute
+/// byte range into `content`, using `ctx.line_offsets` the same way every
+/// rule's own fix ranges are computed. Unlike a `.lines()`-based splice, this
+/// spans multiple lines just as easily as one.
+fn warning_byte_range(ctx: &LintContext, warning: &rumdl::rule::LintWarning) -> Result<std::ops::Range<usize>, String> {
+    let line_offsets = &ctx.line_offsets;
+
+    if warning.line == 0 || warning.line > line_offsets.len() {
+        return Err("Invalid warning line number".to_string());
+    }
+    if warning.end_line == 0 || warning.end_line > line_offsets.len() {
+        return Err("Invalid warning end line number".to_string());
+    }
+
+    let start = line_offsets[warning.line - 1] + warning.column.saturating_sub(1);
+    let end = line_offsets[warning.end_line - 1] + warning.end_column.saturating_sub(1);
+
+    if start > ctx.content.len() || end > ctx.content.len() || end < start {
+        return Err("Invalid warning column range".to_string());
+    }
+    if !ctx.content.is_char_boundary(start) || !ctx.content.is_char_boundary(end) {
+        return Err("Warning range does not fall on a character boundary".to_string());
+    }
+
+    Ok(start..end)
+}
+
 /// Simulates how VS Code extension applies a fix by:
 /// 1. Getting the warning range from the rule
 /// 2. Applying the fix replacement text to that warning range only
 /// 3. Returning the result
+///
+/// Uses an absolute-offset edit model (like rustc's `CodeSuggestion` or
+/// rustfmt's `ModifiedLines`): the warning's line/column is resolved to a
+/// byte range once, and the edit is a single whole-document splice, so
+/// multi-line warning ranges work the same as single-line ones.
 fn simulate_vscode_fix(content: &str, rule: &dyn Rule) -> Result<String, String> {
     let ctx = LintContext::new(content);
     let warnings = rule.check(&ctx).map_err(|e| format!("Check failed: {e:?}"))?;
@@ -26,46 +58,9 @@ fn simulate_vscode_fix(content: &str, rule: &dyn Rule) -> Result<String, String>
     // Take the first warning
     let warning = &warnings[0];
     let fix = warning.fix.as_ref().ok_or("No fix available")?;
+    let range = warning_byte_range(&ctx, warning)?;
 
-    // Get warning range
-    let warning_start_line = warning.line;
-    let warning_start_col = warning.column;
-    let warning_end_line = warning.end_line;
-    let warning_end_col = warning.end_column;
-
-    // Convert to byte positions using the same logic as the warning
-    let lines: Vec<&str> = content.lines().collect();
-
-    if warning_start_line == 0 || warning_start_line > lines.len() {
-        return Err("Invalid warning line number".to_string());
-    }
-
-    // For single-line replacements (most common case)
-    if warning_start_line == warning_end_line {
-        let line = lines[warning_start_line - 1]; // Convert to 0-indexed
-
-        // Convert 1-indexed columns to 0-indexed byte positions
-        // Note: end_column is exclusive (points after the last character)
-        let start_byte = warning_start_col.saturating_sub(1);
-        let end_byte = warning_end_col.saturating_sub(1);
-
-        if start_byte > line.len() || end_byte > line.len() {
-            return Err("Invalid warning column range".to_string());
-        }
-
-        // Apply the replacement to the warning range
-        let before = &line[..start_byte];
-        let after = &line[end_byte..];
-        let new_line = format!("{}{}{}", before, fix.replacement, after);
-
-        // Reconstruct the full content
-        let mut result_lines: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
-        result_lines[warning_start_line - 1] = new_line;
-
-        Ok(result_lines.join("\n") + if content.ends_with('\n') { "\n" } else { "" })
-    } else {
-        Err("Multi-line warning ranges not implemented yet".to_string())
-    }
+    Ok(format!("{}{}{}", &content[..range.start], fix.replacement, &content[range.end..]))
 }
 
 /// Helper function to create test cases for each rule
@@ -180,6 +175,69 @@ fn create_test_case_for_rule(rule_name: &str) -> Option<(&'static str, Box<dyn R
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rumdl::rule::{Fix, LintResult, LintWarning, Severity};
+
+    #[test]
+    fn test_warning_byte_range_spans_multiple_lines() {
+        let content = "alpha\nbeta\ngamma";
+        let ctx = LintContext::new(content);
+        let warning = LintWarning {
+            message: "test".to_string(),
+            line: 1,
+            column: 1,
+            end_line: 3,
+            end_column: 1,
+            severity: Severity::Warning,
+            fix: None,
+            rule_name: None,
+        };
+
+        let range = warning_byte_range(&ctx, &warning).unwrap();
+        assert_eq!(&content[range], "alpha\nbeta\n");
+    }
+
+    /// Minimal rule reporting a single multi-line warning, used to confirm
+    /// `simulate_vscode_fix` applies the fix across the whole warning range
+    /// instead of bailing out like the old single-line-only splice did.
+    #[derive(Clone)]
+    struct MultiLineTestRule;
+
+    impl Rule for MultiLineTestRule {
+        fn name(&self) -> &'static str {
+            "TESTML"
+        }
+
+        fn description(&self) -> &'static str {
+            "test-only rule reporting a multi-line warning"
+        }
+
+        fn check(&self, _ctx: &LintContext) -> LintResult {
+            Ok(vec![LintWarning {
+                message: "multi-line issue".to_string(),
+                line: 1,
+                column: 1,
+                end_line: 3,
+                end_column: 1,
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: 0..0,
+                    replacement: "REPLACED\n".to_string(),
+                }),
+                rule_name: Some("TESTML"),
+            }])
+        }
+
+        fn fix(&self, ctx: &LintContext) -> Result<String, rumdl::rule::LintError> {
+            Ok(ctx.content.to_string())
+        }
+    }
+
+    #[test]
+    fn test_simulate_vscode_fix_applies_multiline_replacement() {
+        let content = "alpha\nbeta\ngamma";
+        let result = simulate_vscode_fix(content, &MultiLineTestRule).unwrap();
+        assert_eq!(result, "REPLACED\ngamma");
+    }
 
     // Keep existing specific tests that we know work
     #[test]